@@ -0,0 +1,31 @@
+#include <stdint.h>
+#include <stdio.h>
+#include <stdlib.h>
+
+void *unnamed_alloc(uint64_t size) {
+  void *ptr = malloc(size);
+  if (ptr == NULL && size != 0) {
+    fputs("out of memory\n", stderr);
+    abort();
+  }
+  return ptr;
+}
+
+void unnamed_free(void *ptr) { free(ptr); }
+
+void unnamed_print_int(int64_t value) { printf("%lld\n", (long long)value); }
+
+void unnamed_print_float(double value) { printf("%g\n", value); }
+
+void unnamed_print_str(const char *str, uint64_t len) {
+  fwrite(str, 1, len, stdout);
+  fputc('\n', stdout);
+}
+
+_Noreturn void unnamed_panic(const char *msg, uint64_t len) {
+  fflush(stdout);
+  fputs("panic: ", stderr);
+  fwrite(msg, 1, len, stderr);
+  fputc('\n', stderr);
+  exit(101);
+}
@@ -0,0 +1,536 @@
+use llvm::{
+    builder::{Builder, FloatPredicate, IntPredicate},
+    context::Context,
+    error::CastError,
+    module::Module,
+    types::{integer::IntegerType, Type},
+    values::{
+        float::FloatValue, function::Linkage, global::GlobalValue, integer::IntegerValue,
+        structure::StructValue, vector::VectorValue, TypeOf, Value,
+    },
+};
+
+use crate::{
+    lexer::token::Position,
+    parser::{expressions::Operator, types::PrimitiveType},
+    runtime::Builtin,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArithmeticMode {
+    Checked,
+    NoWrap,
+}
+
+pub struct Arithmetic<'a, 'ctx> {
+    context: &'ctx Context,
+    module: &'a Module<'ctx>,
+    builder: &'a Builder<'ctx>,
+    mode: ArithmeticMode,
+}
+
+impl<'a, 'ctx> Arithmetic<'a, 'ctx> {
+    pub fn new(
+        context: &'ctx Context,
+        module: &'a Module<'ctx>,
+        builder: &'a Builder<'ctx>,
+        mode: ArithmeticMode,
+    ) -> Self {
+        Self { context, module, builder, mode }
+    }
+
+    pub fn binary(
+        &self,
+        operator: &Operator,
+        ty: PrimitiveType,
+        lhs: IntegerValue<'ctx>,
+        rhs: IntegerValue<'ctx>,
+        position: &Position,
+        name: &str,
+    ) -> Option<IntegerValue<'ctx>> {
+        let builder = self.builder;
+        let signed = ty.is_signed();
+
+        if let Some(predicate) = predicate(operator, signed) {
+            return ty.is_integer().then(|| builder.icmp(predicate, lhs, rhs, name));
+        }
+
+        if !ty.is_integer() || ty == PrimitiveType::Bool {
+            return None;
+        }
+
+        Some(match (self.mode, operator) {
+            (ArithmeticMode::NoWrap, Operator::Plus) if signed => builder.nsw_add(lhs, rhs, name),
+            (ArithmeticMode::NoWrap, Operator::Plus) => builder.nuw_add(lhs, rhs, name),
+            (ArithmeticMode::NoWrap, Operator::Minus) if signed => builder.nsw_sub(lhs, rhs, name),
+            (ArithmeticMode::NoWrap, Operator::Minus) => builder.nuw_sub(lhs, rhs, name),
+            (ArithmeticMode::NoWrap, Operator::Multiply) if signed => {
+                builder.nsw_mul(lhs, rhs, name)
+            }
+            (ArithmeticMode::NoWrap, Operator::Multiply) => builder.nuw_mul(lhs, rhs, name),
+            (ArithmeticMode::Checked, Operator::Plus) => self.overflowing(
+                if signed {
+                    builder.signed_add_with_overflow(lhs, rhs, name)
+                } else {
+                    builder.unsigned_add_with_overflow(lhs, rhs, name)
+                }
+                .ok()?,
+                position,
+                "attempt to add with overflow",
+            ),
+            (ArithmeticMode::Checked, Operator::Minus) => self.overflowing(
+                if signed {
+                    builder.signed_sub_with_overflow(lhs, rhs, name)
+                } else {
+                    builder.unsigned_sub_with_overflow(lhs, rhs, name)
+                }
+                .ok()?,
+                position,
+                "attempt to subtract with overflow",
+            ),
+            (ArithmeticMode::Checked, Operator::Multiply) => self.overflowing(
+                if signed {
+                    builder.signed_mul_with_overflow(lhs, rhs, name)
+                } else {
+                    builder.unsigned_mul_with_overflow(lhs, rhs, name)
+                }
+                .ok()?,
+                position,
+                "attempt to multiply with overflow",
+            ),
+            (_, Operator::Division) => {
+                let ty = lhs.get_type();
+
+                let is_zero = builder.icmp(IntPredicate::EQ, rhs.clone(), ty.constant(0), "");
+                self.panic_if(is_zero.into(), position, "attempt to divide by zero");
+
+                if !signed {
+                    return Some(builder.unsigned_div(lhs, rhs, name));
+                }
+
+                let is_min = builder.icmp(IntPredicate::EQ, lhs.clone(), signed_min(ty), "");
+                let is_minus_one =
+                    builder.icmp(IntPredicate::EQ, rhs.clone(), ty.const_signed(-1), "");
+                let overflows =
+                    builder.select(is_min, is_minus_one, self.context.int::<1>().constant(0), "");
+                self.panic_if(overflows, position, "attempt to divide with overflow");
+
+                builder.signed_div(lhs, rhs, name)
+            }
+            _ => return None,
+        })
+    }
+
+    pub fn float_binary(
+        &self,
+        operator: &Operator,
+        lhs: FloatValue<'ctx>,
+        rhs: FloatValue<'ctx>,
+        name: &str,
+    ) -> Option<Value<'ctx>> {
+        let builder = self.builder;
+
+        if let Some(predicate) = float_predicate(operator) {
+            return Some(builder.fcmp(predicate, lhs, rhs, name).into());
+        }
+
+        Some(
+            match operator {
+                Operator::Plus => builder.float_add(lhs, rhs, name),
+                Operator::Minus => builder.float_sub(lhs, rhs, name),
+                Operator::Multiply => builder.float_mul(lhs, rhs, name),
+                Operator::Division => builder.float_div(lhs, rhs, name),
+                _ => return None,
+            }
+            .into(),
+        )
+    }
+
+    pub fn vector_binary(
+        &self,
+        operator: &Operator,
+        element: PrimitiveType,
+        lhs: VectorValue<'ctx>,
+        rhs: VectorValue<'ctx>,
+        name: &str,
+    ) -> Option<VectorValue<'ctx>> {
+        let builder = self.builder;
+
+        Some(match (operator, element) {
+            (_, PrimitiveType::Bool) => return None,
+            (Operator::Plus, PrimitiveType::Float { .. }) => {
+                builder.vector_float_add(lhs, rhs, name)
+            }
+            (Operator::Minus, PrimitiveType::Float { .. }) => {
+                builder.vector_float_sub(lhs, rhs, name)
+            }
+            (Operator::Multiply, PrimitiveType::Float { .. }) => {
+                builder.vector_float_mul(lhs, rhs, name)
+            }
+            (Operator::Division, PrimitiveType::Float { .. }) => {
+                builder.vector_float_div(lhs, rhs, name)
+            }
+            (Operator::Plus, _) => builder.vector_add(lhs, rhs, name),
+            (Operator::Minus, _) => builder.vector_sub(lhs, rhs, name),
+            (Operator::Multiply, _) => builder.vector_mul(lhs, rhs, name),
+            _ => return None,
+        })
+    }
+
+    fn overflowing(
+        &self,
+        result: StructValue<'ctx>,
+        position: &Position,
+        message: &str,
+    ) -> IntegerValue<'ctx> {
+        let overflowed = self.builder.extract_value(result.clone(), 1, "");
+        self.panic_if(overflowed, position, message);
+
+        IntegerValue::from(self.builder.extract_value(result, 0, ""))
+    }
+
+    fn panic_if(&self, condition: Value<'ctx>, position: &Position, message: &str) {
+        let function = self
+            .builder
+            .get_insert_block()
+            .expect("builder is not positioned in a basic block")
+            .parent();
+
+        let panic_block = function.append_basic_block("panic");
+        let continue_block = function.append_basic_block("continue");
+        self.builder.cond_br(condition, &panic_block, &continue_block);
+
+        self.builder.position_at_end(&panic_block);
+        let message = format!("{position}: {message}");
+        let global = global_string(self.context, self.module, &message);
+
+        let panic = Builtin::Panic.declare(self.context, self.module);
+        let pointer = self
+            .builder
+            .bit_cast(global, self.context.pointer(0), "")
+            .expect("global string is not a pointer");
+        self.builder.call(
+            panic.function_type(),
+            &panic,
+            &[pointer, self.context.int::<64>().constant(message.len() as u64).into()],
+            "",
+        );
+        self.builder.unreachable();
+
+        self.builder.position_at_end(&continue_block);
+    }
+}
+
+/// A private constant holding the bytes of `string`, without a terminating NUL.
+pub fn global_string<'ctx>(
+    context: &'ctx Context,
+    module: &Module<'ctx>,
+    string: &str,
+) -> GlobalValue<'ctx> {
+    let ty = context.array(context.int::<8>().into(), string.len() as u32);
+    let global = module.add_global("", ty.into());
+    global.set_initializer(context.const_string(string, false));
+    global.set_constant(true);
+    global.set_linkage(Linkage::Private);
+
+    global
+}
+
+pub fn cast<'ctx>(
+    builder: &Builder<'ctx>,
+    value: Value<'ctx>,
+    from: PrimitiveType,
+    to: PrimitiveType,
+    ty: Type<'ctx>,
+    name: &str,
+) -> Result<Value<'ctx>, CastError> {
+    if !from.can_cast_to(to) {
+        return Err(CastError::InvalidTypes {
+            op: None,
+            from: value.type_of().kind(),
+            to: ty.kind(),
+        });
+    }
+
+    let signed = match from {
+        PrimitiveType::Float { .. } => to.is_signed(),
+        _ => from.is_signed(),
+    };
+
+    builder.cast(value, ty, signed, name)
+}
+
+fn predicate(operator: &Operator, signed: bool) -> Option<IntPredicate> {
+    Some(match (operator, signed) {
+        (Operator::Eq, _) => IntPredicate::EQ,
+        (Operator::Less, true) => IntPredicate::SLT,
+        (Operator::Less, false) => IntPredicate::ULT,
+        (Operator::LessEq, true) => IntPredicate::SLE,
+        (Operator::LessEq, false) => IntPredicate::ULE,
+        (Operator::Greeter, true) => IntPredicate::SGT,
+        (Operator::Greeter, false) => IntPredicate::UGT,
+        (Operator::GreeterEq, true) => IntPredicate::SGE,
+        (Operator::GreeterEq, false) => IntPredicate::UGE,
+        _ => return None,
+    })
+}
+
+fn float_predicate(operator: &Operator) -> Option<FloatPredicate> {
+    Some(match operator {
+        Operator::Eq => FloatPredicate::OEQ,
+        Operator::Less => FloatPredicate::OLT,
+        Operator::LessEq => FloatPredicate::OLE,
+        Operator::Greeter => FloatPredicate::OGT,
+        Operator::GreeterEq => FloatPredicate::OGE,
+        _ => return None,
+    })
+}
+
+fn signed_min(ty: IntegerType) -> IntegerValue {
+    let width = ty.width();
+    if width <= 64 {
+        return ty.const_signed(i64::MIN >> (64 - width));
+    }
+
+    let mut words = vec![0; (width as usize).div_ceil(64)];
+    words[(width as usize - 1) / 64] = 1 << ((width - 1) % 64);
+
+    ty.const_arbitrary_precision(&words)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use llvm::{
+        context::Context,
+        values::{float::FloatValue, integer::IntegerValue, vector::VectorValue},
+    };
+
+    use crate::{
+        lexer::token::Position,
+        parser::{expressions::Operator, types::PrimitiveType},
+    };
+
+    use super::{cast, Arithmetic, ArithmeticMode};
+
+    macro_rules! lower {
+        ($mode: ident, $operator: ident, $ty: literal) => {{
+            let context = Context::create();
+            let module = context.module("test");
+            let builder = context.builder();
+
+            let int_32 = context.int::<32>();
+            let ty = context.function(&[int_32.into(), int_32.into()], int_32.into());
+            let function = module.add_function("test", ty);
+            builder.position_at_end(&function.append_basic_block("entry"));
+
+            let arithmetic = Arithmetic::new(&context, &module, &builder, ArithmeticMode::$mode);
+            let result = arithmetic
+                .binary(
+                    &Operator::$operator,
+                    PrimitiveType::from_name($ty).unwrap(),
+                    IntegerValue::from(function.param(0)),
+                    IntegerValue::from(function.param(1)),
+                    &Position::new(0, 5, 2, 4, Path::new("test.u")),
+                    "result",
+                )
+                .unwrap();
+            let result = match result.get_type().width() {
+                1 => builder.zero_extend(result, int_32, "").unwrap(),
+                _ => result,
+            };
+            builder.ret(result);
+
+            module.verify().unwrap();
+            module.print_to_string().to_string_lossy().into_owned()
+        }};
+    }
+
+    #[test]
+    fn test_checked_add() {
+        let ir = lower!(Checked, Plus, "i32");
+
+        assert!(ir.contains("@llvm.sadd.with.overflow.i32"));
+        assert!(ir.contains("test.u:3:4: attempt to add with overflow"));
+        assert!(ir.contains("call void @unnamed_panic"));
+    }
+
+    #[test]
+    fn test_checked_sub_and_mul() {
+        assert!(lower!(Checked, Minus, "i32").contains("@llvm.ssub.with.overflow.i32"));
+        assert!(lower!(Checked, Multiply, "i32").contains("@llvm.smul.with.overflow.i32"));
+    }
+
+    #[test]
+    fn test_checked_division() {
+        let ir = lower!(Checked, Division, "i32");
+
+        assert!(ir.contains("attempt to divide by zero"));
+        assert!(ir.contains("attempt to divide with overflow"));
+        assert!(ir.contains("icmp eq i32 %0, -2147483648"));
+        assert!(ir.contains("sdiv i32"));
+    }
+
+    #[test]
+    fn test_checked_unsigned() {
+        assert!(lower!(Checked, Plus, "u32").contains("@llvm.uadd.with.overflow.i32"));
+        assert!(lower!(Checked, Minus, "u32").contains("@llvm.usub.with.overflow.i32"));
+        assert!(lower!(Checked, Multiply, "u32").contains("@llvm.umul.with.overflow.i32"));
+
+        let ir = lower!(Checked, Division, "u32");
+        assert!(ir.contains("attempt to divide by zero"));
+        assert!(!ir.contains("attempt to divide with overflow"));
+        assert!(ir.contains("udiv i32"));
+    }
+
+    #[test]
+    fn test_no_wrap() {
+        assert!(lower!(NoWrap, Plus, "i32").contains("add nsw i32"));
+        assert!(lower!(NoWrap, Minus, "i32").contains("sub nsw i32"));
+        assert!(lower!(NoWrap, Multiply, "i32").contains("mul nsw i32"));
+        assert!(lower!(NoWrap, Division, "i32").contains("attempt to divide with overflow"));
+        assert!(lower!(NoWrap, Plus, "u32").contains("add nuw i32"));
+        assert!(lower!(NoWrap, Minus, "u32").contains("sub nuw i32"));
+        assert!(lower!(NoWrap, Multiply, "u32").contains("mul nuw i32"));
+        assert!(lower!(NoWrap, Division, "u32").contains("attempt to divide by zero"));
+    }
+
+    #[test]
+    fn test_comparison_signedness() {
+        assert!(lower!(Checked, Less, "i32").contains("icmp slt i32"));
+        assert!(lower!(Checked, Less, "u32").contains("icmp ult i32"));
+        assert!(lower!(Checked, GreeterEq, "isize").contains("icmp sge i32"));
+        assert!(lower!(Checked, GreeterEq, "usize").contains("icmp uge i32"));
+        assert!(lower!(NoWrap, Eq, "u32").contains("icmp eq i32"));
+    }
+
+    #[test]
+    fn test_unsupported_operands() {
+        let context = Context::create();
+        let module = context.module("test");
+        let builder = context.builder();
+        let int_32 = context.int::<32>();
+
+        let arithmetic = Arithmetic::new(&context, &module, &builder, ArithmeticMode::Checked);
+        let position = Position::new(0, 1, 0, 0, Path::new("test.u"));
+        let binary = |operator, ty| {
+            arithmetic.binary(&operator, ty, int_32.constant(1), int_32.constant(2), &position, "")
+        };
+
+        assert!(
+            binary(Operator::Assignment, PrimitiveType::Int { width: 32, signed: true }).is_none()
+        );
+        assert!(binary(Operator::Plus, PrimitiveType::Bool).is_none());
+        assert!(binary(Operator::Plus, PrimitiveType::Float { width: 64 }).is_none());
+        assert!(binary(Operator::Less, PrimitiveType::Float { width: 64 }).is_none());
+    }
+
+    #[test]
+    fn test_vector_binary() {
+        let context = Context::create();
+        let module = context.module("test");
+        let builder = context.builder();
+
+        let vec4 = context.vector(context.int::<32>().into(), 4).unwrap();
+        let ty = context.function(&[vec4.into(), vec4.into()], vec4.into());
+        let function = module.add_function("test", ty);
+        builder.position_at_end(&function.append_basic_block("entry"));
+
+        let arithmetic = Arithmetic::new(&context, &module, &builder, ArithmeticMode::Checked);
+        let binary = |operator| {
+            arithmetic.vector_binary(
+                &operator,
+                PrimitiveType::Int { width: 32, signed: true },
+                VectorValue::from(function.param(0)),
+                VectorValue::from(function.param(1)),
+                "",
+            )
+        };
+
+        assert!(binary(Operator::Division).is_none());
+        assert!(binary(Operator::Less).is_none());
+        builder.ret(binary(Operator::Plus).unwrap());
+
+        module.verify().unwrap();
+        let ir = module.print_to_string().to_string_lossy().into_owned();
+        assert!(ir.contains("add <4 x i32> %0, %1"));
+    }
+
+    #[test]
+    fn test_float_binary() {
+        let context = Context::create();
+        let module = context.module("test");
+        let builder = context.builder();
+
+        let f32 = context.f32();
+        let ty = context.function(&[f32.into(), f32.into()], f32.into());
+        let function = module.add_function("test", ty);
+        builder.position_at_end(&function.append_basic_block("entry"));
+
+        let arithmetic = Arithmetic::new(&context, &module, &builder, ArithmeticMode::Checked);
+        let binary = |operator| {
+            arithmetic.float_binary(
+                &operator,
+                FloatValue::from(function.param(0)),
+                FloatValue::from(function.param(1)),
+                "",
+            )
+        };
+
+        assert!(binary(Operator::Less).is_some());
+        assert!(binary(Operator::Assignment).is_none());
+        builder.ret(binary(Operator::Division).unwrap());
+
+        module.verify().unwrap();
+        let ir = module.print_to_string().to_string_lossy().into_owned();
+        assert!(ir.contains("fcmp olt float %0, %1"));
+        assert!(ir.contains("fdiv float %0, %1"));
+    }
+
+    #[test]
+    fn test_cast() {
+        let context = Context::create();
+        let module = context.module("test");
+        let builder = context.builder();
+
+        let int_8 = context.int::<8>();
+        let ty = context.function(&[int_8.into(), context.f64().into()], context.void().into());
+        let function = module.add_function("test", ty);
+        builder.position_at_end(&function.append_basic_block("entry"));
+
+        let primitive = |name| PrimitiveType::from_name(name).unwrap();
+        let byte = function.param(0);
+        let float = function.param(1);
+        let int_64 = context.int::<64>().into();
+
+        cast(&builder, byte.clone(), primitive("i8"), primitive("i64"), int_64, "sext").unwrap();
+        cast(&builder, byte.clone(), primitive("u8"), primitive("i64"), int_64, "zext").unwrap();
+        cast(
+            &builder,
+            byte.clone(),
+            primitive("u8"),
+            primitive("f32"),
+            context.f32().into(),
+            "uitofp",
+        )
+        .unwrap();
+        cast(&builder, float.clone(), primitive("f64"), primitive("u64"), int_64, "fptoui")
+            .unwrap();
+        cast(&builder, float.clone(), primitive("f64"), primitive("i8"), int_8.into(), "fptosi")
+            .unwrap();
+
+        let bool = context.int::<1>().into();
+        assert!(cast(&builder, byte, primitive("i8"), primitive("bool"), bool, "").is_err());
+        assert!(cast(&builder, float, primitive("f64"), primitive("bool"), bool, "").is_err());
+        builder.ret_void();
+
+        module.verify().unwrap();
+        let ir = module.print_to_string().to_string_lossy().into_owned();
+        assert!(ir.contains("%sext = sext i8 %0 to i64"));
+        assert!(ir.contains("%zext = zext i8 %0 to i64"));
+        assert!(ir.contains("%uitofp = uitofp i8 %0 to float"));
+        assert!(ir.contains("%fptoui = fptoui double %1 to i64"));
+        assert!(ir.contains("%fptosi = fptosi double %1 to i8"));
+    }
+}
@@ -4,11 +4,13 @@ use std::result;
 use owo_colors::{OwoColorize, Stream};
 
 use crate::lexer::{error::ErrorKind as LexerErrorKind, token::Chunk};
+use crate::lowering::error::ErrorKind as LoweringErrorKind;
 use crate::parser::error::ErrorKind as ParserErrorKind;
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ErrorKind {
     Lexer(LexerErrorKind),
     Parser(ParserErrorKind),
+    Lowering(LoweringErrorKind),
 }
 
 impl ErrorKind {
@@ -22,6 +24,7 @@ impl Display for ErrorKind {
         match self {
             ErrorKind::Lexer(lexer_error_kind) => write!(f, "{lexer_error_kind}"),
             ErrorKind::Parser(parser_error_kind) => write!(f, "{parser_error_kind}"),
+            ErrorKind::Lowering(lowering_error_kind) => write!(f, "{lowering_error_kind}"),
         }
     }
 }
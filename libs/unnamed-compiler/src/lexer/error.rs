@@ -4,6 +4,7 @@ use std::fmt::{self, Display};
 pub enum ErrorKind {
     UnexpectedToken,
     TooManyFloatingPoints(u8),
+    UnterminatedString,
 }
 impl Display for ErrorKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -12,6 +13,7 @@ impl Display for ErrorKind {
             ErrorKind::TooManyFloatingPoints(points) => {
                 write!(f, "Too many decimal points, expected 1, received {points}")
             }
+            ErrorKind::UnterminatedString => write!(f, "unterminated string literal"),
         }
     }
 }
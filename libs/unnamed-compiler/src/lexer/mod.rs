@@ -41,6 +41,10 @@ impl<'source> Lexer<'source> {
         is_xid_continue(self.cursor.peek())
     }
 
+    pub fn is_string_start(&mut self) -> bool {
+        self.cursor.peek() == '"'
+    }
+
     pub fn is_whitespace(&mut self) -> bool {
         self.cursor.peek().is_whitespace()
     }
@@ -65,6 +69,28 @@ impl<'source> Lexer<'source> {
         }
     }
 
+    pub fn lex_string(&mut self) -> Result<'source, Token<'source>> {
+        self.cursor.next_char();
+        loop {
+            if self.cursor.is_eof() {
+                return Err(Error::new(
+                    CommonErrorKind::Lexer(ErrorKind::UnterminatedString),
+                    Some(self.cursor.chunk()),
+                ));
+            }
+
+            match self.cursor.next_char() {
+                '"' => break,
+                '\\' if !self.cursor.is_eof() => {
+                    self.cursor.next_char();
+                }
+                _ => {}
+            }
+        }
+
+        Ok(Token::new(TokenKind::Str, self.cursor.chunk()))
+    }
+
     pub fn skip_whitespaces(&mut self) {
         while !self.cursor.is_eof() && self.is_whitespace() {
             self.cursor.next_char();
@@ -89,6 +115,10 @@ impl<'source> Lexer<'source> {
             "mut" => Token::new(TokenKind::MutKw, chunk),
             "true" => Token::new(TokenKind::TrueKw, chunk),
             "false" => Token::new(TokenKind::FalseKw, chunk),
+            "asm" => Token::new(TokenKind::AsmKw, chunk),
+            "as" => Token::new(TokenKind::AsKw, chunk),
+            "enum" => Token::new(TokenKind::EnumKw, chunk),
+            "match" => Token::new(TokenKind::MatchKw, chunk),
             _ => Token::new(TokenKind::Identifier, chunk),
         })
     }
@@ -106,6 +136,8 @@ impl<'source> Lexer<'source> {
             ';' => TokenKind::Semicolon,
             '{' => TokenKind::LeftBrace,
             '}' => TokenKind::RightBrace,
+            '!' => TokenKind::Bang,
+            '&' => TokenKind::Ampersand,
             '>' => {
                 if !self.cursor.is_eof() && self.cursor.peek() == '=' {
                     self.cursor.next_char();
@@ -126,6 +158,9 @@ impl<'source> Lexer<'source> {
                 if !self.cursor.is_eof() && self.cursor.peek() == '=' {
                     self.cursor.next_char();
                     TokenKind::Eq
+                } else if !self.cursor.is_eof() && self.cursor.peek() == '>' {
+                    self.cursor.next_char();
+                    TokenKind::FatArrow
                 } else {
                     TokenKind::Assignment
                 }
@@ -148,6 +183,9 @@ impl<'source> Lexer<'source> {
         if self.is_identifier_start() {
             return self.lex_identifier();
         }
+        if self.is_string_start() {
+            return self.lex_string();
+        }
 
         self.lex_special_symbols()
     }
@@ -222,6 +260,19 @@ mod tests {
         test_mut_kw("mut") = MutKw: "mut" at 0..3;
         test_true_kw("true") = TrueKw: "true" at 0..4;
         test_false_kw("false") = FalseKw: "false" at 0..5;
+        test_asm_kw("asm") = AsmKw: "asm" at 0..3;
+        test_in_identifier("in") = Identifier: "in" at 0..2;
+        test_out_identifier("out") = Identifier: "out" at 0..3;
+        test_inout_identifier("inout") = Identifier: "inout" at 0..5;
+        test_as_kw("as") = AsKw: "as" at 0..2;
+        test_enum_kw("enum") = EnumKw: "enum" at 0..4;
+        test_match_kw("match") = MatchKw: "match" at 0..5;
+        test_fat_arrow("=>") = FatArrow: "=>" at 0..2;
+        test_assignment_then_greeter("= >") = Assignment: "=" at 0..1, Greeter: ">" at 2..3;
+        test_bang("!") = Bang: "!" at 0..1;
+        test_ampersand("&") = Ampersand: "&" at 0..1;
+        test_string("\"nop\"") = Str: "\"nop\"" at 0..5;
+        test_string_escape("\"a\\\"b\"") = Str: "\"a\\\"b\"" at 0..6;
         test_skip_whitespaces("  123  456  ") = Integer: "123" at 2..5, Integer: "456" at 7..10;
         test_complex("2 + 2 * 2") = Integer: "2" at 0..1, Plus: "+" at 2..3, Integer: "2" at 4..5, Multiply: "*" at 6..7, Integer: "2" at 8..9;
     );
@@ -240,6 +291,13 @@ mod tests {
         assert!(lexer.next().unwrap().is_err())
     }
 
+    #[test]
+    fn test_unterminated_string() {
+        let cursor = Cursor::new("\"nop", Path::new("main.u"));
+        let mut lexer = Lexer::new(cursor);
+        assert!(lexer.next().unwrap().is_err())
+    }
+
     #[test]
     fn test_empty() {
         let cursor = Cursor::new("", &Path::new("main.u"));
@@ -24,6 +24,18 @@ impl<'source> Position<'source> {
     pub fn new(start: usize, end: usize, line: usize, column: usize, path: &'source Path) -> Self {
         Self { start, end, line, column, path }
     }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn path(&self) -> &'source Path {
+        self.path
+    }
 }
 
 impl<'source> Add for Position<'source> {
@@ -75,6 +87,7 @@ pub enum TokenKind {
     Integer,
     Float,
     Identifier,
+    Str,
 
     Plus,
     Minus,
@@ -86,6 +99,9 @@ pub enum TokenKind {
     GreeterEq,
     Eq,
     Assignment,
+    FatArrow,
+    Bang,
+    Ampersand,
 
     Comma,
     LeftParenthesis,
@@ -104,6 +120,10 @@ pub enum TokenKind {
     ReturnKw,
     TrueKw,
     FalseKw,
+    AsmKw,
+    AsKw,
+    EnumKw,
+    MatchKw,
 }
 
 impl Display for TokenKind {
@@ -138,6 +158,14 @@ impl Display for TokenKind {
             TokenKind::ReturnKw => write!(f, "'return'"),
             TokenKind::TrueKw => write!(f, "'true'"),
             TokenKind::FalseKw => write!(f, "'false'"),
+            TokenKind::Str => write!(f, "{{string}}"),
+            TokenKind::Bang => write!(f, "'!'"),
+            TokenKind::Ampersand => write!(f, "'&'"),
+            TokenKind::AsmKw => write!(f, "'asm'"),
+            TokenKind::AsKw => write!(f, "'as'"),
+            TokenKind::FatArrow => write!(f, "'=>'"),
+            TokenKind::EnumKw => write!(f, "'enum'"),
+            TokenKind::MatchKw => write!(f, "'match'"),
         }
     }
 }
@@ -0,0 +1,179 @@
+use llvm::{
+    types::Type as LlvmType,
+    values::inline_asm::{InlineAsm, InlineAsmDialect},
+};
+
+use crate::{
+    common::error::Result,
+    parser::{
+        expressions::{
+            asm_expr::{AsmDirection, AsmExpression},
+            Expression, Literal,
+        },
+        span::Span,
+    },
+};
+
+use super::{error::ErrorKind, error_at, Compiler, Ty, Typed};
+
+struct Output<'ctx, 'source> {
+    name: &'source str,
+    span: Span<'source>,
+    ty: Ty,
+    tied: Option<Typed<'ctx>>,
+}
+
+impl<'a, 'ctx, 'source> Compiler<'a, 'ctx, 'source> {
+    /// Outputs come first in LLVM's operand list, then inputs, then the inputs tied to `inout`
+    /// outputs. The `{n}` placeholders of the template count operands in source order, so they
+    /// are renumbered on the way.
+    pub(super) fn lower_asm(
+        &mut self,
+        expression: &AsmExpression<'source>,
+    ) -> Result<'source, Typed<'ctx>> {
+        let output_count =
+            expression.operands().iter().filter(|operand| operand.direction != AsmDirection::In);
+        let output_count = output_count.count();
+
+        let mut outputs = vec![];
+        let mut inputs = vec![];
+        let mut output_constraints = vec![];
+        let mut input_constraints = vec![];
+        let mut numbers = vec![];
+
+        for operand in expression.operands() {
+            let constraint = match operand.register.inner.0 {
+                "reg" => "r".to_owned(),
+                register => format!("{{{register}}}"),
+            };
+
+            if operand.direction == AsmDirection::In {
+                let value = self.lower_expression(&operand.expression, None)?;
+                match &value.ty {
+                    Ty::Never => return Ok(value),
+                    ty if self.llvm_type(ty).is_none() => {
+                        return Err(error_at(
+                            ErrorKind::InvalidAsmOperand,
+                            operand.expression.span(),
+                        ));
+                    }
+                    _ => {}
+                }
+
+                numbers.push(output_count + inputs.len());
+                input_constraints.push(constraint);
+                inputs.push(value);
+                continue;
+            }
+
+            let Expression::Literal { literal: Literal::Identifier(ident), span } =
+                &operand.expression
+            else {
+                return Err(error_at(ErrorKind::InvalidAssignment, operand.expression.span()));
+            };
+            let Some(variable) = self.variable(ident.0) else {
+                return Err(error_at(ErrorKind::UnknownVariable, *span));
+            };
+            let Some(ty) = variable.ty().cloned() else {
+                return Err(error_at(ErrorKind::UninitializedVariable, *span));
+            };
+            if self.llvm_type(&ty).is_none() {
+                return Err(error_at(ErrorKind::InvalidAsmOperand, *span));
+            }
+
+            let tied = match operand.direction {
+                AsmDirection::InOut => Some(self.lower_expression(&operand.expression, None)?),
+                _ => None,
+            };
+
+            numbers.push(outputs.len());
+            output_constraints.push(format!("={constraint}"));
+            outputs.push(Output { name: ident.0, span: *span, ty, tied });
+        }
+
+        let template = expression.arguments.inner.template.value();
+        let Some(template) = renumber(&template, &numbers) else {
+            return Err(error_at(ErrorKind::InvalidAsmTemplate, expression.span));
+        };
+
+        let tied = outputs.iter().enumerate().filter_map(|(index, output)| {
+            output.tied.clone().map(|value| (index.to_string(), value))
+        });
+        let (tied_constraints, tied): (Vec<_>, Vec<_>) = tied.unzip();
+        let constraints =
+            [output_constraints, input_constraints, tied_constraints].concat().join(",");
+
+        let arguments = inputs.iter().chain(&tied).collect::<Vec<_>>();
+        let params =
+            arguments.iter().map(|value| self.llvm_type(&value.ty).unwrap()).collect::<Vec<_>>();
+        let mut results =
+            outputs.iter().map(|output| self.llvm_type(&output.ty).unwrap()).collect::<Vec<_>>();
+        let return_ty: LlvmType = match results.len() {
+            0 => self.context.void().into(),
+            1 => results.remove(0),
+            _ => self.context.structure(&results, false).into(),
+        };
+
+        let function_type = self.context.function(&params, return_ty);
+        let asm = InlineAsm::new(
+            function_type,
+            &template,
+            &constraints,
+            InlineAsmDialect::Att,
+            true,
+            false,
+        );
+        let arguments = arguments.iter().map(|value| value.value()).collect::<Vec<_>>();
+
+        self.set_location(expression.span);
+        let call = self.builder.inline_asm_call(&asm, &arguments, "");
+
+        let single = outputs.len() == 1;
+        for (index, output) in outputs.into_iter().enumerate() {
+            let value = if single {
+                call.value()
+            } else {
+                self.builder.extract_value(call.value(), index as u32, "")
+            };
+            self.assign(output.name, output.span, Typed::new(output.ty, value))?;
+        }
+
+        Ok(Typed::unit())
+    }
+}
+
+/// Turns `{n}` placeholders into LLVM's `${m}` and escapes literal `$`. `{{` and `}}` stand for
+/// plain braces.
+fn renumber(template: &str, numbers: &[usize]) -> Option<String> {
+    let mut result = String::with_capacity(template.len());
+
+    let mut chars = template.chars().peekable();
+    while let Some(char) = chars.next() {
+        match char {
+            '$' => result.push_str("$$"),
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                result.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                result.push('}');
+            }
+            '{' => {
+                let mut index = String::new();
+                loop {
+                    match chars.next()? {
+                        '}' => break,
+                        char => index.push(char),
+                    }
+                }
+                let number = numbers.get(index.parse::<usize>().ok()?)?;
+                result.push_str(&format!("${{{number}}}"));
+            }
+            '}' => return None,
+            char => result.push(char),
+        }
+    }
+
+    Some(result)
+}
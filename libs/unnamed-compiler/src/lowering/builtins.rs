@@ -0,0 +1,83 @@
+use llvm::values::Value;
+
+use crate::{
+    codegen::cast,
+    common::error::Result,
+    parser::{expressions::Expression, span::Span, types::PrimitiveType},
+    runtime::Builtin,
+};
+
+use super::{error::ErrorKind, error_at, Compiler, Signature, Ty, Typed};
+
+const I64: PrimitiveType = PrimitiveType::Int { width: 64, signed: true };
+const F64: PrimitiveType = PrimitiveType::Float { width: 64 };
+
+impl<'a, 'ctx, 'source> Compiler<'a, 'ctx, 'source> {
+    pub(super) fn builtin_signature(&self, builtin: Builtin) -> Signature<'ctx> {
+        let bytes =
+            Ty::Pointer(Box::new(Ty::Primitive(PrimitiveType::Int { width: 8, signed: false })));
+        let (params, return_ty) = match builtin {
+            Builtin::Alloc => (vec![Ty::Primitive(PrimitiveType::Size { signed: false })], bytes),
+            Builtin::Free => (vec![bytes], Ty::Unit),
+            Builtin::PrintInt => (vec![Ty::Primitive(I64)], Ty::Unit),
+            Builtin::PrintFloat => (vec![Ty::Primitive(F64)], Ty::Unit),
+            Builtin::PrintStr => (vec![Ty::Str], Ty::Unit),
+            Builtin::Panic => (vec![Ty::Str], Ty::Never),
+        };
+
+        Signature { function: builtin.declare(self.context, self.module), params, return_ty }
+    }
+
+    /// The runtime takes a `str` as two arguments, the pointer and the length.
+    pub(super) fn runtime_arguments(&self, value: &Typed<'ctx>) -> Vec<Value<'ctx>> {
+        match value.ty {
+            Ty::Str => {
+                (0..2).map(|index| self.builder.extract_value(value.value(), index, "")).collect()
+            }
+            _ => vec![value.value()],
+        }
+    }
+
+    /// `print` forwards its argument to the runtime function for its type. Integers are printed
+    /// as `i64`, floats as `f64` and booleans as `true` or `false`.
+    pub(super) fn lower_print(
+        &mut self,
+        arguments: &[Expression<'source>],
+        span: Span<'source>,
+    ) -> Result<'source, Typed<'ctx>> {
+        let [argument] = arguments else {
+            return Err(error_at(
+                ErrorKind::ArgumentCount { expected: 1, received: arguments.len() },
+                span,
+            ));
+        };
+
+        let value = self.lower_expression(argument, None)?;
+        self.set_location(span);
+        let (builtin, value) = match &value.ty {
+            Ty::Never => return Ok(value),
+            Ty::Str => (Builtin::PrintStr, value),
+            Ty::Primitive(PrimitiveType::Bool) => {
+                let (then_value, else_value) = (self.const_str("true"), self.const_str("false"));
+                let value = self.builder.select(value.value(), then_value, else_value, "");
+                (Builtin::PrintStr, Typed::new(Ty::Str, value))
+            }
+            Ty::Primitive(primitive) => {
+                let to = if primitive.is_integer() { I64 } else { F64 };
+                let llvm_type = self.llvm_type(&Ty::Primitive(to)).unwrap();
+                let value =
+                    cast(self.builder, value.value(), *primitive, to, llvm_type, "").unwrap();
+
+                let builtin = if to == I64 { Builtin::PrintInt } else { Builtin::PrintFloat };
+                (builtin, Typed::new(Ty::Primitive(to), value))
+            }
+            _ => return Err(error_at(ErrorKind::Unprintable, argument.span())),
+        };
+
+        let function = builtin.declare(self.context, self.module);
+        let arguments = self.runtime_arguments(&value);
+        self.builder.call(function.function_type(), &function, &arguments, "");
+
+        Ok(Typed::unit())
+    }
+}
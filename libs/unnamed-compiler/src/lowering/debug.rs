@@ -0,0 +1,122 @@
+use std::path::Path;
+
+use llvm::{
+    builder::Builder,
+    debug_info::{DIFile, DISubprogram, DIType, DebugInfoBuilder, SourceLanguage, TypeEncoding},
+    module::Module,
+    target::TargetData,
+    values::{function::FunctionValue, pointer::PointerValue},
+};
+
+use crate::parser::{span::Span, types::PrimitiveType};
+
+use super::types::Ty;
+
+// DWARF has no language code for us; C is the closest match for debuggers.
+const SOURCE_LANGUAGE: SourceLanguage = SourceLanguage::C;
+
+pub(super) struct DebugInfo<'a, 'ctx> {
+    builder: DebugInfoBuilder<'a, 'ctx>,
+    file: DIFile<'ctx>,
+    pointer_bits: u32,
+    subprogram: Option<DISubprogram<'ctx>>,
+}
+
+impl<'a, 'ctx> DebugInfo<'a, 'ctx> {
+    pub fn new(
+        module: &'a Module<'ctx>,
+        target_data: &TargetData,
+        path: &Path,
+        optimized: bool,
+    ) -> Self {
+        let builder = module.debug_info_builder();
+
+        let filename = path.file_name().unwrap_or_default().to_string_lossy();
+        let directory = match path.parent() {
+            Some(parent) if parent != Path::new("") => parent.to_string_lossy(),
+            _ => ".".into(),
+        };
+        let file = builder.create_file(&filename, &directory);
+        builder.create_compile_unit(&file, SOURCE_LANGUAGE, "unnamed", optimized);
+
+        let pointer_bits = target_data.pointer_size(0) * 8;
+
+        Self { builder, file, pointer_bits, subprogram: None }
+    }
+
+    pub fn enter_function(
+        &mut self,
+        function: &FunctionValue<'ctx>,
+        name: &str,
+        params: &[Ty],
+        return_ty: &Ty,
+        span: Span<'_>,
+    ) {
+        let params = params.iter().filter_map(|param| self.di_type(param)).collect::<Vec<_>>();
+        let return_ty = self.di_type(return_ty);
+        let ty = self.builder.create_subroutine_type(&self.file, return_ty.as_ref(), &params);
+
+        let line = span.position().line() as u32 + 1;
+        let subprogram =
+            self.builder.create_function(self.file.into(), name, &self.file, line, &ty, false);
+
+        function.set_subprogram(&subprogram);
+        self.subprogram = Some(subprogram);
+    }
+
+    pub fn set_location(&self, builder: &Builder<'ctx>, span: Span<'_>) {
+        let (line, column) = line_column(span);
+        let location = self.builder.create_debug_location(line, column, self.scope().into());
+
+        builder.set_debug_location(&location);
+    }
+
+    pub fn declare_variable(
+        &self,
+        builder: &Builder<'ctx>,
+        name: &str,
+        pointer: &PointerValue<'ctx>,
+        ty: &Ty,
+        span: Span<'_>,
+    ) {
+        let Some(ty) = self.di_type(ty) else {
+            return;
+        };
+
+        let (line, column) = line_column(span);
+        let variable =
+            self.builder.create_auto_variable(self.scope().into(), name, &self.file, line, &ty);
+        let location = self.builder.create_debug_location(line, column, self.scope().into());
+        let block = builder.get_insert_block().expect("builder is not positioned in a basic block");
+
+        self.builder.insert_declare_at_end(pointer, &variable, &location, &block);
+    }
+
+    pub fn finalize(&self) {
+        self.builder.finalize();
+    }
+
+    fn scope(&self) -> DISubprogram<'ctx> {
+        self.subprogram.expect("debug location outside of a function")
+    }
+
+    fn di_type(&self, ty: &Ty) -> Option<DIType<'ctx>> {
+        let (bits, encoding) = match ty.primitive()? {
+            PrimitiveType::Bool => (8, TypeEncoding::Boolean),
+            PrimitiveType::Int { width, signed: true } => (width, TypeEncoding::Signed),
+            PrimitiveType::Int { width, signed: false } => (width, TypeEncoding::Unsigned),
+            PrimitiveType::Size { signed: true } => (self.pointer_bits, TypeEncoding::Signed),
+            PrimitiveType::Size { signed: false } => (self.pointer_bits, TypeEncoding::Unsigned),
+            PrimitiveType::Float { width } => (width, TypeEncoding::Float),
+        };
+
+        Some(self.builder.create_basic_type(&ty.to_string(), bits as u64, encoding))
+    }
+}
+
+fn line_column(span: Span<'_>) -> (u32, u32) {
+    let position = span.position();
+    let length = span.chunk().slice.chars().count();
+
+    (position.line() as u32 + 1, (position.column() + 1).saturating_sub(length) as u32)
+}
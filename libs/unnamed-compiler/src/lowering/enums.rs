@@ -0,0 +1,257 @@
+use std::collections::{HashMap, HashSet};
+
+use llvm::{
+    types::{array::ArrayType, structure::StructType, Type as LlvmType},
+    values::Value,
+};
+
+use crate::{
+    common::error::Result,
+    parser::{
+        enumeration::Enum,
+        expressions::{match_expr::MatchExpression, Expression},
+        item::Program,
+        span::Span,
+    },
+};
+
+use super::{error::ErrorKind, error_at, types::int_type, Compiler, Ty, Typed, Variable};
+
+#[derive(Debug, Clone)]
+pub(super) struct EnumType<'source> {
+    pub declaration: Enum<'source>,
+    /// The field types of every variant, in declaration order. A variant's index is its tag.
+    pub variants: Vec<Vec<Ty>>,
+}
+
+impl<'a, 'ctx, 'source> Compiler<'a, 'ctx, 'source> {
+    /// Every enum is registered before the field types are resolved, so that enums can refer to
+    /// each other in any order.
+    pub(super) fn declare_enums(&mut self, program: &Program<'source>) -> Result<'source, ()> {
+        for enumeration in program.enums() {
+            let name = enumeration.identifier.0;
+            if self.enums.contains_key(name) {
+                return Err(error_at(ErrorKind::DuplicateType, enumeration.span));
+            }
+
+            for (tag, variant) in enumeration.variants.inner.elements.iter().enumerate() {
+                if self.variants.insert(variant.ident.0, (name, tag)).is_some() {
+                    return Err(error_at(ErrorKind::DuplicateVariant, enumeration.span));
+                }
+            }
+
+            let declaration = enumeration.clone();
+            self.enums.insert(name, EnumType { declaration, variants: vec![] });
+        }
+
+        for enumeration in program.enums() {
+            let variants = enumeration.variants.inner.elements.iter().map(|variant| {
+                variant.fields().iter().map(|ty| self.lower_type(ty, enumeration.span)).collect()
+            });
+            let variants = variants.collect::<Result<Vec<_>>>()?;
+
+            self.enums.get_mut(enumeration.identifier.0).unwrap().variants = variants;
+        }
+
+        for enumeration in program.enums() {
+            let name = enumeration.identifier.0;
+            if self.holds(name, name, &mut HashSet::new()) {
+                return Err(error_at(ErrorKind::RecursiveType, enumeration.span));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Whether a value of enum `name` contains a `target` by value, directly or through other
+    /// enums. Such a type would have an infinite size.
+    fn holds<'e>(&'e self, name: &str, target: &str, visited: &mut HashSet<&'e str>) -> bool {
+        self.enums[name].variants.iter().flatten().any(|ty| match ty {
+            Ty::Enum(field) if field == target => true,
+            Ty::Enum(field) => visited.insert(field) && self.holds(field, target, visited),
+            _ => false,
+        })
+    }
+
+    /// `{ i32, [n x T] }`, the tag followed by a payload that every variant fits in.
+    pub(super) fn enum_type(&self, name: &str) -> StructType<'ctx> {
+        let tag = self.context.int::<32>().into();
+
+        self.context.structure(&[tag, self.payload_type(name).into()], false)
+    }
+
+    fn variant_type(&self, fields: &[Ty]) -> StructType<'ctx> {
+        let fields = fields.iter().map(|ty| self.llvm_type(ty).unwrap()).collect::<Vec<_>>();
+
+        self.context.structure(&fields, false)
+    }
+
+    /// The payload is an array of the most aligned field type, or of an integer with the same
+    /// alignment, so that a slot of the payload type can hold any variant.
+    fn payload_type(&self, name: &str) -> ArrayType<'ctx> {
+        let variants = &self.enums[name].variants;
+        let size = variants
+            .iter()
+            .map(|fields| self.target_data.abi_size(self.variant_type(fields).into()));
+        let size = size.max().unwrap_or(0);
+
+        let fields = variants.iter().flatten().map(|ty| self.llvm_type(ty).unwrap());
+        let element = match fields.max_by_key(|ty| self.target_data.abi_alignment(*ty)) {
+            Some(ty) => {
+                let alignment = self.target_data.abi_alignment(ty);
+                match alignment {
+                    1 | 2 | 4 | 8 | 16 => {
+                        let int: LlvmType = int_type(self.context, alignment * 8).into();
+                        if self.target_data.abi_alignment(int) == alignment {
+                            int
+                        } else {
+                            ty
+                        }
+                    }
+                    _ => ty,
+                }
+            }
+            None => self.context.int::<8>().into(),
+        };
+        let len = size.div_ceil(self.target_data.abi_size(element));
+
+        self.context.array(element, len as u32)
+    }
+
+    /// Constructs the variant named `variant`. The fields are stored as the variant's struct and
+    /// read back as the payload.
+    pub(super) fn lower_variant(
+        &mut self,
+        variant: &str,
+        arguments: &[Expression<'source>],
+        span: Span<'source>,
+    ) -> Result<'source, Typed<'ctx>> {
+        let (name, tag) = self.variants[variant];
+        let fields = self.enums[name].variants[tag].clone();
+        if arguments.len() != fields.len() {
+            return Err(error_at(
+                ErrorKind::ArgumentCount { expected: fields.len(), received: arguments.len() },
+                span,
+            ));
+        }
+
+        let mut values = vec![];
+        for (argument, ty) in arguments.iter().zip(&fields) {
+            let value = self.lower_expression(argument, Some(ty))?;
+            match &value.ty {
+                Ty::Never => return Ok(value),
+                argument_ty if argument_ty != ty => {
+                    return Err(error_at(ErrorKind::MismatchedTypes, argument.span()));
+                }
+                _ => values.push(value.value()),
+            }
+        }
+
+        self.set_location(span);
+        let payload_type = self.payload_type(name);
+        let payload = if values.is_empty() {
+            payload_type.poison().into()
+        } else {
+            let mut fields: Value = self.variant_type(&fields).poison().into();
+            for (index, value) in values.into_iter().enumerate() {
+                fields = self.builder.insert_value(fields, value, index as u32, "");
+            }
+
+            let slot = self.builder.entry_alloca(payload_type, "");
+            self.builder.store(fields, slot.clone());
+            self.builder.load(payload_type, slot, "")
+        };
+
+        let tag = self.context.int::<32>().constant(tag as u64);
+        let value = self.builder.insert_value(self.enum_type(name).poison(), tag, 0, "");
+        let value = self.builder.insert_value(value, payload, 1, "");
+
+        Ok(Typed::new(Ty::Enum(name.to_owned()), value))
+    }
+
+    /// Switches on the tag, with one block per arm. Exhaustiveness makes the default block
+    /// unreachable.
+    pub(super) fn lower_match(
+        &mut self,
+        expression: &MatchExpression<'source>,
+        hint: Option<&Ty>,
+    ) -> Result<'source, Typed<'ctx>> {
+        let scrutinee = self.lower_expression(&expression.expression, None)?;
+        let name = match &scrutinee.ty {
+            Ty::Never => return Ok(scrutinee),
+            Ty::Enum(name) => name.clone(),
+            _ => return Err(error_at(ErrorKind::NotAnEnum, expression.expression.span())),
+        };
+        expression.check_exhaustive(&self.enums[name.as_str()].declaration)?;
+
+        self.set_location(expression.span);
+        let tag = self.builder.extract_value(scrutinee.value(), 0, "");
+        let payload = self.builder.extract_value(scrutinee.value(), 1, "");
+
+        let function = self.current_function();
+        let default_block = function.append_basic_block("unreachable");
+        let arm_blocks = expression.arms().iter().map(|_| function.append_basic_block("arm"));
+        let arm_blocks = arm_blocks.collect::<Vec<_>>();
+        let merge_block = function.append_basic_block("merge");
+
+        let cases = expression.arms().iter().zip(&arm_blocks).map(|(arm, block)| {
+            let (_, tag) = self.variants[arm.pattern.variant.0];
+            (self.context.int::<32>().constant(tag as u64), block.clone())
+        });
+        self.builder.switch(tag, &default_block, &cases.collect::<Vec<_>>());
+
+        self.builder.position_at_end(&default_block);
+        self.builder.unreachable();
+
+        let mut values = vec![];
+        for (arm, block) in expression.arms().iter().zip(arm_blocks) {
+            self.builder.position_at_end(&block);
+
+            let (_, tag) = self.variants[arm.pattern.variant.0];
+            let fields = self.enums[name.as_str()].variants[tag].clone();
+            let mut bindings = HashMap::new();
+            if !fields.is_empty() {
+                let slot = self.builder.entry_alloca(self.payload_type(&name), "");
+                self.builder.store(payload.clone(), slot.clone());
+                let variant = self.builder.load(self.variant_type(&fields), slot, "");
+
+                for (index, (binding, ty)) in arm.pattern.bindings().iter().zip(fields).enumerate()
+                {
+                    let value =
+                        self.builder.extract_value(variant.clone(), index as u32, binding.0);
+                    let storage =
+                        self.storage(binding.0, false, Typed::new(ty, value), expression.span);
+                    bindings.insert(binding.0, Variable { mutable: false, storage });
+                }
+            }
+
+            self.scopes.push(bindings);
+            let value = self.lower_expression(&arm.expression, hint)?;
+            self.scopes.pop();
+
+            if value.ty != Ty::Never {
+                values.push((value, self.builder.get_insert_block().unwrap()));
+                self.builder.br(&merge_block);
+            }
+        }
+
+        let Some(ty) = values.first().map(|(value, _)| value.ty.clone()) else {
+            merge_block.delete();
+            return Ok(Typed::never());
+        };
+        if values.iter().any(|(value, _)| value.ty != ty) {
+            return Err(error_at(ErrorKind::MismatchedTypes, expression.span));
+        }
+
+        self.builder.position_at_end(&merge_block);
+        if ty == Ty::Unit {
+            return Ok(Typed::unit());
+        }
+
+        let phi = self.builder.phi(self.llvm_type(&ty).unwrap(), "");
+        let incomming = values.into_iter().map(|(value, block)| (value.value(), block));
+        phi.add_incomming(&incomming.collect::<Vec<_>>());
+
+        Ok(Typed::new(ty, phi.value()))
+    }
+}
@@ -0,0 +1,72 @@
+use std::fmt::{self, Display};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    UnknownType,
+    InvalidVectorElement,
+    UnknownVariable,
+    UnknownFunction,
+    DuplicateFunction,
+    DuplicateType,
+    DuplicateVariant,
+    RecursiveType,
+    NotAnEnum,
+    MismatchedTypes,
+    InvalidOperands,
+    InvalidCast,
+    IntegerOutOfRange,
+    ArgumentCount { expected: usize, received: usize },
+    InvalidAssignment,
+    ImmutableAssignment,
+    SharedReferenceAssignment,
+    MutableBorrow,
+    InvalidReference,
+    InvalidDereference,
+    InvalidAsmOperand,
+    InvalidAsmTemplate,
+    Unprintable,
+    UninitializedVariable,
+    MissingReturnValue,
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::UnknownType => write!(f, "unknown type"),
+            ErrorKind::InvalidVectorElement => {
+                write!(f, "vector elements must be integers or floats")
+            }
+            ErrorKind::UnknownVariable => write!(f, "unknown variable"),
+            ErrorKind::UnknownFunction => write!(f, "unknown function"),
+            ErrorKind::DuplicateFunction => write!(f, "function is defined more than once"),
+            ErrorKind::DuplicateType => write!(f, "type is defined more than once"),
+            ErrorKind::DuplicateVariant => write!(f, "variant is defined more than once"),
+            ErrorKind::RecursiveType => write!(f, "enum contains itself without indirection"),
+            ErrorKind::NotAnEnum => write!(f, "only enums can be matched"),
+            ErrorKind::MismatchedTypes => write!(f, "mismatched types"),
+            ErrorKind::InvalidOperands => write!(f, "operator cannot be applied to these operands"),
+            ErrorKind::InvalidCast => write!(f, "invalid cast"),
+            ErrorKind::IntegerOutOfRange => write!(f, "integer literal does not fit its type"),
+            ErrorKind::ArgumentCount { expected, received } => {
+                write!(f, "expected {expected} arguments, received {received}")
+            }
+            ErrorKind::InvalidAssignment => write!(f, "left-hand side cannot be assigned to"),
+            ErrorKind::ImmutableAssignment => {
+                write!(f, "cannot assign twice to immutable variable")
+            }
+            ErrorKind::SharedReferenceAssignment => {
+                write!(f, "cannot assign through a `&` reference")
+            }
+            ErrorKind::MutableBorrow => write!(f, "cannot borrow immutable variable as mutable"),
+            ErrorKind::InvalidReference => write!(f, "cannot take a reference to this expression"),
+            ErrorKind::InvalidDereference => write!(f, "type cannot be dereferenced"),
+            ErrorKind::InvalidAsmOperand => {
+                write!(f, "type cannot be used as an inline assembly operand")
+            }
+            ErrorKind::InvalidAsmTemplate => write!(f, "invalid inline assembly template"),
+            ErrorKind::Unprintable => write!(f, "type cannot be printed"),
+            ErrorKind::UninitializedVariable => write!(f, "variable is used before assignment"),
+            ErrorKind::MissingReturnValue => write!(f, "function does not return a value"),
+        }
+    }
+}
@@ -0,0 +1,601 @@
+use llvm::{
+    types::{float::FloatType, integer::IntegerType, vector::VectorType},
+    values::{
+        float::FloatValue, integer::IntegerValue, pointer::PointerValue, vector::VectorValue, Value,
+    },
+};
+
+use crate::{
+    codegen::{cast, global_string},
+    common::error::Result,
+    parser::{
+        expressions::{
+            if_expr::{Alternative, IfExpression},
+            while_expr::WhileExpression,
+            Expression, Literal, Operator,
+        },
+        primitive::Identifier,
+        span::Span,
+        types::{PrimitiveType, Type},
+    },
+    runtime::Builtin,
+};
+
+use super::{error::ErrorKind, error_at, types::vector_len, Compiler, Storage, Ty, Typed};
+
+impl<'a, 'ctx, 'source> Compiler<'a, 'ctx, 'source> {
+    pub(super) fn lower_expression(
+        &mut self,
+        expression: &Expression<'source>,
+        hint: Option<&Ty>,
+    ) -> Result<'source, Typed<'ctx>> {
+        match expression {
+            Expression::Literal { literal, span } => self.lower_literal(literal, *span, hint),
+            Expression::Call { ident, arguments, span } => {
+                self.lower_call(ident, &arguments.elements, *span, hint)
+            }
+            Expression::Infix { lhs, operator: Operator::Assignment, rhs, span } => {
+                self.lower_assignment(lhs, rhs, *span)
+            }
+            Expression::Infix { lhs, operator, rhs, span } => {
+                self.lower_infix(lhs, operator, rhs, *span, hint)
+            }
+            Expression::If(expression) => self.lower_if(expression, hint),
+            Expression::While(expression) => self.lower_while(expression),
+            Expression::Cast { expression, ty, span } => self.lower_cast(expression, ty, *span),
+            Expression::Reference { mutable, expression, span } => {
+                self.lower_reference(*mutable, expression, *span, hint)
+            }
+            Expression::Dereference { expression, span } => {
+                self.lower_dereference(expression, *span)
+            }
+            Expression::Asm(expression) => self.lower_asm(expression),
+            Expression::Match(expression) => self.lower_match(expression, hint),
+        }
+    }
+
+    fn lower_literal(
+        &mut self,
+        literal: &Literal<'source>,
+        span: Span<'source>,
+        hint: Option<&Ty>,
+    ) -> Result<'source, Typed<'ctx>> {
+        let bool = self.context.int::<1>();
+
+        Ok(match literal {
+            Literal::True => Typed::new(Ty::BOOL, bool.constant(1).into()),
+            Literal::False => Typed::new(Ty::BOOL, bool.constant(0).into()),
+            Literal::Integer(integer) => {
+                let ty = match hint {
+                    Some(
+                        ty @ Ty::Primitive(PrimitiveType::Int { .. } | PrimitiveType::Size { .. }),
+                    ) => ty.clone(),
+                    _ => Ty::Primitive(PrimitiveType::Int { width: 32, signed: true }),
+                };
+                let primitive = ty.primitive().unwrap();
+                let width = self.int_width(primitive).unwrap();
+
+                let max = u128::MAX >> (128 - width + primitive.is_signed() as u32);
+                if !integer.0.parse::<u128>().is_ok_and(|value| value <= max) {
+                    return Err(error_at(ErrorKind::IntegerOutOfRange, span));
+                }
+
+                let llvm_type = IntegerType::from(self.llvm_type(&ty).unwrap());
+                let value = llvm_type.const_from_str(integer.0, 10).unwrap();
+                Typed::new(ty, value.into())
+            }
+            Literal::Float(float) => {
+                let ty = match hint {
+                    Some(ty @ Ty::Primitive(PrimitiveType::Float { .. })) => ty.clone(),
+                    _ => Ty::Primitive(PrimitiveType::Float { width: 64 }),
+                };
+
+                let llvm_type = FloatType::from(self.llvm_type(&ty).unwrap());
+                let value = llvm_type.constant(float.0.parse().unwrap());
+                Typed::new(ty, value.into())
+            }
+            Literal::Str(string) => Typed::new(Ty::Str, self.const_str(&string.value())),
+            Literal::Identifier(ident) => {
+                let Some(variable) = self.variable(ident.0) else {
+                    if self.variants.contains_key(ident.0) {
+                        return self.lower_variant(ident.0, &[], span);
+                    }
+                    return Err(error_at(ErrorKind::UnknownVariable, span));
+                };
+
+                match &variable.storage {
+                    Storage::Uninitialized => {
+                        return Err(error_at(ErrorKind::UninitializedVariable, span))
+                    }
+                    Storage::Register(value) => value.clone(),
+                    Storage::Stack { pointer, ty } => {
+                        let llvm_type = self.llvm_type(ty).unwrap();
+                        let value = self.builder.load(llvm_type, pointer.clone(), ident.0);
+
+                        Typed::new(ty.clone(), value)
+                    }
+                }
+            }
+        })
+    }
+
+    pub(super) fn const_str(&self, string: &str) -> Value<'ctx> {
+        let global = global_string(self.context, self.module, string);
+        let len = self.context.int_ptr(self.target_data, 0).constant(string.len() as u64);
+
+        self.context.const_struct(&[global.into(), len.into()], false).into()
+    }
+
+    fn lower_call(
+        &mut self,
+        ident: &Identifier<'source>,
+        arguments: &[Expression<'source>],
+        span: Span<'source>,
+        hint: Option<&Ty>,
+    ) -> Result<'source, Typed<'ctx>> {
+        let (signature, builtin) = match self.functions.get(ident.0) {
+            Some(signature) => (signature.clone(), false),
+            None if ident.0 == "print" => return self.lower_print(arguments, span),
+            None if self.variants.contains_key(ident.0) => {
+                return self.lower_variant(ident.0, arguments, span);
+            }
+            None => match (vector_len(ident.0), Builtin::from_name(ident.0)) {
+                (Some(len), _) => return self.lower_vector(len, arguments, span, hint),
+                (None, Some(builtin)) => (self.builtin_signature(builtin), true),
+                (None, None) => return Err(error_at(ErrorKind::UnknownFunction, span)),
+            },
+        };
+
+        if arguments.len() != signature.params.len() {
+            return Err(error_at(
+                ErrorKind::ArgumentCount {
+                    expected: signature.params.len(),
+                    received: arguments.len(),
+                },
+                span,
+            ));
+        }
+
+        let mut values = vec![];
+        for (argument, ty) in arguments.iter().zip(&signature.params) {
+            let value = self.lower_expression(argument, Some(ty))?;
+            match &value.ty {
+                Ty::Never => return Ok(value),
+                argument_ty if argument_ty != ty => {
+                    return Err(error_at(ErrorKind::MismatchedTypes, argument.span()));
+                }
+                _ if builtin => values.extend(self.runtime_arguments(&value)),
+                _ => values.extend(value.value),
+            }
+        }
+
+        self.set_location(span);
+        let function = signature.function;
+        let call = self.builder.call(function.function_type(), &function, &values, "");
+
+        Ok(match signature.return_ty {
+            Ty::Unit => Typed::unit(),
+            Ty::Never => {
+                self.builder.unreachable();
+                Typed::never()
+            }
+            ty => Typed::new(ty, call.value()),
+        })
+    }
+
+    fn lower_vector(
+        &mut self,
+        len: u32,
+        arguments: &[Expression<'source>],
+        span: Span<'source>,
+        hint: Option<&Ty>,
+    ) -> Result<'source, Typed<'ctx>> {
+        if arguments.len() != len as usize {
+            return Err(error_at(
+                ErrorKind::ArgumentCount { expected: len as usize, received: arguments.len() },
+                span,
+            ));
+        }
+
+        let mut element = match hint {
+            Some(Ty::Vector { element, len: hint_len }) if *hint_len == len => {
+                Some(Ty::Primitive(*element))
+            }
+            _ => None,
+        };
+
+        let mut values = vec![];
+        for argument in arguments {
+            let value = self.lower_expression(argument, element.as_ref())?;
+            match (&value.ty, &element) {
+                (Ty::Never, _) => return Ok(value),
+                (ty, Some(element)) if ty != element => {
+                    return Err(error_at(ErrorKind::MismatchedTypes, argument.span()));
+                }
+                (Ty::Primitive(primitive), None) if *primitive != PrimitiveType::Bool => {
+                    element = Some(value.ty.clone())
+                }
+                (_, None) => {
+                    return Err(error_at(ErrorKind::InvalidVectorElement, argument.span()))
+                }
+                _ => {}
+            }
+            values.push(value.value());
+        }
+
+        let ty = Ty::Vector { element: element.unwrap().primitive().unwrap(), len };
+        let vector_type = VectorType::from(self.llvm_type(&ty).unwrap());
+
+        self.set_location(span);
+        let mut vector = vector_type.poison();
+        for (index, value) in values.into_iter().enumerate() {
+            let index = self.context.int::<32>().constant(index as u64);
+            vector = self.builder.insert_element(vector, value, index, "");
+        }
+
+        Ok(Typed::new(ty, vector.into()))
+    }
+
+    fn lower_assignment(
+        &mut self,
+        lhs: &Expression<'source>,
+        rhs: &Expression<'source>,
+        span: Span<'source>,
+    ) -> Result<'source, Typed<'ctx>> {
+        if let Expression::Dereference { expression, span: target } = lhs {
+            return self.lower_store(expression, rhs, *target, span);
+        }
+
+        let Expression::Literal { literal: Literal::Identifier(ident), span: target } = lhs else {
+            return Err(error_at(ErrorKind::InvalidAssignment, span));
+        };
+
+        let Some(variable) = self.variable(ident.0) else {
+            return Err(error_at(ErrorKind::UnknownVariable, *target));
+        };
+        let ty = variable.ty().cloned();
+
+        let value = self.lower_expression(rhs, ty.as_ref())?;
+        match (&value.ty, &ty) {
+            (Ty::Never, _) => return Ok(value),
+            (value_ty, Some(ty)) if value_ty != ty => {
+                return Err(error_at(ErrorKind::MismatchedTypes, span));
+            }
+            _ => {}
+        }
+
+        self.assign(ident.0, *target, value)?;
+
+        Ok(Typed::unit())
+    }
+
+    /// Stores an already type-checked value into the variable `name`.
+    pub(super) fn assign(
+        &mut self,
+        name: &'source str,
+        target: Span<'source>,
+        value: Typed<'ctx>,
+    ) -> Result<'source, ()> {
+        let variable = self.variable(name).unwrap();
+        let storage = match &variable.storage {
+            Storage::Uninitialized => self.storage(name, true, value, target),
+            Storage::Stack { pointer, ty } if variable.mutable => {
+                self.builder.store(value.value(), pointer.clone());
+                Storage::Stack { pointer: pointer.clone(), ty: ty.clone() }
+            }
+            Storage::Register(..) if variable.mutable => Storage::Register(value),
+            Storage::Register(..) | Storage::Stack { .. } => {
+                return Err(error_at(ErrorKind::ImmutableAssignment, target));
+            }
+        };
+        self.variable_mut(name).unwrap().storage = storage;
+
+        Ok(())
+    }
+
+    fn lower_store(
+        &mut self,
+        pointer: &Expression<'source>,
+        rhs: &Expression<'source>,
+        target: Span<'source>,
+        span: Span<'source>,
+    ) -> Result<'source, Typed<'ctx>> {
+        let pointer = self.lower_expression(pointer, None)?;
+        let ty = match &pointer.ty {
+            Ty::Never => return Ok(pointer),
+            Ty::Reference { mutable: false, .. } => {
+                return Err(error_at(ErrorKind::SharedReferenceAssignment, target));
+            }
+            ty => ty.pointee().cloned().ok_or(error_at(ErrorKind::InvalidDereference, target))?,
+        };
+
+        let value = self.lower_expression(rhs, Some(&ty))?;
+        match &value.ty {
+            Ty::Never => return Ok(value),
+            value_ty if *value_ty != ty => return Err(error_at(ErrorKind::MismatchedTypes, span)),
+            _ => {}
+        }
+
+        self.set_location(span);
+        self.builder.store(value.value(), PointerValue::from(pointer.value()));
+
+        Ok(Typed::unit())
+    }
+
+    fn lower_reference(
+        &mut self,
+        mutable: bool,
+        expression: &Expression<'source>,
+        span: Span<'source>,
+        hint: Option<&Ty>,
+    ) -> Result<'source, Typed<'ctx>> {
+        let (pointer, ty) = match expression {
+            Expression::Literal { literal: Literal::Identifier(ident), span: target } => {
+                let Some(variable) = self.variable(ident.0) else {
+                    return Err(error_at(ErrorKind::UnknownVariable, *target));
+                };
+
+                match &variable.storage {
+                    Storage::Uninitialized => {
+                        return Err(error_at(ErrorKind::UninitializedVariable, *target));
+                    }
+                    Storage::Stack { .. } if mutable && !variable.mutable => {
+                        return Err(error_at(ErrorKind::MutableBorrow, span));
+                    }
+                    Storage::Stack { pointer, ty } => (pointer.clone(), ty.clone()),
+                    Storage::Register(..) => {
+                        return Err(error_at(ErrorKind::InvalidReference, span));
+                    }
+                }
+            }
+            expression => {
+                // Temporaries get their own slot, like `&5` in Rust.
+                let value = self.lower_expression(expression, hint.and_then(Ty::pointee))?;
+                if value.ty == Ty::Never {
+                    return Ok(value);
+                }
+                let Some(llvm_type) = self.llvm_type(&value.ty) else {
+                    return Err(error_at(ErrorKind::InvalidReference, span));
+                };
+
+                let pointer = self.builder.entry_alloca(llvm_type, "");
+                self.builder.store(value.value(), pointer.clone());
+                (pointer, value.ty)
+            }
+        };
+
+        Ok(Typed::new(Ty::Reference { mutable, ty: Box::new(ty) }, pointer.into()))
+    }
+
+    fn lower_dereference(
+        &mut self,
+        expression: &Expression<'source>,
+        span: Span<'source>,
+    ) -> Result<'source, Typed<'ctx>> {
+        let pointer = self.lower_expression(expression, None)?;
+        if pointer.ty == Ty::Never {
+            return Ok(pointer);
+        }
+
+        let Some(ty) = pointer.ty.pointee().cloned() else {
+            return Err(error_at(ErrorKind::InvalidDereference, span));
+        };
+        let Some(llvm_type) = self.llvm_type(&ty) else {
+            return Err(error_at(ErrorKind::InvalidDereference, span));
+        };
+
+        self.set_location(span);
+        let value = self.builder.load(llvm_type, PointerValue::from(pointer.value()), "");
+
+        Ok(Typed::new(ty, value))
+    }
+
+    fn lower_infix(
+        &mut self,
+        lhs: &Expression<'source>,
+        operator: &Operator,
+        rhs: &Expression<'source>,
+        span: Span<'source>,
+        hint: Option<&Ty>,
+    ) -> Result<'source, Typed<'ctx>> {
+        let hint = if operator.is_comparison() { None } else { hint.cloned() };
+        let lhs_hint = match lhs {
+            Expression::Literal { literal: Literal::Integer(..) | Literal::Float(..), .. } => {
+                self.peek_type(rhs).or(hint)
+            }
+            _ => hint,
+        };
+
+        let lhs = self.lower_expression(lhs, lhs_hint.as_ref())?;
+        if lhs.ty == Ty::Never {
+            return Ok(lhs);
+        }
+
+        let rhs = self.lower_expression(rhs, Some(&lhs.ty))?;
+        match &rhs.ty {
+            Ty::Never => return Ok(rhs),
+            ty if *ty != lhs.ty => return Err(error_at(ErrorKind::MismatchedTypes, span)),
+            _ => {}
+        }
+
+        self.set_location(span);
+        let result_ty = if operator.is_comparison() { Ty::BOOL } else { lhs.ty.clone() };
+        let value = match &lhs.ty {
+            Ty::Primitive(primitive) if primitive.is_integer() => self
+                .arithmetic
+                .binary(
+                    operator,
+                    *primitive,
+                    IntegerValue::from(lhs.value()),
+                    IntegerValue::from(rhs.value()),
+                    span.position(),
+                    "",
+                )
+                .map(Into::into),
+            Ty::Primitive(PrimitiveType::Float { .. }) => self.arithmetic.float_binary(
+                operator,
+                FloatValue::from(lhs.value()),
+                FloatValue::from(rhs.value()),
+                "",
+            ),
+            Ty::Vector { element, .. } => self
+                .arithmetic
+                .vector_binary(
+                    operator,
+                    *element,
+                    VectorValue::from(lhs.value()),
+                    VectorValue::from(rhs.value()),
+                    "",
+                )
+                .map(Into::into),
+            _ => None,
+        };
+
+        match value {
+            Some(value) => Ok(Typed::new(result_ty, value)),
+            None => Err(error_at(ErrorKind::InvalidOperands, span)),
+        }
+    }
+
+    fn lower_cast(
+        &mut self,
+        expression: &Expression<'source>,
+        ty: &Type<'source>,
+        span: Span<'source>,
+    ) -> Result<'source, Typed<'ctx>> {
+        let target = self.lower_type(ty, span)?;
+        let value = self.lower_expression(expression, None)?;
+        if value.ty == Ty::Never || value.ty == target {
+            return Ok(value);
+        }
+
+        let (Some(from), Some(to)) = (value.ty.primitive(), target.primitive()) else {
+            return Err(error_at(ErrorKind::InvalidCast, span));
+        };
+        if !from.can_cast_to(to) {
+            return Err(error_at(ErrorKind::InvalidCast, span));
+        }
+
+        self.set_location(span);
+        let llvm_type = self.llvm_type(&target).unwrap();
+        let value = cast(self.builder, value.value(), from, to, llvm_type, "")
+            .map_err(|_| error_at(ErrorKind::InvalidCast, span))?;
+
+        Ok(Typed::new(target, value))
+    }
+
+    fn lower_if(
+        &mut self,
+        expression: &IfExpression<'source>,
+        hint: Option<&Ty>,
+    ) -> Result<'source, Typed<'ctx>> {
+        let condition = self.lower_condition(&expression.expression)?;
+        let Some(condition) = condition else {
+            return Ok(Typed::never());
+        };
+
+        let function = self.current_function();
+        let then_block = function.append_basic_block("then");
+        let else_block = function.append_basic_block("else");
+        let merge_block = function.append_basic_block("merge");
+        self.builder.cond_br(condition, &then_block, &else_block);
+
+        self.builder.position_at_end(&then_block);
+        let then_value = self.lower_block(&expression.block, hint)?;
+        let then_end = self.builder.get_insert_block().unwrap();
+        if then_value.ty != Ty::Never {
+            self.builder.br(&merge_block);
+        }
+
+        self.builder.position_at_end(&else_block);
+        let else_value = match &expression.alternative {
+            None => Typed::unit(),
+            Some(Alternative::End(block)) => self.lower_block(block, hint)?,
+            Some(Alternative::If(expression)) => self.lower_if(expression, hint)?,
+        };
+        let else_end = self.builder.get_insert_block().unwrap();
+        if else_value.ty != Ty::Never {
+            self.builder.br(&merge_block);
+        }
+
+        if then_value.ty == Ty::Never && else_value.ty == Ty::Never {
+            merge_block.delete();
+            return Ok(Typed::never());
+        }
+
+        self.builder.position_at_end(&merge_block);
+        Ok(match (then_value, else_value) {
+            (value, Typed { ty: Ty::Never, .. }) | (Typed { ty: Ty::Never, .. }, value) => value,
+            _ if expression.alternative.is_none() => Typed::unit(),
+            (then_value, else_value) if then_value.ty != else_value.ty => {
+                return Err(error_at(ErrorKind::MismatchedTypes, expression.span));
+            }
+            (Typed { ty: Ty::Unit, .. }, _) => Typed::unit(),
+            (then_value, else_value) => {
+                let phi = self.builder.phi(self.llvm_type(&then_value.ty).unwrap(), "");
+                phi.add_incomming(&[
+                    (then_value.value(), then_end),
+                    (else_value.value(), else_end),
+                ]);
+
+                Typed::new(then_value.ty, phi.value())
+            }
+        })
+    }
+
+    fn lower_while(
+        &mut self,
+        expression: &WhileExpression<'source>,
+    ) -> Result<'source, Typed<'ctx>> {
+        let function = self.current_function();
+        let test_block = function.append_basic_block("test");
+        let body_block = function.append_basic_block("body");
+        let end_block = function.append_basic_block("end");
+        self.builder.br(&test_block);
+
+        self.builder.position_at_end(&test_block);
+        let Some(condition) = self.lower_condition(&expression.test)? else {
+            end_block.delete();
+            body_block.delete();
+            return Ok(Typed::never());
+        };
+        self.builder.cond_br(condition, &body_block, &end_block);
+
+        self.builder.position_at_end(&body_block);
+        if self.lower_block(&expression.block, None)?.ty != Ty::Never {
+            self.builder.br(&test_block);
+        }
+
+        self.builder.position_at_end(&end_block);
+        Ok(Typed::unit())
+    }
+
+    fn lower_condition(
+        &mut self,
+        expression: &Expression<'source>,
+    ) -> Result<'source, Option<IntegerValue<'ctx>>> {
+        let condition = self.lower_expression(expression, Some(&Ty::BOOL))?;
+
+        match condition.ty {
+            Ty::Never => Ok(None),
+            Ty::Primitive(PrimitiveType::Bool) => Ok(Some(IntegerValue::from(condition.value()))),
+            _ => Err(error_at(ErrorKind::MismatchedTypes, expression.span())),
+        }
+    }
+
+    fn peek_type(&self, expression: &Expression<'source>) -> Option<Ty> {
+        match expression {
+            Expression::Literal { literal: Literal::True | Literal::False, .. } => Some(Ty::BOOL),
+            Expression::Literal { literal: Literal::Str(..), .. } => Some(Ty::Str),
+            Expression::Literal { literal: Literal::Identifier(ident), .. } => {
+                self.variable(ident.0)?.ty().cloned()
+            }
+            Expression::Call { ident, .. } => Some(self.functions.get(ident.0)?.return_ty.clone()),
+            Expression::Infix { operator, .. } if operator.is_comparison() => Some(Ty::BOOL),
+            Expression::Infix { lhs, rhs, .. } => {
+                self.peek_type(lhs).or_else(|| self.peek_type(rhs))
+            }
+            _ => None,
+        }
+    }
+}
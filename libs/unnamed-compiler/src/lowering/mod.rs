@@ -0,0 +1,823 @@
+mod asm;
+mod builtins;
+mod debug;
+mod enums;
+pub mod error;
+mod expressions;
+mod references;
+pub mod types;
+
+use std::{
+    collections::{HashMap, HashSet},
+    path::Path,
+};
+
+use llvm::{
+    builder::Builder,
+    context::Context,
+    module::Module,
+    target::{OptimizationLevel, TargetData},
+    types::{integer::IntegerType, Type as LlvmType},
+    values::{function::FunctionValue, pointer::PointerValue, Value},
+};
+
+use crate::{
+    codegen::Arithmetic,
+    common::{
+        error::{Error, Result},
+        CommonErrorKind,
+    },
+    options::Options,
+    parser::{
+        function::Function,
+        item::Program,
+        span::Span,
+        statements::{let_stmt::LetStatement, return_stmt::ReturnStatement, Statement},
+        Block,
+    },
+};
+
+use self::{
+    debug::DebugInfo, enums::EnumType, error::ErrorKind, references::referenced_variables,
+    types::Ty,
+};
+
+fn error_at(kind: ErrorKind, span: Span<'_>) -> Error<'_> {
+    Error::new(CommonErrorKind::Lowering(kind), Some(span.chunk()))
+}
+
+#[derive(Debug, Clone)]
+pub struct Typed<'ctx> {
+    pub ty: Ty,
+    pub value: Option<Value<'ctx>>,
+}
+
+impl<'ctx> Typed<'ctx> {
+    pub fn new(ty: Ty, value: Value<'ctx>) -> Self {
+        Self { ty, value: Some(value) }
+    }
+
+    pub fn unit() -> Self {
+        Self { ty: Ty::Unit, value: None }
+    }
+
+    pub fn never() -> Self {
+        Self { ty: Ty::Never, value: None }
+    }
+
+    fn value(&self) -> Value<'ctx> {
+        self.value.clone().expect("only unit and never values are empty")
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Signature<'ctx> {
+    function: FunctionValue<'ctx>,
+    params: Vec<Ty>,
+    return_ty: Ty,
+}
+
+#[derive(Debug, Clone)]
+enum Storage<'ctx> {
+    Uninitialized,
+    Register(Typed<'ctx>),
+    Stack { pointer: PointerValue<'ctx>, ty: Ty },
+}
+
+#[derive(Debug, Clone)]
+struct Variable<'ctx> {
+    mutable: bool,
+    storage: Storage<'ctx>,
+}
+
+impl<'ctx> Variable<'ctx> {
+    fn ty(&self) -> Option<&Ty> {
+        match &self.storage {
+            Storage::Uninitialized => None,
+            Storage::Register(typed) => Some(&typed.ty),
+            Storage::Stack { ty, .. } => Some(ty),
+        }
+    }
+}
+
+pub struct Compiler<'a, 'ctx, 'source> {
+    context: &'ctx Context,
+    module: &'a Module<'ctx>,
+    builder: &'a Builder<'ctx>,
+    target_data: &'a TargetData,
+    arithmetic: Arithmetic<'a, 'ctx>,
+    functions: HashMap<&'source str, Signature<'ctx>>,
+    enums: HashMap<&'source str, EnumType<'source>>,
+    /// Every variant name with its enum and tag. Variants are constructed without naming the
+    /// enum, so their names are unique across enums.
+    variants: HashMap<&'source str, (&'source str, usize)>,
+    scopes: Vec<HashMap<&'source str, Variable<'ctx>>>,
+    referenced: HashSet<&'source str>,
+    return_ty: Ty,
+    debug_info: Option<DebugInfo<'a, 'ctx>>,
+}
+
+impl<'a, 'ctx, 'source> Compiler<'a, 'ctx, 'source> {
+    pub fn new(
+        context: &'ctx Context,
+        module: &'a Module<'ctx>,
+        builder: &'a Builder<'ctx>,
+        target_data: &'a TargetData,
+        options: &Options,
+    ) -> Self {
+        Self {
+            context,
+            module,
+            builder,
+            target_data,
+            arithmetic: Arithmetic::new(context, module, builder, options.arithmetic),
+            functions: HashMap::new(),
+            enums: HashMap::new(),
+            variants: HashMap::new(),
+            scopes: vec![],
+            referenced: HashSet::new(),
+            return_ty: Ty::Unit,
+            debug_info: options.debug_info.then(|| {
+                let path = options.input.as_deref().unwrap_or(Path::new(""));
+                let optimized = options.optimization != OptimizationLevel::None;
+                DebugInfo::new(module, target_data, path, optimized)
+            }),
+        }
+    }
+
+    pub fn compile(&mut self, program: &Program<'source>) -> Result<'source, ()> {
+        self.declare_enums(program)?;
+
+        for function in program.functions() {
+            self.declare_function(function)?;
+        }
+
+        for function in program.functions() {
+            self.lower_function(function)?;
+        }
+
+        if let Some(debug_info) = &self.debug_info {
+            debug_info.finalize();
+        }
+
+        Ok(())
+    }
+
+    fn declare_function(&mut self, function: &Function<'source>) -> Result<'source, ()> {
+        let name = function.identifier.0;
+        if self.functions.contains_key(name) {
+            return Err(error_at(ErrorKind::DuplicateFunction, function.span));
+        }
+
+        let params = function
+            .arguments
+            .inner
+            .elements
+            .iter()
+            .map(|argument| self.lower_type(&argument.ty, argument.span))
+            .collect::<Result<Vec<_>>>()?;
+        let return_ty = match &function.return_ty {
+            Some(ty) => self.lower_type(ty, function.span)?,
+            None => Ty::Unit,
+        };
+
+        let param_types =
+            params.iter().map(|param| self.llvm_type(param).unwrap()).collect::<Vec<_>>();
+        // The C runtime takes `main`'s result as the exit status.
+        let return_type = match self.llvm_type(&return_ty) {
+            Some(ty) => ty,
+            None if name == "main" => self.context.int::<32>().into(),
+            None => self.context.void().into(),
+        };
+        let function_type = self.context.function(&param_types, return_type);
+
+        let function = self.module.add_function(name, function_type);
+        self.functions.insert(name, Signature { function, params, return_ty });
+
+        Ok(())
+    }
+
+    fn lower_function(&mut self, function: &Function<'source>) -> Result<'source, ()> {
+        let signature = self.functions[function.identifier.0].clone();
+        self.builder.position_at_end(&signature.function.append_basic_block("entry"));
+        self.return_ty = signature.return_ty.clone();
+        self.referenced = referenced_variables(&function.block);
+
+        if let Some(debug_info) = &mut self.debug_info {
+            debug_info.enter_function(
+                &signature.function,
+                function.identifier.0,
+                &signature.params,
+                &signature.return_ty,
+                function.span,
+            );
+            debug_info.set_location(self.builder, function.span);
+        }
+
+        let mut params = HashMap::new();
+        for (index, (argument, ty)) in
+            function.arguments.inner.elements.iter().zip(signature.params).enumerate()
+        {
+            let value = Typed::new(ty, signature.function.param(index as u32));
+            let storage = self.storage(argument.ident.0, false, value, argument.span);
+            params.insert(argument.ident.0, Variable { mutable: false, storage });
+        }
+
+        self.scopes.push(params);
+        let body = self.lower_block(&function.block, Some(&signature.return_ty))?;
+        self.scopes.pop();
+
+        match (&body.ty, &signature.return_ty) {
+            (Ty::Never, _) => {}
+            (_, Ty::Unit) => self.return_unit(),
+            (Ty::Unit, _) => return Err(error_at(ErrorKind::MissingReturnValue, function.span)),
+            (ty, return_ty) if ty == return_ty => self.builder.ret(body.value()),
+            _ => return Err(error_at(ErrorKind::MismatchedTypes, function.span)),
+        }
+
+        Ok(())
+    }
+
+    fn lower_block(
+        &mut self,
+        block: &Block<'source>,
+        hint: Option<&Ty>,
+    ) -> Result<'source, Typed<'ctx>> {
+        self.scopes.push(HashMap::new());
+
+        let statements = &block.inner.elements;
+        let mut value = Typed::unit();
+        for (index, statement) in statements.iter().enumerate() {
+            let hint = if index + 1 == statements.len() { hint } else { None };
+
+            value = self.lower_statement(statement, hint)?;
+            if value.ty == Ty::Never {
+                break;
+            }
+        }
+
+        self.scopes.pop();
+        Ok(value)
+    }
+
+    fn lower_statement(
+        &mut self,
+        statement: &Statement<'source>,
+        hint: Option<&Ty>,
+    ) -> Result<'source, Typed<'ctx>> {
+        self.set_location(match statement {
+            Statement::Let(statement) => statement.span,
+            Statement::Return(statement) => statement.span,
+            Statement::Expression(expression) => expression.span(),
+        });
+
+        match statement {
+            Statement::Let(statement) => self.lower_let(statement),
+            Statement::Return(statement) => self.lower_return(statement),
+            Statement::Expression(expression) => self.lower_expression(expression, hint),
+        }
+    }
+
+    fn lower_let(&mut self, statement: &LetStatement<'source>) -> Result<'source, Typed<'ctx>> {
+        let mutable = statement.mut_kw.is_some();
+        let name = statement.name.0;
+
+        let storage = match &statement.init {
+            Some(init) => {
+                let value = self.lower_expression(init, None)?;
+                if value.ty == Ty::Never {
+                    return Ok(value);
+                }
+
+                self.storage(name, mutable, value, statement.span)
+            }
+            None => Storage::Uninitialized,
+        };
+
+        self.scopes.last_mut().unwrap().insert(name, Variable { mutable, storage });
+        Ok(Typed::unit())
+    }
+
+    fn lower_return(
+        &mut self,
+        statement: &ReturnStatement<'source>,
+    ) -> Result<'source, Typed<'ctx>> {
+        let return_ty = self.return_ty.clone();
+        let value = match &statement.expression {
+            Some(expression) => self.lower_expression(expression, Some(&return_ty))?,
+            None => Typed::unit(),
+        };
+
+        match &value.ty {
+            Ty::Never => {}
+            ty if *ty != return_ty => {
+                return Err(error_at(ErrorKind::MismatchedTypes, statement.span));
+            }
+            Ty::Unit => self.return_unit(),
+            _ => self.builder.ret(value.value()),
+        }
+
+        Ok(Typed::never())
+    }
+
+    fn storage(
+        &self,
+        name: &str,
+        mutable: bool,
+        value: Typed<'ctx>,
+        span: Span<'source>,
+    ) -> Storage<'ctx> {
+        match self.llvm_type(&value.ty) {
+            Some(ty) if mutable || self.referenced.contains(name) => {
+                let pointer = self.builder.entry_alloca(ty, name);
+                self.builder.store(value.value(), pointer.clone());
+
+                if let Some(debug_info) = &self.debug_info {
+                    debug_info.declare_variable(self.builder, name, &pointer, &value.ty, span);
+                }
+
+                Storage::Stack { pointer, ty: value.ty }
+            }
+            _ => Storage::Register(value),
+        }
+    }
+
+    fn return_unit(&self) {
+        match self.current_function().function_type().return_ty() {
+            LlvmType::Void(..) => self.builder.ret_void(),
+            ty => self.builder.ret(IntegerType::from(ty).constant(0)),
+        }
+    }
+
+    fn set_location(&self, span: Span<'source>) {
+        if let Some(debug_info) = &self.debug_info {
+            debug_info.set_location(self.builder, span);
+        }
+    }
+
+    fn variable(&self, name: &str) -> Option<&Variable<'ctx>> {
+        self.scopes.iter().rev().find_map(|scope| scope.get(name))
+    }
+
+    fn variable_mut(&mut self, name: &str) -> Option<&mut Variable<'ctx>> {
+        self.scopes.iter_mut().rev().find_map(|scope| scope.get_mut(name))
+    }
+
+    fn current_function(&self) -> FunctionValue<'ctx> {
+        self.builder
+            .get_insert_block()
+            .expect("builder is not positioned in a basic block")
+            .parent()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use llvm::{context::Context, target::TargetData};
+
+    use crate::{
+        codegen::ArithmeticMode,
+        common::CommonErrorKind,
+        options::Options,
+        parser::{error::ErrorKind as ParserErrorKind, item::Program},
+    };
+
+    use super::{error::ErrorKind, Compiler};
+
+    const X86_64_LAYOUT: &str =
+        "e-m:e-p270:32:32-p271:32:32-p272:64:64-i64:64-f80:128-n8:16:32:64-S128";
+
+    fn compile(source: &str, options: Options) -> Result<String, (CommonErrorKind, String)> {
+        compile_for(source, options, X86_64_LAYOUT)
+    }
+
+    fn compile_for(
+        source: &str,
+        options: Options,
+        layout: &str,
+    ) -> Result<String, (CommonErrorKind, String)> {
+        let program = Program::from_source(source, Path::new("test.u")).unwrap();
+
+        let context = Context::create();
+        let module = context.module("test");
+        let builder = context.builder();
+        let target_data = TargetData::create(layout);
+
+        let result =
+            Compiler::new(&context, &module, &builder, &target_data, &options).compile(&program);
+        match result {
+            Ok(()) => {
+                module.verify().unwrap();
+                Ok(module.print_to_string().to_string_lossy().into_owned())
+            }
+            Err(err) => Err((err.kind, err.chunk.unwrap().slice.to_owned())),
+        }
+    }
+
+    fn lower(source: &str) -> String {
+        compile(source, Options::default()).unwrap()
+    }
+
+    fn lower_err(source: &str) -> (ErrorKind, String) {
+        match compile(source, Options::default()).unwrap_err() {
+            (CommonErrorKind::Lowering(kind), slice) => (kind, slice),
+            (kind, _) => panic!("unexpected error: {kind}"),
+        }
+    }
+
+    fn parser_err(source: &str) -> (ParserErrorKind, String) {
+        match compile(source, Options::default()).unwrap_err() {
+            (CommonErrorKind::Parser(kind), slice) => (kind, slice),
+            (kind, _) => panic!("unexpected error: {kind}"),
+        }
+    }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        let ir = lower("func add(a: i32, b: i32): i32 { a + b * 2 }");
+
+        assert!(ir.contains("define i32 @add(i32 %0, i32 %1)"));
+        assert!(ir.contains("@llvm.smul.with.overflow.i32(i32 %1, i32 2)"));
+        assert!(ir.contains("@llvm.sadd.with.overflow.i32"));
+        assert!(ir.contains("test.u:1:39: attempt to multiply with overflow"));
+    }
+
+    #[test]
+    fn test_release_arithmetic() {
+        let options = Options { arithmetic: ArithmeticMode::NoWrap, ..Default::default() };
+        let ir = compile("func sub(a: u64, b: u64): u64 { a - b }", options).unwrap();
+
+        assert!(ir.contains("sub nuw i64 %0, %1"));
+        assert!(!ir.contains("with.overflow"));
+    }
+
+    #[test]
+    fn test_literal_types() {
+        let ir = lower("func f(a: i8): bool { 1 < a }");
+
+        assert!(ir.contains("icmp slt i8 1, %0"));
+        assert!(ir.contains("define i1 @f(i8 %0)"));
+    }
+
+    #[test]
+    fn test_if_else() {
+        let ir = lower("func max(a: i32, b: i32): i32 { if a > b { a } else { b } }");
+
+        assert!(ir.contains("br i1 %2, label %then, label %else"));
+        assert!(ir.contains("phi i32 [ %0, %then ], [ %1, %else ]"));
+    }
+
+    #[test]
+    fn test_early_return() {
+        let ir = lower("func abs(a: i32): i32 { if a < 0 { return 0 - a; }; a }");
+
+        assert!(ir.contains("ret i32 %0"));
+        assert_eq!(ir.matches("ret i32").count(), 2);
+    }
+
+    #[test]
+    fn test_while_loop() {
+        let ir = lower(
+            "func sum(n: i32): i32 { let mut i = 0; let mut total = 0; \
+             while i < n { total = total + i; i = i + 1; }; total }",
+        );
+
+        assert!(ir.contains("alloca i32"));
+        assert!(ir.contains("br label %test"));
+        assert!(ir.contains("label %body, label %end"));
+    }
+
+    #[test]
+    fn test_calls() {
+        let ir = lower(
+            "func add(a: i64, b: i64): i64 { a + b } func main() { let x = add(1, 2); add(x, 3); }",
+        );
+
+        assert!(ir.contains("call i64 @add(i64 1, i64 2)"));
+        assert!(ir.contains("define i32 @main()"));
+        assert!(ir.contains("ret i32 0"));
+    }
+
+    #[test]
+    fn test_deferred_initialization() {
+        let ir = lower("func f(): i32 { let x; x = 1; x }");
+        assert!(ir.contains("store i32 1"));
+
+        assert_eq!(
+            lower_err("func f() { let x; x = 1; x = 2; }"),
+            (ErrorKind::ImmutableAssignment, "x".to_owned())
+        );
+        assert_eq!(
+            lower_err("func f(): i32 { let x; x }"),
+            (ErrorKind::UninitializedVariable, "x".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_mismatched_types() {
+        assert_eq!(
+            lower_err("func f(a: i32, b: i64): i64 { a + b }"),
+            (ErrorKind::MismatchedTypes, "+".to_owned())
+        );
+        assert_eq!(
+            lower_err("func f(a: i32): bool { a }"),
+            (ErrorKind::MismatchedTypes, "func".to_owned())
+        );
+        assert_eq!(
+            lower_err("func f(a: i32) { if a { } }"),
+            (ErrorKind::MismatchedTypes, "a".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_immutable_assignment() {
+        assert_eq!(
+            lower_err("func f() { let x = 1; x = 2; }"),
+            (ErrorKind::ImmutableAssignment, "x".to_owned())
+        );
+        assert_eq!(
+            lower_err("func f(a: i32) { a = 2; }"),
+            (ErrorKind::ImmutableAssignment, "a".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_name_errors() {
+        assert_eq!(lower_err("func f(): i32 { y }"), (ErrorKind::UnknownVariable, "y".to_owned()));
+        assert_eq!(lower_err("func f() { g(); }"), (ErrorKind::UnknownFunction, "g".to_owned()));
+        assert_eq!(
+            lower_err("func f() {} func f() {}"),
+            (ErrorKind::DuplicateFunction, "func".to_owned())
+        );
+        assert_eq!(
+            lower_err("func f(a: i32) { f(); }"),
+            (ErrorKind::ArgumentCount { expected: 1, received: 0 }, "f".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_floats() {
+        let ir = lower(
+            "func area(w: f32, h: f32): f32 { w * h / 2.0 } \
+             func less(a: f64): bool { 0.5 < a }",
+        );
+
+        assert!(ir.contains("define float @area(float %0, float %1)"));
+        assert!(ir.contains("fmul float %0, %1"));
+        assert!(ir.contains("fdiv float %2, 2.000000e+00"));
+        assert!(ir.contains("fcmp olt double 5.000000e-01, %0"));
+
+        assert_eq!(
+            lower_err("func f(a: f32, b: f64): f64 { a + b }"),
+            (ErrorKind::MismatchedTypes, "+".to_owned())
+        );
+        assert_eq!(
+            lower_err("func f(a: f64): f64 { a + 1 }"),
+            (ErrorKind::MismatchedTypes, "+".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_casts() {
+        let ir = lower(
+            "func f(a: i8, b: f64, c: bool): u64 { \
+             let x = a as i64; let y = b as u8; let z = c as u32; \
+             (x as f32 as u64) + (y as u64) + (z as usize as u64) }",
+        );
+
+        assert!(ir.contains("sext i8 %0 to i64"));
+        assert!(ir.contains("fptoui double %1 to i8"));
+        assert!(ir.contains("zext i1 %2 to i32"));
+        assert!(ir.contains("sitofp i64 %3 to float"));
+
+        assert_eq!(
+            lower_err("func f(a: i32): bool { a as bool }"),
+            (ErrorKind::InvalidCast, "as".to_owned())
+        );
+        assert_eq!(
+            lower_err("func f(a: vec2<i32>): i32 { a as i32 }"),
+            (ErrorKind::InvalidCast, "as".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_vectors() {
+        let ir = lower(
+            "func scale(a: vec4<f32>, b: vec4<f32>): vec4<f32> { a * b + vec4(1.0, 2.0, 3.0, 4.0) } \
+             func add(a: vec2<i64>): vec2<i64> { a + vec2(1, 2) }",
+        );
+
+        assert!(ir.contains("define <4 x float> @scale(<4 x float> %0, <4 x float> %1)"));
+        assert!(ir.contains("fmul <4 x float> %0, %1"));
+        assert!(ir.contains("fadd <4 x float> %2, <float 1.000000e+00"));
+        assert!(ir.contains("add <2 x i64> %0, <i64 1, i64 2>"));
+
+        assert_eq!(
+            lower_err("func f(a: vec4<i32>): vec4<i32> { a / a }"),
+            (ErrorKind::InvalidOperands, "/".to_owned())
+        );
+        assert_eq!(
+            lower_err("func f(a: vec4<bool>) {}"),
+            (ErrorKind::InvalidVectorElement, "a".to_owned())
+        );
+        assert_eq!(
+            lower_err("func f(): vec2<f64> { vec2(1.0) }"),
+            (ErrorKind::ArgumentCount { expected: 2, received: 1 }, "vec2".to_owned())
+        );
+        assert_eq!(
+            lower_err("func f(a: vec2<f64>, b: vec4<f64>) { a + b; }"),
+            (ErrorKind::MismatchedTypes, "+".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_pointer_sized_integers() {
+        let ir = lower("func f(a: usize, b: isize): usize { a / 2 }");
+        assert!(ir.contains("define i64 @f(i64 %0, i64 %1)"));
+        assert!(ir.contains("udiv i64 %0, 2"));
+
+        let ir = compile_for("func f(a: isize): isize { a * 3 }", Options::default(), "e-p:32:32")
+            .unwrap();
+        assert!(ir.contains("define i32 @f(i32 %0)"));
+        assert!(ir.contains("@llvm.smul.with.overflow.i32(i32 %0, i32 3)"));
+    }
+
+    #[test]
+    fn test_debug_info() {
+        let options =
+            Options { debug_info: true, input: Some("src/test.u".into()), ..Default::default() };
+        let ir =
+            compile("func add(a: i64, b: u8): i64 {\n    let mut c = a;\n    c + 1\n}", options)
+                .unwrap();
+
+        assert!(ir.contains(r#"!DIFile(filename: "test.u", directory: "src")"#));
+        assert!(ir.contains(
+            r#"!DISubprogram(name: "add", linkageName: "add", scope: !2, file: !2, line: 1"#
+        ));
+        assert!(ir.contains(r#"!DIBasicType(name: "i64", size: 64, encoding: DW_ATE_signed)"#));
+        assert!(ir.contains(r#"!DIBasicType(name: "u8", size: 8, encoding: DW_ATE_unsigned)"#));
+        assert!(ir.contains(r#"!DILocalVariable(name: "c", scope: "#));
+        assert!(ir.contains("!DILocation(line: 2, column: 5, scope: "));
+        assert!(ir.contains("!DILocation(line: 3, column: 7, scope: "));
+    }
+
+    #[test]
+    fn test_integer_out_of_range() {
+        assert_eq!(
+            lower_err("func f(): u8 { 256 }"),
+            (ErrorKind::IntegerOutOfRange, "256".to_owned())
+        );
+        assert!(lower("func f(): i8 { 127 }").contains("ret i8 127"));
+    }
+
+    #[test]
+    fn test_references() {
+        let ir = lower(
+            "func swap(a: &mut i32, b: &mut i32) { let tmp = *a; *a = *b; *b = tmp; } \
+             func f(): i32 { let x = 1; let mut y = 2; swap(&mut y, &mut y); g(&x) + *&3 } \
+             func g(p: &i32): i32 { *p }",
+        );
+        assert!(ir.contains("define void @swap(ptr %0, ptr %1)"));
+        assert!(ir.contains("store i32 %3, ptr %0"));
+        assert!(ir.contains("%x = alloca i32"));
+        assert!(ir.contains("call void @swap(ptr %y, ptr %y)"));
+        assert!(ir.contains("call i32 @g(ptr %x)"));
+
+        assert_eq!(
+            lower_err("func f(p: &i32) { *p = 1; }"),
+            (ErrorKind::SharedReferenceAssignment, "*".to_owned())
+        );
+        assert_eq!(
+            lower_err("func f() { let x = 1; let p = &mut x; }"),
+            (ErrorKind::MutableBorrow, "&".to_owned())
+        );
+        assert_eq!(
+            lower_err("func f(p: &mut i32) { *p = true; }"),
+            (ErrorKind::MismatchedTypes, "=".to_owned())
+        );
+        assert_eq!(
+            lower_err("func f(a: i32): i32 { *a }"),
+            (ErrorKind::InvalidDereference, "*".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_inline_asm() {
+        let ir = lower(
+            "func f(a: i64): i64 { let mut b = a; asm!(\"mov {1}, {0}\", out(reg) b, in(reg) a); b } \
+             func g(a: i32): i32 { let mut x = a; asm!(\"incl {0}\", inout(eax) x); x } \
+             func h(): i64 { let mut x = 0 as i64; let mut y = x; \
+                 asm!(\"movq $1, {1}\", out(reg) x, out(reg) y); y } \
+             func i() { asm!(\"nop\"); }",
+        );
+
+        assert!(ir.contains(r#"call i64 asm sideeffect "mov ${1}, ${0}", "=r,r"(i64 %0)"#));
+        assert!(ir.contains(r#"call i32 asm sideeffect "incl ${0}", "={eax},0"(i32 %x1)"#));
+        assert!(ir.contains(r#"call { i64, i64 } asm sideeffect "movq $$1, ${1}", "=r,=r"()"#));
+        assert!(ir.contains("extractvalue { i64, i64 } %0, 1"));
+        assert!(ir.contains(r#"call void asm sideeffect "nop", ""()"#));
+
+        assert_eq!(
+            lower_err("func f(a: i32) { asm!(\"mov {0}, {0}\", out(reg) a); }"),
+            (ErrorKind::ImmutableAssignment, "a".to_owned())
+        );
+        assert_eq!(
+            lower_err("func f(a: i32) { asm!(\"mov {1}, {2}\", in(reg) a); }"),
+            (ErrorKind::InvalidAsmTemplate, "asm".to_owned())
+        );
+        assert_eq!(
+            lower_err("func f(a: i32) { asm!(\"\", out(reg) a + 1); }"),
+            (ErrorKind::InvalidAssignment, "+".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_builtins() {
+        let ir = lower(
+            "func main() { print(\"hi\"); print(1 as u8); print(true); print_int(3); \
+             free(alloc(8)); panic(\"no\") } \
+             func f(s: str) { print_str(s); return; }",
+        );
+
+        assert!(ir.contains("define i32 @main()"));
+        assert!(ir.contains(r#"@0 = private constant [2 x i8] c"hi""#));
+        assert!(ir.contains("call void @unnamed_print_str(ptr @0, i64 2)"));
+        assert!(ir.contains("call void @unnamed_print_int(i64 1)"));
+        assert!(ir.contains("call void @unnamed_print_str(ptr @1, i64 4)"));
+        assert!(ir.contains("%0 = call ptr @unnamed_alloc(i64 8)"));
+        assert!(ir.contains("call void @unnamed_free(ptr %0)"));
+        assert!(ir.contains("call void @unnamed_panic(ptr @3, i64 2)\n  unreachable"));
+        assert!(ir.contains("define void @f({ ptr, i64 } %0)"));
+        assert!(ir.contains("call void @unnamed_print_str(ptr %1, i64 %2)"));
+
+        assert!(lower("func main() { print(1.5); }").contains("ret i32 0"));
+        assert!(
+            lower("func print(a: i32) {} func main() { print(1); }").contains("call void @print")
+        );
+
+        assert_eq!(
+            lower_err("func f(a: vec2<i32>) { print(a); }"),
+            (ErrorKind::Unprintable, "a".to_owned())
+        );
+        assert_eq!(
+            lower_err("func f() { print_int(1.0); }"),
+            (ErrorKind::MismatchedTypes, "1.0".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_enums() {
+        let ir = lower(
+            "enum Shape { Circle(f64), Rect(i32, i32), Empty } \
+             func area(s: Shape): f64 { \
+                 match s { Circle(r) => r * r, Rect(w, h) => (w * h) as f64, Empty => 0.0 } } \
+             func f(): f64 { area(Circle(2.0)) + area(Rect(1, 2)) + area(Empty) }",
+        );
+
+        assert!(ir.contains("define double @area({ i32, [1 x i64] } %0)"));
+        assert!(ir.contains("switch i32 %3, label %unreachable [\n    i32 0, label %arm\n"));
+        assert!(ir.contains("%7 = load { i32, i32 }, ptr %1, align 4"));
+        assert!(ir.contains("%h = extractvalue { i32, i32 } %7, 1"));
+        assert!(ir.contains("phi double [ %6, %arm ], [ %12, %continue ], [ 0.000000e+00, %arm2 ]"));
+        assert!(ir.contains("store { double } { double 2.000000e+00 }, ptr %"));
+        assert!(ir.contains("call double @area({ i32, [1 x i64] } { i32 2, [1 x i64] poison })"));
+
+        assert_eq!(
+            parser_err("enum Bit { Zero, One } func f(b: Bit): i32 { match b { One => 1 } }"),
+            (ParserErrorKind::NonExhaustiveMatch { missing: 1 }, "match".to_owned())
+        );
+        assert_eq!(
+            parser_err("enum Bit { Zero, One(i32) } func f(b: Bit) { match b { Zero => 0, Onw(a) => a }; }"),
+            (ParserErrorKind::UnknownVariant, "match".to_owned())
+        );
+        assert_eq!(
+            parser_err("enum Bit { Zero, One(i32) } func f(b: Bit) { match b { Zero => 0, One(a, b) => a }; }"),
+            (ParserErrorKind::PatternArity { expected: 1, received: 2 }, "match".to_owned())
+        );
+        assert_eq!(
+            lower_err("func f(a: i32): i32 { match a { } }"),
+            (ErrorKind::NotAnEnum, "a".to_owned())
+        );
+        assert_eq!(
+            lower_err(
+                "enum Bit { Zero, One } func f(b: Bit): i32 { match b { Zero => 0, One => true } }"
+            ),
+            (ErrorKind::MismatchedTypes, "match".to_owned())
+        );
+        assert_eq!(
+            lower_err("enum List { Cons(i32, List), Nil }"),
+            (ErrorKind::RecursiveType, "enum".to_owned())
+        );
+        assert_eq!(
+            lower_err("enum A { X } enum B { X }"),
+            (ErrorKind::DuplicateVariant, "enum".to_owned())
+        );
+        assert_eq!(lower_err("enum A { X(Shape) }"), (ErrorKind::UnknownType, "enum".to_owned()));
+        assert_eq!(
+            lower_err("enum A { X(f64) } func f(): A { X(1) }"),
+            (ErrorKind::MismatchedTypes, "1".to_owned())
+        );
+    }
+}
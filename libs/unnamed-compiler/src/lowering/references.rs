@@ -0,0 +1,80 @@
+use std::collections::HashSet;
+
+use crate::parser::{
+    expressions::{
+        if_expr::{Alternative, IfExpression},
+        Expression, Literal,
+    },
+    statements::Statement,
+    Block,
+};
+
+/// Names of the variables a function body takes a reference to. Those live in an alloca for
+/// their whole lifetime so that every `&x` points at the same storage.
+pub(super) fn referenced_variables<'source>(block: &Block<'source>) -> HashSet<&'source str> {
+    let mut names = HashSet::new();
+    visit_block(block, &mut names);
+
+    names
+}
+
+fn visit_block<'source>(block: &Block<'source>, names: &mut HashSet<&'source str>) {
+    for statement in &block.inner.elements {
+        match statement {
+            Statement::Let(statement) => statement.init.iter().for_each(|e| visit(e, names)),
+            Statement::Return(statement) => {
+                statement.expression.iter().for_each(|e| visit(e, names))
+            }
+            Statement::Expression(expression) => visit(expression, names),
+        }
+    }
+}
+
+fn visit_if<'source>(expression: &IfExpression<'source>, names: &mut HashSet<&'source str>) {
+    visit(&expression.expression, names);
+    visit_block(&expression.block, names);
+
+    match &expression.alternative {
+        Some(Alternative::End(block)) => visit_block(block, names),
+        Some(Alternative::If(expression)) => visit_if(expression, names),
+        None => {}
+    }
+}
+
+fn visit<'source>(expression: &Expression<'source>, names: &mut HashSet<&'source str>) {
+    match expression {
+        Expression::Literal { .. } => {}
+        Expression::Reference { expression, .. } => match &**expression {
+            Expression::Literal { literal: Literal::Identifier(ident), .. } => {
+                names.insert(ident.0);
+            }
+            expression => visit(expression, names),
+        },
+        Expression::Dereference { expression, .. } | Expression::Cast { expression, .. } => {
+            visit(expression, names)
+        }
+        Expression::Call { arguments, .. } => {
+            arguments.elements.iter().for_each(|argument| visit(argument, names))
+        }
+        Expression::Infix { lhs, rhs, .. } => {
+            visit(lhs, names);
+            visit(rhs, names);
+        }
+        Expression::If(expression) => visit_if(expression, names),
+        Expression::While(expression) => {
+            visit(&expression.test, names);
+            visit_block(&expression.block, names);
+        }
+        Expression::Asm(expression) => {
+            for operand in expression.operands() {
+                visit(&operand.expression, names);
+            }
+        }
+        Expression::Match(expression) => {
+            visit(&expression.expression, names);
+            for arm in expression.arms() {
+                visit(&arm.expression, names);
+            }
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use std::fmt::{self, Display};
+
+use llvm::{
+    context::Context,
+    types::{integer::IntegerType, Type as LlvmType},
+};
+
+use crate::{
+    common::{
+        error::{Error, Result},
+        CommonErrorKind,
+    },
+    parser::{span::Span, types::PrimitiveType, types::Type},
+};
+
+use super::{error::ErrorKind, error_at, Compiler};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Ty {
+    Unit,
+    Never,
+    Primitive(PrimitiveType),
+    Str,
+    Vector { element: PrimitiveType, len: u32 },
+    Pointer(Box<Ty>),
+    Reference { mutable: bool, ty: Box<Ty> },
+    Enum(String),
+}
+
+impl Ty {
+    pub const BOOL: Ty = Ty::Primitive(PrimitiveType::Bool);
+
+    pub fn from_ast<'source>(ty: &Type<'source>, span: Span<'source>) -> Result<'source, Self> {
+        let error = |kind| Error::new(CommonErrorKind::Lowering(kind), Some(span.chunk()));
+
+        if let Some(primitive) = ty.as_primitive() {
+            return Ok(Ty::Primitive(primitive));
+        }
+
+        let (ident, arguments) = match ty {
+            Type::Pointer(ty) => return Ok(Ty::Pointer(Box::new(Ty::from_ast(ty, span)?))),
+            Type::Reference { mutable, ty } => {
+                let ty = Box::new(Ty::from_ast(ty, span)?);
+                return Ok(Ty::Reference { mutable: *mutable, ty });
+            }
+            Type::Generic { ident, arguments } => (ident, arguments),
+            Type::Named(ident) if ident.0 == "str" => return Ok(Ty::Str),
+            // Checked against the declared enums by `Compiler::lower_type`.
+            Type::Named(ident) => return Ok(Ty::Enum(ident.0.to_owned())),
+        };
+        let Some(len) = vector_len(ident.0) else {
+            return Err(error(ErrorKind::UnknownType));
+        };
+
+        match arguments.elements.as_slice() {
+            [element] => match Ty::from_ast(element, span)? {
+                Ty::Primitive(element) if element != PrimitiveType::Bool => {
+                    Ok(Ty::Vector { element, len })
+                }
+                _ => Err(error(ErrorKind::InvalidVectorElement)),
+            },
+            _ => Err(error(ErrorKind::InvalidVectorElement)),
+        }
+    }
+
+    pub fn primitive(&self) -> Option<PrimitiveType> {
+        match self {
+            Ty::Primitive(primitive) => Some(*primitive),
+            _ => None,
+        }
+    }
+
+    pub fn pointee(&self) -> Option<&Ty> {
+        match self {
+            Ty::Pointer(ty) | Ty::Reference { ty, .. } => Some(ty),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Ty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Ty::Unit => write!(f, "()"),
+            Ty::Never => write!(f, "!"),
+            Ty::Primitive(PrimitiveType::Bool) => write!(f, "bool"),
+            Ty::Primitive(PrimitiveType::Int { width, signed: true }) => write!(f, "i{width}"),
+            Ty::Primitive(PrimitiveType::Int { width, signed: false }) => write!(f, "u{width}"),
+            Ty::Primitive(PrimitiveType::Size { signed: true }) => write!(f, "isize"),
+            Ty::Primitive(PrimitiveType::Size { signed: false }) => write!(f, "usize"),
+            Ty::Primitive(PrimitiveType::Float { width }) => write!(f, "f{width}"),
+            Ty::Str => write!(f, "str"),
+            Ty::Vector { element, len } => write!(f, "vec{len}<{}>", Ty::Primitive(*element)),
+            Ty::Pointer(ty) => write!(f, "*{ty}"),
+            Ty::Reference { mutable: true, ty } => write!(f, "&mut {ty}"),
+            Ty::Reference { mutable: false, ty } => write!(f, "&{ty}"),
+            Ty::Enum(name) => write!(f, "{name}"),
+        }
+    }
+}
+
+impl<'a, 'ctx, 'source> Compiler<'a, 'ctx, 'source> {
+    pub(super) fn lower_type(
+        &self,
+        ty: &Type<'source>,
+        span: Span<'source>,
+    ) -> Result<'source, Ty> {
+        let ty = Ty::from_ast(ty, span)?;
+
+        let mut inner = &ty;
+        while let Some(pointee) = inner.pointee() {
+            inner = pointee;
+        }
+        match inner {
+            Ty::Enum(name) if !self.enums.contains_key(name.as_str()) => {
+                Err(error_at(ErrorKind::UnknownType, span))
+            }
+            _ => Ok(ty),
+        }
+    }
+
+    pub(super) fn llvm_type(&self, ty: &Ty) -> Option<LlvmType<'ctx>> {
+        Some(match ty {
+            Ty::Unit | Ty::Never => return None,
+            Ty::Primitive(PrimitiveType::Bool) => self.context.int::<1>().into(),
+            Ty::Primitive(PrimitiveType::Int { width, .. }) => {
+                int_type(self.context, *width).into()
+            }
+            Ty::Primitive(PrimitiveType::Size { .. }) => {
+                self.context.int_ptr(self.target_data, 0).into()
+            }
+            Ty::Primitive(PrimitiveType::Float { width: 32 }) => self.context.f32().into(),
+            Ty::Primitive(PrimitiveType::Float { width: 64 }) => self.context.f64().into(),
+            Ty::Primitive(PrimitiveType::Float { width }) => {
+                unreachable!("f{width} is not a language type")
+            }
+            // A pointer to the bytes and their length, the way the runtime takes strings.
+            Ty::Str => {
+                let len = self.context.int_ptr(self.target_data, 0).into();
+                self.context.structure(&[self.context.pointer(0).into(), len], false).into()
+            }
+            Ty::Vector { element, len } => {
+                let element = self.llvm_type(&Ty::Primitive(*element)).unwrap();
+                self.context.vector(element, *len).unwrap().into()
+            }
+            Ty::Pointer(..) | Ty::Reference { .. } => self.context.pointer(0).into(),
+            Ty::Enum(name) => self.enum_type(name).into(),
+        })
+    }
+
+    pub(super) fn int_width(&self, primitive: PrimitiveType) -> Option<u32> {
+        match primitive {
+            PrimitiveType::Bool => Some(1),
+            PrimitiveType::Int { width, .. } => Some(width),
+            PrimitiveType::Size { .. } => Some(self.target_data.pointer_size(0) * 8),
+            PrimitiveType::Float { .. } => None,
+        }
+    }
+}
+
+pub(super) fn vector_len(name: &str) -> Option<u32> {
+    let len = name.strip_prefix("vec")?;
+    if len.starts_with('0') {
+        return None;
+    }
+
+    len.parse().ok()
+}
+
+pub(super) fn int_type(context: &Context, width: u32) -> IntegerType<'_> {
+    match width {
+        1 => context.int::<1>(),
+        8 => context.int::<8>(),
+        16 => context.int::<16>(),
+        32 => context.int::<32>(),
+        64 => context.int::<64>(),
+        128 => context.int::<128>(),
+        _ => unreachable!("i{width} is not a language type"),
+    }
+}
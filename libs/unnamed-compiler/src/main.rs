@@ -1,20 +1,47 @@
+use std::{fs, path::PathBuf, process};
+
 use llvm::{
-    builder::IntPredicate,
     context::Context,
-    pass_manager::PassManagerOptions,
-    target::{OptimizationLevel, Target, TargetMachine, X86},
+    pass_manager::{PassManagerOptions, PassPipeline},
+    target::{RelocMode, Target, TargetMachine, X86},
 };
 
+use lowering::Compiler;
+use options::Options;
+use parser::item::Program;
+use runtime::{declare_builtins, link};
+
+pub mod codegen;
 pub mod common;
 pub mod lexer;
+pub mod lowering;
+pub mod options;
 pub mod parser;
+pub mod runtime;
 
 fn main() {
-    let target = Target::<X86>::initialize();
+    let compile_options = Options::from_args(std::env::args().skip(1));
+    let Some(path) = &compile_options.input else {
+        eprintln!(
+            "usage: unnamed [-g] [-O0|-O1|-O2|-O3] [--release] [--emit-llvm] [-o <output>] <file.u>"
+        );
+        process::exit(2);
+    };
+
+    let source = fs::read_to_string(path).unwrap_or_else(|err| {
+        eprintln!("{}: {err}", path.display());
+        process::exit(1);
+    });
+    let program = Program::from_source(&source, path).unwrap_or_else(|err| {
+        eprint!("{err}");
+        process::exit(1);
+    });
+
+    let target = Target::<X86>::initialize().expect("x86-64 target is not available");
     let machine: TargetMachine = TargetMachine::from_host(
         target,
-        OptimizationLevel::None,
-        Default::default(),
+        compile_options.optimization,
+        RelocMode::Pic,
         Default::default(),
     );
 
@@ -22,64 +49,39 @@ fn main() {
     options.set_debug_logging(true);
     options.set_verify_each(true);
 
+    let target_data = machine.data_layout();
+
     let context = Context::create();
-    let module = context.module("test");
+    let module = context.module(&path.file_stem().unwrap_or_default().to_string_lossy());
+    module.set_source_filename(&path.to_string_lossy());
+    module.set_data_layout(&target_data);
+    module.set_triple(&machine.triple().to_string_lossy());
     let builder = context.builder();
-
-    let int_32 = context.int::<32>();
-
-    let add_ty = context.function(&[int_32.into(), int_32.into()], int_32.into());
-    let add = module.add_function("add", add_ty);
-    let add_entry = add.append_basic_block("entry");
-
-    let main = context.function(&[], context.int::<32>().into());
-    let main = module.add_function("main", main);
-    let main_entry = main.append_basic_block("entry");
-
-    builder.position_at_end(&add_entry);
-    let lhs = add.param(0);
-    let rhs = add.param(1);
-
-    let result = builder.add(lhs, rhs, "add");
-    builder.ret(result);
-
-    builder.position_at_end(&main_entry);
-    let lhs = builder.call(
-        add_ty,
-        &add,
-        &[int_32.constant(5).into(), int_32.constant(5).into()],
-        "call_lhs",
-    );
-    let rhs = builder.call(
-        add_ty,
-        &add,
-        &[int_32.constant(5).into(), int_32.constant(5).into()],
-        "call_rhs",
-    );
-
-    let result = builder.icmp(IntPredicate::EQ, lhs, rhs, "cmp");
-
-    let true_br = main.append_basic_block("true_br");
-    let false_br = main.append_basic_block("false_br");
-    let end_br = main.append_basic_block("end_br");
-
-    builder.cond_br(result, &true_br, &false_br);
-
-    builder.position_at_end(&true_br);
-    let a = int_32.constant(1488);
-    builder.br(&end_br);
-
-    builder.position_at_end(&false_br);
-    let b = int_32.constant(666);
-    builder.br(&end_br);
-
-    builder.position_at_end(&end_br);
-
-    let phi_node = builder.phi(int_32, "select");
-    phi_node.add_incomming(&[(a.into(), true_br), (b.into(), false_br)]);
-
-    builder.ret(phi_node);
-
-    println!("{:?}", module.run_passes("verify", machine, options));
-    println!("{}", module.print_to_string().to_string_lossy());
+    declare_builtins(&context, &module);
+
+    let mut compiler = Compiler::new(&context, &module, &builder, &target_data, &compile_options);
+    if let Err(err) = compiler.compile(&program) {
+        eprint!("{err}");
+        process::exit(1);
+    }
+
+    let pipeline = PassPipeline::from(compile_options.optimization).verify();
+    if let Err(err) = module.run_pipeline(&pipeline, &machine, options) {
+        eprintln!("{}", err.get_error_message());
+        process::exit(1);
+    }
+
+    if compile_options.emit_llvm {
+        println!("{}", module.print_to_string().to_string_lossy());
+        return;
+    }
+
+    let output = match &compile_options.output {
+        Some(output) => output.clone(),
+        None => PathBuf::from(path.file_stem().unwrap_or_default()),
+    };
+    if let Err(err) = link(&machine, &module, &output) {
+        eprintln!("{err}");
+        process::exit(1);
+    }
 }
@@ -0,0 +1,120 @@
+use std::path::PathBuf;
+
+use llvm::target::OptimizationLevel;
+
+use crate::codegen::ArithmeticMode;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Options {
+    pub debug_info: bool,
+    pub optimization: OptimizationLevel,
+    pub arithmetic: ArithmeticMode,
+    pub emit_llvm: bool,
+    pub input: Option<PathBuf>,
+    pub output: Option<PathBuf>,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            debug_info: false,
+            optimization: OptimizationLevel::None,
+            arithmetic: ArithmeticMode::Checked,
+            emit_llvm: false,
+            input: None,
+            output: None,
+        }
+    }
+}
+
+impl Options {
+    pub fn from_args<I: IntoIterator<Item = String>>(args: I) -> Self {
+        let mut options = Options::default();
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-g" => options.debug_info = true,
+                "-O0" => options.optimization = OptimizationLevel::None,
+                "-O1" => options.optimization = OptimizationLevel::Less,
+                "-O" | "-O2" => options.optimization = OptimizationLevel::Default,
+                "-O3" => options.optimization = OptimizationLevel::Aggressive,
+                "--release" => options.arithmetic = ArithmeticMode::NoWrap,
+                "--emit-llvm" => options.emit_llvm = true,
+                "-o" => options.output = args.next().map(PathBuf::from),
+                arg if !arg.starts_with('-') => options.input = Some(PathBuf::from(arg)),
+                _ => {}
+            }
+        }
+
+        options
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use llvm::target::OptimizationLevel;
+
+    use crate::codegen::ArithmeticMode;
+
+    use super::Options;
+
+    fn parse(args: &[&str]) -> Options {
+        Options::from_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn test_default_options() {
+        assert_eq!(
+            parse(&[]),
+            Options {
+                debug_info: false,
+                optimization: OptimizationLevel::None,
+                arithmetic: ArithmeticMode::Checked,
+                emit_llvm: false,
+                input: None,
+                output: None
+            }
+        );
+    }
+
+    #[test]
+    fn test_debug_info_flag() {
+        assert!(parse(&["-g"]).debug_info);
+    }
+
+    #[test]
+    fn test_optimization_levels() {
+        assert_eq!(parse(&["-O0"]).optimization, OptimizationLevel::None);
+        assert_eq!(parse(&["-O1"]).optimization, OptimizationLevel::Less);
+        assert_eq!(parse(&["-O"]).optimization, OptimizationLevel::Default);
+        assert_eq!(parse(&["-O2"]).optimization, OptimizationLevel::Default);
+        assert_eq!(parse(&["-O3"]).optimization, OptimizationLevel::Aggressive);
+        assert_eq!(parse(&["-O3", "-O1"]).optimization, OptimizationLevel::Less);
+    }
+
+    #[test]
+    fn test_release_flag() {
+        assert_eq!(parse(&["--release"]).arithmetic, ArithmeticMode::NoWrap);
+    }
+
+    #[test]
+    fn test_input_file() {
+        let options = parse(&["-g", "main.u", "--release"]);
+
+        assert_eq!(options.input.as_deref(), Some(Path::new("main.u")));
+        assert!(options.debug_info);
+    }
+
+    #[test]
+    fn test_output() {
+        let options = parse(&["-o", "out/main", "main.u"]);
+
+        assert_eq!(options.output.as_deref(), Some(Path::new("out/main")));
+        assert_eq!(options.input.as_deref(), Some(Path::new("main.u")));
+        assert!(!options.emit_llvm);
+        assert!(parse(&["--emit-llvm", "main.u"]).emit_llvm);
+    }
+}
@@ -0,0 +1,113 @@
+use std::ops::Index;
+
+use derive_macro::Parse;
+
+use crate::{common::error::Result, lexer::token::Token};
+
+use super::{
+    cursor::Cursor,
+    delimited::{Braced, Parenthesized},
+    primitive::{Comma, EnumKw, Identifier, LeftParenthesis, RightBrace, RightParenthesis},
+    punctuated::Punctuated,
+    span::Span,
+    types::Type,
+    Parse, SyntaxKind,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Variant<'source> {
+    pub ident: Identifier<'source>,
+    pub fields:
+        Option<Parenthesized<'source, Punctuated<'source, Type<'source>, Comma, RightParenthesis>>>,
+}
+
+impl<'source> Variant<'source> {
+    pub fn new(ident: Identifier<'source>, fields: Option<Vec<Type<'source>>>) -> Self {
+        Self { ident, fields: fields.map(|fields| Parenthesized::new(Punctuated::new(fields))) }
+    }
+
+    pub fn fields(&self) -> &[Type<'source>] {
+        self.fields.as_ref().map_or(&[], |fields| &fields.inner.elements)
+    }
+
+    pub fn arity(&self) -> usize {
+        self.fields().len()
+    }
+}
+
+impl<'source> Parse<'source> for Variant<'source> {
+    fn parse<I: Index<usize, Output = Token<'source>>>(
+        cursor: &mut Cursor<'source, I>,
+    ) -> Result<'source, Self> {
+        let ident = cursor.parse()?;
+        let fields = if LeftParenthesis::test(cursor) { Some(cursor.parse()?) } else { None };
+
+        Ok(Self { ident, fields })
+    }
+}
+
+#[derive(Parse, Debug, Clone, PartialEq, Eq)]
+pub struct Enum<'source> {
+    pub span: Span<'source>,
+    pub enum_kw: EnumKw,
+    pub identifier: Identifier<'source>,
+    pub variants: Braced<'source, Punctuated<'source, Variant<'source>, Comma, RightBrace>>,
+}
+
+impl<'source> Enum<'source> {
+    pub fn variant(&self, name: &str) -> Option<&Variant<'source>> {
+        self.variants.inner.elements.iter().find(|variant| variant.ident.0 == name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        parser::{
+            delimited::Braced,
+            primitive::{EnumKw, Identifier},
+            punctuated::Punctuated,
+            types::Type,
+        },
+        tests,
+    };
+
+    use super::{Enum, Variant};
+
+    macro_rules! enumeration {
+        ($name: ident { $($variant: expr),* }) => {
+            Enum {
+                span: Default::default(),
+                enum_kw: EnumKw,
+                identifier: Identifier(stringify!($name)),
+                variants: Braced::new(Punctuated::new(vec![$($variant),*])),
+            }
+        };
+    }
+
+    macro_rules! variant {
+        ($name: ident) => {
+            Variant::new(Identifier(stringify!($name)), None)
+        };
+        ($name: ident($($ty: ident),*)) => {
+            Variant::new(
+                Identifier(stringify!($name)),
+                Some(vec![$(Type::Named(Identifier(stringify!($ty)))),*]),
+            )
+        };
+    }
+
+    tests! {
+        test_empty_enum("enum Never {}"): enumeration!(Never {});
+        test_unit_variants("enum Bool { Yes, No }"): enumeration!(Bool { variant!(Yes), variant!(No) });
+        test_tuple_variants("enum Shape { Circle(float), Rect(float, float) }"): enumeration!(Shape {
+            variant!(Circle(float)),
+            variant!(Rect(float, float))
+        });
+        test_trailing_comma("enum Option { Some(int), None, }"): enumeration!(Option {
+            variant!(Some(int)),
+            variant!(None)
+        });
+        test_empty_fields("enum Unit { Empty() }"): enumeration!(Unit { variant!(Empty()) });
+    }
+}
@@ -6,12 +6,29 @@ use crate::lexer::token::TokenKind;
 pub enum ErrorKind {
     UnexpectedEof,
     UnexpectedToken { expected: &'static [TokenKind], received: Option<TokenKind> },
+    UnknownAsmDirection,
+    NonExhaustiveMatch { missing: usize },
+    UnknownVariant,
+    PatternArity { expected: usize, received: usize },
+    DuplicatePattern,
 }
 
 impl Display for ErrorKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             ErrorKind::UnexpectedEof => write!(f, "unexpected end of file"),
+            ErrorKind::UnknownAsmDirection => write!(f, "expected 'in', 'out' or 'inout'"),
+            ErrorKind::NonExhaustiveMatch { missing: 1 } => {
+                write!(f, "non-exhaustive match, 1 variant is not covered")
+            }
+            ErrorKind::NonExhaustiveMatch { missing } => {
+                write!(f, "non-exhaustive match, {missing} variants are not covered")
+            }
+            ErrorKind::UnknownVariant => write!(f, "pattern is not a variant of the enum"),
+            ErrorKind::PatternArity { expected, received } => {
+                write!(f, "pattern binds {received} fields, the variant has {expected}")
+            }
+            ErrorKind::DuplicatePattern => write!(f, "variant is matched more than once"),
             ErrorKind::UnexpectedToken { expected, received } => {
                 write!(f, "expected ")?;
                 // Nano#2724 ❤️
@@ -0,0 +1,104 @@
+use std::ops::Index;
+
+use derive_macro::Parse;
+
+use crate::{
+    common::{
+        error::{Error, Result},
+        CommonErrorKind,
+    },
+    lexer::token::{Token, TokenKind},
+    parser::{
+        cursor::Cursor,
+        delimited::Parenthesized,
+        error::ErrorKind,
+        primitive::{AsmKw, Bang, Comma, Identifier, RightParenthesis, Str},
+        punctuated::Punctuated,
+        span::Span,
+        Parse,
+    },
+};
+
+use super::Expression;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AsmDirection {
+    In,
+    Out,
+    InOut,
+}
+
+impl<'source> Parse<'source> for AsmDirection {
+    fn parse<I: Index<usize, Output = Token<'source>>>(
+        cursor: &mut Cursor<'source, I>,
+    ) -> Result<'source, Self> {
+        let token = cursor.consume(&[TokenKind::Identifier])?;
+
+        match token.chunk.slice {
+            "in" => Ok(AsmDirection::In),
+            "out" => Ok(AsmDirection::Out),
+            "inout" => Ok(AsmDirection::InOut),
+            _ => Err(Error::new(
+                CommonErrorKind::Parser(ErrorKind::UnknownAsmDirection),
+                Some(token.chunk),
+            )),
+        }
+    }
+}
+
+#[derive(Parse, Debug, Clone, PartialEq, Eq)]
+pub struct AsmOperand<'source> {
+    pub direction: AsmDirection,
+    pub register: Parenthesized<'source, Identifier<'source>>,
+    pub expression: Expression<'source>,
+}
+
+impl<'source> AsmOperand<'source> {
+    pub fn new(
+        direction: AsmDirection,
+        register: Identifier<'source>,
+        expression: Expression<'source>,
+    ) -> Self {
+        Self { direction, register: Parenthesized::new(register), expression }
+    }
+}
+
+#[derive(Parse, Debug, Clone, PartialEq, Eq)]
+pub struct AsmArguments<'source> {
+    pub template: Str<'source>,
+    comma: Option<Comma>,
+    #[parse_if(comma.is_some())]
+    pub operands: Option<Punctuated<'source, AsmOperand<'source>, Comma, RightParenthesis>>,
+}
+
+#[derive(Parse, Debug, Clone, PartialEq, Eq)]
+pub struct AsmExpression<'source> {
+    pub span: Span<'source>,
+    asm_kw: AsmKw,
+    bang: Bang,
+    pub arguments: Parenthesized<'source, AsmArguments<'source>>,
+}
+
+impl<'source> AsmExpression<'source> {
+    pub fn new(template: Str<'source>, operands: Vec<AsmOperand<'source>>) -> Self {
+        let operands = if operands.is_empty() { None } else { Some(Punctuated::new(operands)) };
+
+        Self {
+            span: Default::default(),
+            asm_kw: Default::default(),
+            bang: Default::default(),
+            arguments: Parenthesized::new(AsmArguments {
+                template,
+                comma: if operands.is_some() { Some(Default::default()) } else { None },
+                operands,
+            }),
+        }
+    }
+
+    pub fn operands(&self) -> &[AsmOperand<'source>] {
+        match &self.arguments.inner.operands {
+            Some(operands) => &operands.elements,
+            None => &[],
+        }
+    }
+}
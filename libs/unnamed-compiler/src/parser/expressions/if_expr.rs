@@ -8,6 +8,7 @@ use crate::{
     parser::{
         cursor::Cursor,
         primitive::{ElseKw, IfKw},
+        span::Span,
         Block, Parse, SyntaxKind,
     },
 };
@@ -16,12 +17,13 @@ use super::Expression;
 
 #[derive(Parse, Debug, Clone, PartialEq, Eq)]
 pub struct IfExpression<'source> {
+    pub span: Span<'source>,
     if_kw: IfKw,
-    expression: Box<Expression<'source>>,
-    block: Block<'source>,
+    pub expression: Box<Expression<'source>>,
+    pub block: Block<'source>,
     else_kw: Option<ElseKw>,
     #[parse_if(else_kw.is_some())]
-    alternative: Option<Alternative<'source>>,
+    pub alternative: Option<Alternative<'source>>,
 }
 
 impl<'source> IfExpression<'source> {
@@ -31,6 +33,7 @@ impl<'source> IfExpression<'source> {
         alternative: Option<Alternative<'source>>,
     ) -> Self {
         Self {
+            span: Default::default(),
             if_kw: Default::default(),
             expression: Box::new(expression),
             block,
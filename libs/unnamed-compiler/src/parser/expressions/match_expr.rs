@@ -0,0 +1,196 @@
+use std::ops::Index;
+
+use derive_macro::Parse;
+
+use crate::{
+    common::{
+        error::{Error, Result},
+        CommonErrorKind,
+    },
+    lexer::token::Token,
+    parser::{
+        cursor::Cursor,
+        delimited::{Braced, Parenthesized},
+        enumeration::Enum,
+        error::ErrorKind,
+        primitive::{
+            Comma, FatArrow, Identifier, LeftParenthesis, MatchKw, RightBrace, RightParenthesis,
+        },
+        punctuated::Punctuated,
+        span::Span,
+        Parse, SyntaxKind,
+    },
+};
+
+use super::Expression;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pattern<'source> {
+    pub variant: Identifier<'source>,
+    pub bindings: Option<
+        Parenthesized<'source, Punctuated<'source, Identifier<'source>, Comma, RightParenthesis>>,
+    >,
+}
+
+impl<'source> Pattern<'source> {
+    pub fn new(variant: Identifier<'source>, bindings: Option<Vec<Identifier<'source>>>) -> Self {
+        Self {
+            variant,
+            bindings: bindings.map(|bindings| Parenthesized::new(Punctuated::new(bindings))),
+        }
+    }
+
+    pub fn bindings(&self) -> &[Identifier<'source>] {
+        self.bindings.as_ref().map_or(&[], |bindings| &bindings.inner.elements)
+    }
+
+    pub fn arity(&self) -> usize {
+        self.bindings().len()
+    }
+}
+
+impl<'source> Parse<'source> for Pattern<'source> {
+    fn parse<I: Index<usize, Output = Token<'source>>>(
+        cursor: &mut Cursor<'source, I>,
+    ) -> Result<'source, Self> {
+        let variant = cursor.parse()?;
+        let bindings = if LeftParenthesis::test(cursor) { Some(cursor.parse()?) } else { None };
+
+        Ok(Self { variant, bindings })
+    }
+}
+
+#[derive(Parse, Debug, Clone, PartialEq, Eq)]
+pub struct MatchArm<'source> {
+    pub pattern: Pattern<'source>,
+    fat_arrow: FatArrow,
+    pub expression: Expression<'source>,
+}
+
+impl<'source> MatchArm<'source> {
+    pub fn new(pattern: Pattern<'source>, expression: Expression<'source>) -> Self {
+        Self { pattern, fat_arrow: Default::default(), expression }
+    }
+}
+
+#[derive(Parse, Debug, Clone, PartialEq, Eq)]
+pub struct MatchExpression<'source> {
+    pub span: Span<'source>,
+    match_kw: MatchKw,
+    pub expression: Box<Expression<'source>>,
+    arms: Braced<'source, Punctuated<'source, MatchArm<'source>, Comma, RightBrace>>,
+}
+
+impl<'source> MatchExpression<'source> {
+    pub fn new(expression: Expression<'source>, arms: Vec<MatchArm<'source>>) -> Self {
+        Self {
+            span: Default::default(),
+            match_kw: Default::default(),
+            expression: Box::new(expression),
+            arms: Braced::new(Punctuated::new(arms)),
+        }
+    }
+
+    pub fn arms(&self) -> &[MatchArm<'source>] {
+        &self.arms.inner.elements
+    }
+
+    pub fn missing_variants<'e>(
+        &self,
+        enumeration: &'e Enum<'source>,
+    ) -> Vec<&'e Identifier<'source>> {
+        enumeration
+            .variants
+            .inner
+            .elements
+            .iter()
+            .map(|variant| &variant.ident)
+            .filter(|ident| !self.arms().iter().any(|arm| arm.pattern.variant == **ident))
+            .collect()
+    }
+
+    /// Every arm has to name a variant of `enumeration` with as many bindings as it has fields,
+    /// and every variant has to be covered exactly once.
+    pub fn check_exhaustive(&self, enumeration: &Enum<'source>) -> Result<'source, ()> {
+        let error = |kind| Err(Error::new(CommonErrorKind::Parser(kind), Some(self.span.chunk())));
+
+        for (index, arm) in self.arms().iter().enumerate() {
+            let Some(variant) = enumeration.variant(arm.pattern.variant.0) else {
+                return error(ErrorKind::UnknownVariant);
+            };
+            if arm.pattern.arity() != variant.arity() {
+                return error(ErrorKind::PatternArity {
+                    expected: variant.arity(),
+                    received: arm.pattern.arity(),
+                });
+            }
+            if self.arms()[..index].iter().any(|other| other.pattern.variant == arm.pattern.variant)
+            {
+                return error(ErrorKind::DuplicatePattern);
+            }
+        }
+
+        match self.missing_variants(enumeration).len() {
+            0 => Ok(()),
+            missing => error(ErrorKind::NonExhaustiveMatch { missing }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Index;
+
+    use crate::{
+        common::{error::Result, CommonErrorKind},
+        lexer::token::Token,
+        parser::{enumeration::Enum, error::ErrorKind, expressions::MatchExpression},
+        tests,
+    };
+
+    #[derive(Debug, PartialEq)]
+    struct Missing<'source>(Vec<&'source str>);
+
+    impl<'source> Parse<'source> for Missing<'source> {
+        fn parse<I: Index<usize, Output = Token<'source>>>(
+            cursor: &mut Cursor<'source, I>,
+        ) -> Result<'source, Self> {
+            let enumeration = cursor.parse::<Enum>()?;
+            let expression = cursor.parse::<MatchExpression>()?;
+            let missing = expression.missing_variants(&enumeration);
+
+            Ok(Missing(missing.into_iter().map(|ident| ident.0).collect()))
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Diagnostic(Option<CommonErrorKind>);
+
+    impl<'source> Parse<'source> for Diagnostic {
+        fn parse<I: Index<usize, Output = Token<'source>>>(
+            cursor: &mut Cursor<'source, I>,
+        ) -> Result<'source, Self> {
+            let enumeration = cursor.parse::<Enum>()?;
+            let expression = cursor.parse::<MatchExpression>()?;
+
+            let err = expression.check_exhaustive(&enumeration).err();
+            if let Some(err) = &err {
+                assert_eq!(err.chunk.unwrap().slice, "match");
+            }
+
+            Ok(Diagnostic(err.map(|err| err.kind)))
+        }
+    }
+
+    tests! {
+        test_exhaustive_match<Missing>("enum Shape { Circle(f64), Rect(f64, f64) } match s { Rect(w, h) => w * h, Circle(r) => r }"): Missing(vec![]);
+        test_missing_variants<Missing>("enum Shape { Circle, Rect, Point } match s { Rect => 0 }"): Missing(vec!["Circle", "Point"]);
+        test_empty_match<Missing>("enum Bit { Zero, One } match b {}"): Missing(vec!["Zero", "One"]);
+        test_exhaustive_diagnostic<Diagnostic>("enum Bit { Zero, One } match b { One => 1, Zero => 0 }"): Diagnostic(None);
+        test_non_exhaustive_diagnostic<Diagnostic>("enum Shape { Circle, Rect, Point } match s { Rect => 0 }"): Diagnostic(Some(CommonErrorKind::Parser(ErrorKind::NonExhaustiveMatch { missing: 2 })));
+        test_unknown_variant<Diagnostic>("enum Shape { Circle(f64), Rect(f64, f64) } match s { Circl(r) => r, Rect(w, h) => w, Circle(r) => r }"): Diagnostic(Some(CommonErrorKind::Parser(ErrorKind::UnknownVariant)));
+        test_pattern_arity<Diagnostic>("enum Shape { Circle(f64), Rect(f64, f64) } match s { Circle(a, b) => a, Rect(w, h) => w }"): Diagnostic(Some(CommonErrorKind::Parser(ErrorKind::PatternArity { expected: 1, received: 2 })));
+        test_unit_pattern_arity<Diagnostic>("enum Bit { Zero, One } match b { One() => 1, Zero => 0 }"): Diagnostic(None);
+        test_duplicate_pattern<Diagnostic>("enum Bit { Zero, One } match b { One => 1, Zero => 0, One => 2 }"): Diagnostic(Some(CommonErrorKind::Parser(ErrorKind::DuplicatePattern)));
+    }
+}
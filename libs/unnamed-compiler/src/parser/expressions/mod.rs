@@ -1,4 +1,6 @@
+pub mod asm_expr;
 pub mod if_expr;
+pub mod match_expr;
 pub mod while_expr;
 
 use std::ops::Index;
@@ -10,12 +12,20 @@ use crate::{
     lexer::token::{Token, TokenKind},
 };
 
-use self::{if_expr::IfExpression, while_expr::WhileExpression};
+use self::{
+    asm_expr::AsmExpression, if_expr::IfExpression, match_expr::MatchExpression,
+    while_expr::WhileExpression,
+};
 
 use super::{
     cursor::Cursor,
-    primitive::{Comma, FalseKw, Float, Identifier, Integer, RightParenthesis, TrueKw},
+    primitive::{
+        Ampersand, Comma, FalseKw, Float, Identifier, Integer, Multiply, MutKw, RightParenthesis,
+        Str, TrueKw,
+    },
     punctuated::Punctuated,
+    span::Span,
+    types::Type,
     Parse,
 };
 
@@ -43,6 +53,17 @@ impl Operator {
             Operator::Eq => (8, 9),
         }
     }
+
+    pub fn is_comparison(&self) -> bool {
+        matches!(
+            self,
+            Operator::Less
+                | Operator::LessEq
+                | Operator::Greeter
+                | Operator::GreeterEq
+                | Operator::Eq
+        )
+    }
 }
 
 impl<'source> Parse<'source> for Operator {
@@ -69,6 +90,7 @@ pub enum Literal<'source> {
     Integer(Integer<'source>),
     Float(Float<'source>),
     Identifier(Identifier<'source>),
+    Str(Str<'source>),
     True,
     False,
 }
@@ -81,6 +103,7 @@ impl<'source> Parse<'source> for Literal<'source> {
             Integer => Literal::Integer(cursor.parse()?),
             Float => Literal::Float(cursor.parse()?),
             Identifier => Literal::Identifier(cursor.parse()?),
+            Str => Literal::Str(cursor.parse()?),
             TrueKw => {
                 cursor.parse::<TrueKw>()?;
                 Literal::True
@@ -95,57 +118,120 @@ impl<'source> Parse<'source> for Literal<'source> {
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Expression<'source> {
-    Literal(Literal<'source>),
+    Literal {
+        literal: Literal<'source>,
+        span: Span<'source>,
+    },
     If(IfExpression<'source>),
     While(WhileExpression<'source>),
+    Asm(AsmExpression<'source>),
+    Match(MatchExpression<'source>),
     Call {
         ident: Identifier<'source>,
         arguments: Punctuated<'source, Expression<'source>, Comma, RightParenthesis>,
+        span: Span<'source>,
     },
     Infix {
         lhs: Box<Expression<'source>>,
         operator: Operator,
         rhs: Box<Expression<'source>>,
+        span: Span<'source>,
+    },
+    Cast {
+        expression: Box<Expression<'source>>,
+        ty: Type<'source>,
+        span: Span<'source>,
+    },
+    Reference {
+        mutable: bool,
+        expression: Box<Expression<'source>>,
+        span: Span<'source>,
+    },
+    Dereference {
+        expression: Box<Expression<'source>>,
+        span: Span<'source>,
     },
 }
 
 impl<'source> Expression<'source> {
+    pub const CAST_BINDING_POWER: u8 = 10;
+    pub const PREFIX_BINDING_POWER: u8 = 11;
+
     pub const POSSIBLE_TOKENS: &'static [TokenKind] = &[
         TokenKind::Identifier,
         TokenKind::IfKw,
         TokenKind::WhileKw,
+        TokenKind::AsmKw,
+        TokenKind::MatchKw,
         TokenKind::Float,
         TokenKind::Integer,
         TokenKind::LeftParenthesis,
+        TokenKind::Ampersand,
+        TokenKind::Multiply,
     ];
 
+    pub fn span(&self) -> Span<'source> {
+        match self {
+            Expression::Literal { span, .. }
+            | Expression::Call { span, .. }
+            | Expression::Infix { span, .. }
+            | Expression::Cast { span, .. }
+            | Expression::Reference { span, .. }
+            | Expression::Dereference { span, .. } => *span,
+            Expression::If(expression) => expression.span,
+            Expression::While(expression) => expression.span,
+            Expression::Asm(expression) => expression.span,
+            Expression::Match(expression) => expression.span,
+        }
+    }
+
     fn parse_bp<I: Index<usize, Output = Token<'source>>>(
         cursor: &mut Cursor<'source, I>,
         min_bp: u8,
     ) -> Result<'source, Self> {
-        let mut lhs = check!(cursor(_lhs) {
+        let mut lhs = check!(cursor(token) {
             Identifier => {
                 let ident = cursor.parse()?;
+                let span = Span(token.chunk);
 
                 let expr = if cursor.test(&[TokenKind::LeftParenthesis])? {
                     cursor.next_token()?;
-                    let call = Expression::Call { ident, arguments: cursor.parse()? };
+                    let call = Expression::Call { ident, arguments: cursor.parse()?, span };
                     cursor.parse::<RightParenthesis>()?;
                     call
                 } else {
-                    Expression::Literal(Literal::Identifier(ident))
+                    Expression::Literal { literal: Literal::Identifier(ident), span }
                 };
 
                 expr
             },
             IfKw => Expression::If(cursor.parse()?),
             WhileKw => Expression::While(cursor.parse()?),
-            Float | Integer | TrueKw | FalseKw => Expression::Literal(cursor.parse()?),
+            AsmKw => Expression::Asm(cursor.parse()?),
+            MatchKw => Expression::Match(cursor.parse()?),
+            Float | Integer | Str | TrueKw | FalseKw => {
+                Expression::Literal { literal: cursor.parse()?, span: Span(token.chunk) }
+            },
             LeftParenthesis => {
                 cursor.next_token()?;
                 let expression = cursor.parse::<Expression>()?;
                 cursor.parse::<RightParenthesis>()?;
                 expression
+            },
+            Ampersand => {
+                cursor.parse::<Ampersand>()?;
+                let mutable = cursor.parse::<Option<MutKw>>()?.is_some();
+                let expression = Expression::parse_bp(cursor, Self::PREFIX_BINDING_POWER)?;
+                Expression::Reference {
+                    mutable,
+                    expression: Box::new(expression),
+                    span: Span(token.chunk),
+                }
+            },
+            Multiply => {
+                cursor.parse::<Multiply>()?;
+                let expression = Expression::parse_bp(cursor, Self::PREFIX_BINDING_POWER)?;
+                Expression::Dereference { expression: Box::new(expression), span: Span(token.chunk) }
             }
         });
 
@@ -160,6 +246,18 @@ impl<'source> Expression<'source> {
                 break;
             }
 
+            if cursor.test(&[TokenKind::AsKw])? {
+                if Self::CAST_BINDING_POWER < min_bp {
+                    break;
+                }
+
+                let span = Span(cursor.consume(&[TokenKind::AsKw])?.chunk);
+                let ty = Type::parse_without_generics(cursor)?;
+
+                lhs = Expression::Cast { expression: Box::new(lhs), ty, span };
+                continue;
+            }
+
             let operator = match cursor.parse_without_consume::<Operator>() {
                 Ok(op) => op,
                 Err(err) if err.kind.is_eof() => break,
@@ -171,10 +269,10 @@ impl<'source> Expression<'source> {
                 break;
             }
 
-            cursor.next_token()?;
+            let span = Span(cursor.next_token()?.chunk);
             let rhs = Expression::parse_bp(cursor, r_bp)?;
 
-            lhs = Expression::Infix { lhs: Box::new(lhs), operator, rhs: Box::new(rhs) }
+            lhs = Expression::Infix { lhs: Box::new(lhs), operator, rhs: Box::new(rhs), span }
         }
         Ok(lhs)
     }
@@ -193,14 +291,17 @@ mod tests {
     use crate::{
         parser::{
             delimited::Delimited,
-            primitive::{Float, Identifier, Integer},
+            primitive::{Float, Identifier, Integer, Str},
             punctuated::Punctuated,
+            types::Type,
         },
         tests,
     };
 
     use super::{
+        asm_expr::{AsmDirection, AsmExpression, AsmOperand},
         if_expr::{Alternative, IfExpression},
+        match_expr::{MatchArm, MatchExpression, Pattern},
         while_expr::WhileExpression,
         Expression, Literal, Operator,
     };
@@ -208,28 +309,42 @@ mod tests {
     #[macro_export]
     macro_rules! int {
         ($lit: literal) => {
-            Expression::Literal(Literal::Integer(Integer(stringify!($lit))))
+            Expression::Literal {
+                literal: Literal::Integer(Integer(stringify!($lit))),
+                span: Default::default(),
+            }
         };
     }
 
     #[macro_export]
     macro_rules! float {
         ($lit: literal) => {
-            Expression::Literal(Literal::Float(Float(stringify!($lit))))
+            Expression::Literal {
+                literal: Literal::Float(Float(stringify!($lit))),
+                span: Default::default(),
+            }
         };
     }
 
     #[macro_export]
     macro_rules! ident {
         ($lit: ident) => {
-            Expression::Literal(Literal::Identifier(Identifier(stringify!($lit))))
+            Expression::Literal {
+                literal: Literal::Identifier(Identifier(stringify!($lit))),
+                span: Default::default(),
+            }
         };
     }
 
     #[macro_export]
     macro_rules! infix {
         ($lhs: expr, $op: ident, $rhs: expr) => {
-            Expression::Infix { lhs: Box::new($lhs), operator: Operator::$op, rhs: Box::new($rhs) }
+            Expression::Infix {
+                lhs: Box::new($lhs),
+                operator: Operator::$op,
+                rhs: Box::new($rhs),
+                span: Default::default(),
+            }
         };
     }
 
@@ -238,11 +353,57 @@ mod tests {
         ($ident: ident($($arg: expr),*)) => {
             Expression::Call {
                 ident: Identifier(stringify!($ident)),
-                arguments: Punctuated::new(vec![$($arg),*])
+                arguments: Punctuated::new(vec![$($arg),*]),
+                span: Default::default(),
+            }
+        };
+    }
+
+    macro_rules! cast {
+        ($expr: expr, $ty: ident) => {
+            Expression::Cast {
+                expression: Box::new($expr),
+                ty: Type::Named(Identifier(stringify!($ty))),
+                span: Default::default(),
+            }
+        };
+    }
+
+    macro_rules! reference {
+        ($expr: expr) => {
+            Expression::Reference {
+                mutable: false,
+                expression: Box::new($expr),
+                span: Default::default(),
+            }
+        };
+        (mut $expr: expr) => {
+            Expression::Reference {
+                mutable: true,
+                expression: Box::new($expr),
+                span: Default::default(),
             }
         };
     }
 
+    macro_rules! deref {
+        ($expr: expr) => {
+            Expression::Dereference { expression: Box::new($expr), span: Default::default() }
+        };
+    }
+
+    macro_rules! pattern {
+        ($variant: ident) => {
+            Pattern::new(Identifier(stringify!($variant)), None)
+        };
+        ($variant: ident($($binding: ident),*)) => {
+            Pattern::new(
+                Identifier(stringify!($variant)),
+                Some(vec![$(Identifier(stringify!($binding))),*]),
+            )
+        };
+    }
+
     macro_rules! empty_body {
         () => {
             Delimited::new(Punctuated::new(vec![]))
@@ -253,8 +414,9 @@ mod tests {
         test_integer("10"): int!(10);
         test_float("1.0"): float!(1.0);
         test_identifier("pi"): ident!(pi);
-        test_true("true"): Expression::Literal(Literal::True);
-        test_false("false"): Expression::Literal(Literal::False);
+        test_true("true"): Expression::Literal { literal: Literal::True, span: Default::default() };
+        test_false("false"): Expression::Literal { literal: Literal::False, span: Default::default() };
+        test_string("\"hi\""): Expression::Literal { literal: Literal::Str(Str("\"hi\"")), span: Default::default() };
         test_infix("2 + pi"): infix!(int!(2), Plus, ident!(pi));
         test_call_no_args("test()"): call!(test());
         test_call_one_arg("test(1)"): call!(test(int!(1)));
@@ -263,6 +425,48 @@ mod tests {
         test_simple_if("if a {}"): IfExpression::new(ident!(a), empty_body!(), None);
         test_if_with_end_else("if a {} else {}"): IfExpression::new(ident!(a), empty_body!(), Some(Alternative::End(empty_body!())));
         test_if_with_if_else("if a {} else if b {}"): IfExpression::new(ident!(a), empty_body!(), Some(Alternative::If(Box::new(IfExpression::new(ident!(b), empty_body!(), None)))));
-        test_while_expression("while 42 {}"): WhileExpression::new(int!(42), empty_body!())
+        test_while_expression("while 42 {}"): WhileExpression::new(int!(42), empty_body!());
+        test_cast("1 as f64"): cast!(int!(1), f64);
+        test_cast_chain("a as i32 as f64"): cast!(cast!(ident!(a), i32), f64);
+        test_cast_binds_tighter("a + b as i64 * 2"): infix!(ident!(a), Plus, infix!(cast!(ident!(b), i64), Multiply, int!(2)));
+        test_cast_parenthesis("(a + b) as u8"): cast!(infix!(ident!(a), Plus, ident!(b)), u8);
+        test_cast_then_less("x as i32 < y"): infix!(cast!(ident!(x), i32), Less, ident!(y));
+        test_cast_then_greeter("x as u8 > y"): infix!(cast!(ident!(x), u8), Greeter, ident!(y));
+        test_reference("&x"): reference!(ident!(x));
+        test_mut_reference("&mut x"): reference!(mut ident!(x));
+        test_dereference("*p"): deref!(ident!(p));
+        test_double_dereference("**p"): deref!(deref!(ident!(p)));
+        test_dereference_assignment("*p = v"): infix!(deref!(ident!(p)), Assignment, ident!(v));
+        test_dereference_binds_tighter("*p * 2"): infix!(deref!(ident!(p)), Multiply, int!(2));
+        test_multiply_dereference("a * *p"): infix!(ident!(a), Multiply, deref!(ident!(p)));
+        test_dereference_before_cast("*p as i64"): cast!(deref!(ident!(p)), i64);
+        test_reference_call_argument("swap(&mut a, &b)"): call!(swap(reference!(mut ident!(a)), reference!(ident!(b))));
+        test_asm_no_operands("asm!(\"nop\")"): Expression::Asm(AsmExpression::new(Str("\"nop\""), vec![]));
+        test_asm_operands("asm!(\"mov {0}, {1}\", out(reg) y, in(reg) x + 1)"): Expression::Asm(AsmExpression::new(Str("\"mov {0}, {1}\""), vec![
+            AsmOperand::new(AsmDirection::Out, Identifier("reg"), ident!(y)),
+            AsmOperand::new(AsmDirection::In, Identifier("reg"), infix!(ident!(x), Plus, int!(1))),
+        ]));
+        test_asm_inout("asm!(\"inc {0}\", inout(reg) x)"): Expression::Asm(AsmExpression::new(Str("\"inc {0}\""), vec![
+            AsmOperand::new(AsmDirection::InOut, Identifier("reg"), ident!(x)),
+        ]));
+        test_asm_direction_names_as_operands("asm!(\"mov {0}, {1}\", out(reg) out, in(reg) in)"): Expression::Asm(AsmExpression::new(Str("\"mov {0}, {1}\""), vec![
+            AsmOperand::new(AsmDirection::Out, Identifier("reg"), ident!(out)),
+            AsmOperand::new(AsmDirection::In, Identifier("reg"), Expression::Literal { literal: Literal::Identifier(Identifier("in")), span: Default::default() }),
+        ]));
+        test_direction_names_as_identifiers("inout + out"): infix!(ident!(inout), Plus, ident!(out));
+        test_match_empty("match s {}"): Expression::Match(MatchExpression::new(ident!(s), vec![]));
+        test_match_arms("match s { Circle(r) => r * r, Rect(w, h) => w * h }"): Expression::Match(MatchExpression::new(ident!(s), vec![
+            MatchArm::new(pattern!(Circle(r)), infix!(ident!(r), Multiply, ident!(r))),
+            MatchArm::new(pattern!(Rect(w, h)), infix!(ident!(w), Multiply, ident!(h))),
+        ]));
+        test_match_unit_pattern("match b { Yes => 1, No => 0, }"): Expression::Match(MatchExpression::new(ident!(b), vec![
+            MatchArm::new(pattern!(Yes), int!(1)),
+            MatchArm::new(pattern!(No), int!(0)),
+        ]));
+        test_match_nested("match a { Some(x) => match x { Zero => 0 }, None => 1 }"): Expression::Match(MatchExpression::new(ident!(a), vec![
+            MatchArm::new(pattern!(Some(x)), Expression::Match(MatchExpression::new(ident!(x), vec![MatchArm::new(pattern!(Zero), int!(0))]))),
+            MatchArm::new(pattern!(None), int!(1)),
+        ]));
+        test_match_in_infix("1 + match s { A => 2 }"): infix!(int!(1), Plus, Expression::Match(MatchExpression::new(ident!(s), vec![MatchArm::new(pattern!(A), int!(2))])))
     }
 }
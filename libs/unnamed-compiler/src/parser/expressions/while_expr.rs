@@ -1,18 +1,19 @@
 use derive_macro::Parse;
 
-use crate::parser::{primitive::WhileKw, Block};
+use crate::parser::{primitive::WhileKw, span::Span, Block};
 
 use super::Expression;
 
 #[derive(Parse, Debug, Clone, PartialEq, Eq)]
 pub struct WhileExpression<'source> {
+    pub span: Span<'source>,
     while_kw: WhileKw,
-    test: Box<Expression<'source>>,
-    block: Block<'source>,
+    pub test: Box<Expression<'source>>,
+    pub block: Block<'source>,
 }
 
 impl<'source> WhileExpression<'source> {
     pub fn new(test: Expression<'source>, block: Block<'source>) -> Self {
-        Self { while_kw: Default::default(), test: Box::new(test), block }
+        Self { span: Default::default(), while_kw: Default::default(), test: Box::new(test), block }
     }
 }
@@ -4,25 +4,29 @@ use super::{
     delimited::Parenthesized,
     primitive::{Colon, Comma, FuncKw, Identifier, RightParenthesis},
     punctuated::Punctuated,
+    span::Span,
+    types::Type,
     Block,
 };
 
 #[derive(Parse, Debug, Clone, PartialEq, Eq)]
 pub struct Argument<'source> {
+    pub span: Span<'source>,
     pub ident: Identifier<'source>,
     _colon: Colon,
-    pub ty: Identifier<'source>,
+    pub ty: Type<'source>,
 }
 
 #[derive(Parse, Debug, Clone, PartialEq, Eq)]
 pub struct Function<'source> {
+    pub span: Span<'source>,
     pub func_kw: FuncKw,
     pub identifier: Identifier<'source>,
     pub arguments:
         Parenthesized<'source, Punctuated<'source, Argument<'source>, Comma, RightParenthesis>>,
     pub colon: Option<Colon>,
     #[parse_if(colon.is_some())]
-    pub return_ty: Option<Identifier<'source>>,
+    pub return_ty: Option<Type<'source>>,
     pub block: Block<'source>,
 }
 
@@ -33,6 +37,7 @@ mod tests {
             delimited::{Braced, Parenthesized},
             primitive::{Colon, FuncKw, Identifier},
             punctuated::Punctuated,
+            types::Type,
         },
         tests,
     };
@@ -41,10 +46,10 @@ mod tests {
 
     macro_rules! func {
         ($name: ident($($arg: ident : $ty: ident),*): $body: expr) => {
-            Function { func_kw: FuncKw, identifier: Identifier(stringify!($name)), arguments: Parenthesized::new(Punctuated::new(vec![$(Argument { ident: Identifier(stringify!($arg)), _colon: Colon, ty: Identifier(stringify!($ty)) }),*])), colon: None, return_ty: None, block: $body }
+            Function { span: Default::default(), func_kw: FuncKw, identifier: Identifier(stringify!($name)), arguments: Parenthesized::new(Punctuated::new(vec![$(Argument { span: Default::default(), ident: Identifier(stringify!($arg)), _colon: Colon, ty: Type::Named(Identifier(stringify!($ty))) }),*])), colon: None, return_ty: None, block: $body }
         };
         ($name: ident($($arg: ident : $ty: ident),*) -> $return_ty: ident: $body: expr) => {
-            Function { func_kw: FuncKw, identifier: Identifier(stringify!($name)), arguments: Parenthesized::new(Punctuated::new(vec![$(Argument { ident: Identifier(stringify!($arg)), _colon: Colon, ty: Identifier(stringify!($ty)) }),*])), colon: Some(Colon), return_ty: Some(Identifier(stringify!($return_ty))), block: $body }
+            Function { span: Default::default(), func_kw: FuncKw, identifier: Identifier(stringify!($name)), arguments: Parenthesized::new(Punctuated::new(vec![$(Argument { span: Default::default(), ident: Identifier(stringify!($arg)), _colon: Colon, ty: Type::Named(Identifier(stringify!($ty))) }),*])), colon: Some(Colon), return_ty: Some(Type::Named(Identifier(stringify!($return_ty)))), block: $body }
         };
     }
     macro_rules! body {
@@ -57,5 +62,20 @@ mod tests {
         test_function_one_argument("func add(a: int) {}"): func!(add(a: int): body!());
         test_function_multiple_arguments("func add(a: int, b: int) {}"): func!(add(a: int, b: int): body!());
         test_function_return_ty("func add(a: int, b: int): int {}"): func!(add(a: int, b: int) -> int: body!());
+        test_function_float_ty("func half(a: f32): f64 {}"): func!(half(a: f32) -> f64: body!());
+        test_function_generic_ty("func dot(a: vec4<float>) {}"): Function {
+            span: Default::default(),
+            func_kw: FuncKw,
+            identifier: Identifier("dot"),
+            arguments: Parenthesized::new(Punctuated::new(vec![Argument {
+                span: Default::default(),
+                ident: Identifier("a"),
+                _colon: Colon,
+                ty: Type::Generic { ident: Identifier("vec4"), arguments: Punctuated::new(vec![Type::Named(Identifier("float"))]) },
+            }])),
+            colon: None,
+            return_ty: None,
+            block: body!(),
+        };
     }
 }
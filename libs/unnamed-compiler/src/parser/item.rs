@@ -0,0 +1,118 @@
+use std::{ops::Index, path::Path, result};
+
+use crate::{
+    check,
+    common::error::Result,
+    lexer::{
+        self,
+        token::{Token, TokenKind},
+        Lexer,
+    },
+};
+
+use super::{cursor::Cursor, enumeration::Enum, function::Function, Parse};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Item<'source> {
+    Function(Function<'source>),
+    Enum(Enum<'source>),
+}
+
+impl<'source> Parse<'source> for Item<'source> {
+    fn parse<I: Index<usize, Output = Token<'source>>>(
+        cursor: &mut Cursor<'source, I>,
+    ) -> Result<'source, Self> {
+        Ok(check!(cursor(_token) {
+            FuncKw => Item::Function(cursor.parse()?),
+            EnumKw => Item::Enum(cursor.parse()?)
+        }))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Program<'source> {
+    pub items: Vec<Item<'source>>,
+}
+
+impl<'source> Program<'source> {
+    pub fn from_source(source: &'source str, path: &'source Path) -> Result<'source, Self> {
+        let lexer = Lexer::new(lexer::cursor::Cursor::new(source, path));
+        let tokens = lexer.collect::<result::Result<Vec<_>, _>>()?;
+
+        Cursor::new(tokens.len(), tokens).parse()
+    }
+
+    pub fn functions(&self) -> impl Iterator<Item = &Function<'source>> {
+        self.items.iter().filter_map(|item| match item {
+            Item::Function(function) => Some(function),
+            _ => None,
+        })
+    }
+
+    pub fn enums(&self) -> impl Iterator<Item = &Enum<'source>> {
+        self.items.iter().filter_map(|item| match item {
+            Item::Enum(enumeration) => Some(enumeration),
+            _ => None,
+        })
+    }
+}
+
+impl<'source> Parse<'source> for Program<'source> {
+    fn parse<I: Index<usize, Output = Token<'source>>>(
+        cursor: &mut Cursor<'source, I>,
+    ) -> Result<'source, Self> {
+        let mut items = vec![];
+
+        while !cursor.is_eof() {
+            items.push(cursor.parse()?);
+        }
+
+        Ok(Self { items })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::{
+        common::CommonErrorKind,
+        parser::{error::ErrorKind, item::Program},
+    };
+
+    #[test]
+    fn test_items() {
+        let program =
+            Program::from_source("func a() {} func b(x: i32): i32 { x }", Path::new("test.u"))
+                .unwrap();
+        let names = program.functions().map(|function| function.identifier.0).collect::<Vec<_>>();
+
+        assert_eq!(names, ["a", "b"]);
+    }
+
+    #[test]
+    fn test_enum_item() {
+        let program = Program::from_source(
+            "enum Bit { Zero, One } func a() {} enum Shape { Circle(f64) }",
+            Path::new("test.u"),
+        )
+        .unwrap();
+        let names = program.enums().map(|enumeration| enumeration.identifier.0).collect::<Vec<_>>();
+
+        assert_eq!(names, ["Bit", "Shape"]);
+        assert_eq!(program.functions().count(), 1);
+    }
+
+    #[test]
+    fn test_empty_program() {
+        assert!(Program::from_source("", Path::new("test.u")).unwrap().items.is_empty());
+    }
+
+    #[test]
+    fn test_unexpected_item() {
+        let err = Program::from_source("let x = 1", Path::new("test.u")).unwrap_err();
+
+        assert!(matches!(err.kind, CommonErrorKind::Parser(ErrorKind::UnexpectedToken { .. })));
+        assert_eq!(err.chunk.unwrap().slice, "let");
+    }
+}
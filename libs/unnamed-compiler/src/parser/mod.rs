@@ -12,12 +12,16 @@ use self::{
 
 pub mod cursor;
 pub mod delimited;
+pub mod enumeration;
 pub mod error;
 pub mod expressions;
 pub mod function;
+pub mod item;
 pub mod primitive;
 pub mod punctuated;
+pub mod span;
 pub mod statements;
+pub mod types;
 
 pub type Block<'source> =
     Braced<'source, Punctuated<'source, Statement<'source>, Semicolon, RightBrace>>;
@@ -97,6 +97,9 @@ implement_primitive!(
     Greeter,
     Eq,
     Assignment,
+    FatArrow,
+    Bang,
+    Ampersand,
     LeftParenthesis,
     RightParenthesis,
     LeftBrace,
@@ -112,6 +115,36 @@ implement_primitive!(
     LetKw,
     MutKw,
     TrueKw,
-    FalseKw
+    FalseKw,
+    AsmKw,
+    AsKw,
+    EnumKw,
+    MatchKw
 );
-implement_primitive_inner!(Integer<'source>, Float<'source>, Identifier<'source>);
+implement_primitive_inner!(Integer<'source>, Float<'source>, Identifier<'source>, Str<'source>);
+
+impl Str<'_> {
+    /// The string without its quotes, with escape sequences resolved.
+    pub fn value(&self) -> String {
+        let inner = &self.0[1..self.0.len() - 1];
+        let mut value = String::with_capacity(inner.len());
+
+        let mut chars = inner.chars();
+        while let Some(char) = chars.next() {
+            if char != '\\' {
+                value.push(char);
+                continue;
+            }
+
+            match chars.next() {
+                Some('n') => value.push('\n'),
+                Some('t') => value.push('\t'),
+                Some('0') => value.push('\0'),
+                Some(char) => value.push(char),
+                None => {}
+            }
+        }
+
+        value
+    }
+}
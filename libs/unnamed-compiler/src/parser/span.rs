@@ -0,0 +1,43 @@
+use std::{ops::Index, path::Path};
+
+use crate::{
+    common::error::Result,
+    lexer::token::{Chunk, Position, Token},
+};
+
+use super::{cursor::Cursor, Parse};
+
+/// Location of the token a node starts at. Spans never take part in comparisons, so trees built
+/// by hand compare equal to parsed ones.
+#[derive(Debug, Clone, Copy, Eq)]
+pub struct Span<'source>(pub Chunk<'source>);
+
+impl<'source> Span<'source> {
+    pub fn chunk(&self) -> Chunk<'source> {
+        self.0
+    }
+
+    pub fn position(&self) -> &Position<'source> {
+        &self.0.position
+    }
+}
+
+impl<'source> Default for Span<'source> {
+    fn default() -> Self {
+        Self(Chunk::new(Position::new(0, 0, 0, 0, Path::new("")), ""))
+    }
+}
+
+impl<'source> PartialEq for Span<'source> {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl<'source> Parse<'source> for Span<'source> {
+    fn parse<I: Index<usize, Output = Token<'source>>>(
+        cursor: &mut Cursor<'source, I>,
+    ) -> Result<'source, Self> {
+        Ok(Self(cursor.peek()?.chunk))
+    }
+}
@@ -3,10 +3,12 @@ use derive_macro::Parse;
 use crate::parser::{
     expressions::Expression,
     primitive::{Assignment, Identifier, LetKw, MutKw},
+    span::Span,
 };
 
 #[derive(Parse, Debug, Clone, PartialEq, Eq)]
 pub struct LetStatement<'source> {
+    pub span: Span<'source>,
     pub let_kw: LetKw,
     pub mut_kw: Option<MutKw>,
     pub name: Identifier<'source>,
@@ -18,6 +20,7 @@ pub struct LetStatement<'source> {
 impl<'source> LetStatement<'source> {
     pub fn new(name: Identifier<'source>, is_mut: bool, init: Option<Expression<'source>>) -> Self {
         Self {
+            span: Default::default(),
             let_kw: Default::default(),
             mut_kw: if is_mut { Some(Default::default()) } else { None },
             name,
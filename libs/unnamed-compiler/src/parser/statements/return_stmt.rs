@@ -1,9 +1,10 @@
 use derive_macro::Parse;
 
-use crate::parser::{expressions::Expression, primitive::ReturnKw};
+use crate::parser::{expressions::Expression, primitive::ReturnKw, span::Span};
 
 #[derive(Parse, Debug, Clone, PartialEq, Eq)]
 pub struct ReturnStatement<'source> {
+    pub span: Span<'source>,
     pub return_kw: ReturnKw,
     #[parse_if(cursor.test(Expression::POSSIBLE_TOKENS)?)]
     pub expression: Option<Expression<'source>>,
@@ -11,6 +12,6 @@ pub struct ReturnStatement<'source> {
 
 impl<'source> ReturnStatement<'source> {
     pub fn new(expression: Option<Expression<'source>>) -> Self {
-        Self { return_kw: Default::default(), expression }
+        Self { span: Default::default(), return_kw: Default::default(), expression }
     }
 }
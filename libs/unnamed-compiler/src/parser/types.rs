@@ -0,0 +1,222 @@
+use std::ops::Index;
+
+use crate::{common::error::Result, lexer::token::Token};
+
+use super::{
+    cursor::Cursor,
+    primitive::{Ampersand, Comma, Greeter, Identifier, Less, Multiply, MutKw},
+    punctuated::Punctuated,
+    Parse, SyntaxKind,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Type<'source> {
+    Named(Identifier<'source>),
+    Generic {
+        ident: Identifier<'source>,
+        arguments: Punctuated<'source, Type<'source>, Comma, Greeter>,
+    },
+    Pointer(Box<Type<'source>>),
+    Reference {
+        mutable: bool,
+        ty: Box<Type<'source>>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrimitiveType {
+    Bool,
+    Int { width: u32, signed: bool },
+    Size { signed: bool },
+    Float { width: u32 },
+}
+
+impl PrimitiveType {
+    pub fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "bool" => PrimitiveType::Bool,
+            "i8" => PrimitiveType::Int { width: 8, signed: true },
+            "i16" => PrimitiveType::Int { width: 16, signed: true },
+            "i32" => PrimitiveType::Int { width: 32, signed: true },
+            "i64" => PrimitiveType::Int { width: 64, signed: true },
+            "i128" => PrimitiveType::Int { width: 128, signed: true },
+            "u8" => PrimitiveType::Int { width: 8, signed: false },
+            "u16" => PrimitiveType::Int { width: 16, signed: false },
+            "u32" => PrimitiveType::Int { width: 32, signed: false },
+            "u64" => PrimitiveType::Int { width: 64, signed: false },
+            "u128" => PrimitiveType::Int { width: 128, signed: false },
+            "isize" => PrimitiveType::Size { signed: true },
+            "usize" => PrimitiveType::Size { signed: false },
+            "f32" => PrimitiveType::Float { width: 32 },
+            "f64" => PrimitiveType::Float { width: 64 },
+            _ => return None,
+        })
+    }
+
+    pub fn is_signed(&self) -> bool {
+        matches!(
+            self,
+            PrimitiveType::Int { signed: true, .. } | PrimitiveType::Size { signed: true }
+        )
+    }
+
+    pub fn is_integer(&self) -> bool {
+        matches!(self, PrimitiveType::Bool | PrimitiveType::Int { .. } | PrimitiveType::Size { .. })
+    }
+
+    pub fn can_cast_to(&self, to: PrimitiveType) -> bool {
+        match (self, to) {
+            (PrimitiveType::Bool, PrimitiveType::Float { .. }) => false,
+            (_, PrimitiveType::Bool) => *self == PrimitiveType::Bool,
+            _ => true,
+        }
+    }
+}
+
+impl<'source> Type<'source> {
+    pub fn as_primitive(&self) -> Option<PrimitiveType> {
+        match self {
+            Type::Named(Identifier(name)) => PrimitiveType::from_name(name),
+            Type::Generic { .. } | Type::Pointer(..) | Type::Reference { .. } => None,
+        }
+    }
+}
+
+impl<'source> Type<'source> {
+    pub fn parse_without_generics<I: Index<usize, Output = Token<'source>>>(
+        cursor: &mut Cursor<'source, I>,
+    ) -> Result<'source, Self> {
+        Self::parse_inner(cursor, false)
+    }
+
+    fn parse_inner<I: Index<usize, Output = Token<'source>>>(
+        cursor: &mut Cursor<'source, I>,
+        generics: bool,
+    ) -> Result<'source, Self> {
+        if Multiply::test(cursor) {
+            cursor.parse::<Multiply>()?;
+            return Ok(Type::Pointer(Box::new(Self::parse_inner(cursor, generics)?)));
+        }
+
+        if Ampersand::test(cursor) {
+            cursor.parse::<Ampersand>()?;
+            let mutable = cursor.parse::<Option<MutKw>>()?.is_some();
+            let ty = Box::new(Self::parse_inner(cursor, generics)?);
+            return Ok(Type::Reference { mutable, ty });
+        }
+
+        let ident = cursor.parse()?;
+
+        if !generics || !Less::test(cursor) {
+            return Ok(Type::Named(ident));
+        }
+
+        cursor.parse::<Less>()?;
+        let arguments = cursor.parse()?;
+        cursor.parse::<Greeter>()?;
+
+        Ok(Type::Generic { ident, arguments })
+    }
+}
+
+impl<'source> Parse<'source> for Type<'source> {
+    fn parse<I: Index<usize, Output = Token<'source>>>(
+        cursor: &mut Cursor<'source, I>,
+    ) -> Result<'source, Self> {
+        Self::parse_inner(cursor, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        parser::{primitive::Identifier, punctuated::Punctuated},
+        tests,
+    };
+
+    use super::{PrimitiveType, Type};
+
+    tests! {
+        test_named<Type>("int"): Type::Named(Identifier("int"));
+        test_generic<Type>("vec4<float>"): Type::Generic {
+            ident: Identifier("vec4"),
+            arguments: Punctuated::new(vec![Type::Named(Identifier("float"))]),
+        };
+        test_nested_generic<Type>("map<int, vec4<float>>"): Type::Generic {
+            ident: Identifier("map"),
+            arguments: Punctuated::new(vec![
+                Type::Named(Identifier("int")),
+                Type::Generic {
+                    ident: Identifier("vec4"),
+                    arguments: Punctuated::new(vec![Type::Named(Identifier("float"))]),
+                },
+            ]),
+        };
+        test_pointer<Type>("*u8"): Type::Pointer(Box::new(Type::Named(Identifier("u8"))));
+        test_pointer_to_pointer<Type>("**u8"): Type::Pointer(Box::new(Type::Pointer(Box::new(Type::Named(Identifier("u8"))))));
+        test_reference<Type>("&i32"): Type::Reference { mutable: false, ty: Box::new(Type::Named(Identifier("i32"))) };
+        test_mut_reference<Type>("&mut vec4<f32>"): Type::Reference {
+            mutable: true,
+            ty: Box::new(Type::Generic {
+                ident: Identifier("vec4"),
+                arguments: Punctuated::new(vec![Type::Named(Identifier("f32"))]),
+            }),
+        };
+    }
+
+    #[test]
+    fn test_primitive_names() {
+        assert_eq!(PrimitiveType::from_name("bool"), Some(PrimitiveType::Bool));
+        assert_eq!(
+            PrimitiveType::from_name("i8"),
+            Some(PrimitiveType::Int { width: 8, signed: true })
+        );
+        assert_eq!(
+            PrimitiveType::from_name("u128"),
+            Some(PrimitiveType::Int { width: 128, signed: false })
+        );
+        assert_eq!(PrimitiveType::from_name("usize"), Some(PrimitiveType::Size { signed: false }));
+        assert_eq!(PrimitiveType::from_name("f64"), Some(PrimitiveType::Float { width: 64 }));
+        assert_eq!(PrimitiveType::from_name("vec4"), None);
+    }
+
+    #[test]
+    fn test_signedness() {
+        assert!(PrimitiveType::from_name("i32").unwrap().is_signed());
+        assert!(PrimitiveType::from_name("isize").unwrap().is_signed());
+        assert!(!PrimitiveType::from_name("u32").unwrap().is_signed());
+        assert!(!PrimitiveType::from_name("bool").unwrap().is_signed());
+    }
+
+    #[test]
+    fn test_as_primitive() {
+        assert_eq!(
+            Type::Named(Identifier("u16")).as_primitive(),
+            Some(PrimitiveType::Int { width: 16, signed: false })
+        );
+        assert_eq!(
+            Type::Generic { ident: Identifier("vec4"), arguments: Punctuated::new(vec![]) }
+                .as_primitive(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_can_cast_to() {
+        let bool = PrimitiveType::Bool;
+        let i8 = PrimitiveType::Int { width: 8, signed: true };
+        let u64 = PrimitiveType::Int { width: 64, signed: false };
+        let usize = PrimitiveType::Size { signed: false };
+        let f32 = PrimitiveType::Float { width: 32 };
+
+        assert!(i8.can_cast_to(u64));
+        assert!(u64.can_cast_to(i8));
+        assert!(usize.can_cast_to(f32));
+        assert!(f32.can_cast_to(i8));
+        assert!(bool.can_cast_to(u64));
+        assert!(bool.can_cast_to(bool));
+        assert!(!bool.can_cast_to(f32));
+        assert!(!i8.can_cast_to(bool));
+        assert!(!f32.can_cast_to(bool));
+    }
+}
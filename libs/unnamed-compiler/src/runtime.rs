@@ -0,0 +1,197 @@
+use std::{
+    env,
+    fmt::{self, Display},
+    fs, io,
+    path::Path,
+    process::{self, Command, ExitStatus},
+};
+
+use llvm::{
+    attribute::AttributeKind,
+    context::Context,
+    error::TargetError,
+    module::Module,
+    target::{FileType, TargetMachine},
+    types::function::FunctionType,
+    values::function::FunctionValue,
+};
+
+pub const RUNTIME_SOURCE: &str = include_str!("../runtime/runtime.c");
+
+#[derive(Debug)]
+pub enum LinkError {
+    Emit(TargetError),
+    Io(io::Error),
+    Compiler(ExitStatus),
+}
+
+impl Display for LinkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LinkError::Emit(err) => write!(f, "{err}"),
+            LinkError::Io(err) => write!(f, "failed to run the C compiler: {err}"),
+            LinkError::Compiler(status) => write!(f, "C compiler failed: {status}"),
+        }
+    }
+}
+
+impl std::error::Error for LinkError {}
+
+impl From<TargetError> for LinkError {
+    fn from(err: TargetError) -> Self {
+        LinkError::Emit(err)
+    }
+}
+
+impl From<io::Error> for LinkError {
+    fn from(err: io::Error) -> Self {
+        LinkError::Io(err)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Builtin {
+    Alloc,
+    Free,
+    PrintInt,
+    PrintFloat,
+    PrintStr,
+    Panic,
+}
+
+impl Builtin {
+    pub const ALL: [Builtin; 6] = [
+        Builtin::Alloc,
+        Builtin::Free,
+        Builtin::PrintInt,
+        Builtin::PrintFloat,
+        Builtin::PrintStr,
+        Builtin::Panic,
+    ];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|builtin| builtin.name() == name)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Builtin::Alloc => "alloc",
+            Builtin::Free => "free",
+            Builtin::PrintInt => "print_int",
+            Builtin::PrintFloat => "print_float",
+            Builtin::PrintStr => "print_str",
+            Builtin::Panic => "panic",
+        }
+    }
+
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Builtin::Alloc => "unnamed_alloc",
+            Builtin::Free => "unnamed_free",
+            Builtin::PrintInt => "unnamed_print_int",
+            Builtin::PrintFloat => "unnamed_print_float",
+            Builtin::PrintStr => "unnamed_print_str",
+            Builtin::Panic => "unnamed_panic",
+        }
+    }
+
+    pub fn function_type<'ctx>(&self, context: &'ctx Context) -> FunctionType<'ctx> {
+        match self {
+            Builtin::Alloc => context.fn_type::<(u64,), *mut u8>().as_function_type(),
+            Builtin::Free => context.fn_type::<(*mut u8,), ()>().as_function_type(),
+            Builtin::PrintInt => context.fn_type::<(i64,), ()>().as_function_type(),
+            Builtin::PrintFloat => context.fn_type::<(f64,), ()>().as_function_type(),
+            Builtin::PrintStr | Builtin::Panic => {
+                context.fn_type::<(*const u8, u64), ()>().as_function_type()
+            }
+        }
+    }
+
+    pub fn declare<'ctx>(
+        &self,
+        context: &'ctx Context,
+        module: &Module<'ctx>,
+    ) -> FunctionValue<'ctx> {
+        if let Some(function) = module.get_function(self.symbol()) {
+            return function;
+        }
+
+        let function = module.add_function(self.symbol(), self.function_type(context));
+        if let Builtin::Panic = self {
+            let attributes = [AttributeKind::NoReturn, AttributeKind::Cold]
+                .into_iter()
+                .filter_map(|kind| context.attribute(kind))
+                .collect::<Vec<_>>();
+            function.add_attributes(&attributes);
+        }
+
+        function
+    }
+}
+
+pub fn declare_builtins<'ctx>(context: &'ctx Context, module: &Module<'ctx>) {
+    for builtin in Builtin::ALL {
+        builtin.declare(context, module);
+    }
+}
+
+/// Emits `module` as an object file and links it with the runtime into the executable `output`.
+/// The C compiler is taken from `$CC`, falling back to `cc`.
+pub fn link(machine: &TargetMachine, module: &Module, output: &Path) -> Result<(), LinkError> {
+    let directory = env::temp_dir().join(format!("unnamed-{}", process::id()));
+    fs::create_dir_all(&directory)?;
+
+    let result = link_in(&directory, machine, module, output);
+    fs::remove_dir_all(&directory)?;
+
+    result
+}
+
+fn link_in(
+    directory: &Path,
+    machine: &TargetMachine,
+    module: &Module,
+    output: &Path,
+) -> Result<(), LinkError> {
+    let object = directory.join("module.o");
+    let runtime = directory.join("runtime.c");
+    machine.emit_to_file(module, &object, FileType::Object)?;
+    fs::write(&runtime, RUNTIME_SOURCE)?;
+
+    let compiler = env::var_os("CC").unwrap_or_else(|| "cc".into());
+    let status =
+        Command::new(compiler).arg(&object).arg(&runtime).arg("-o").arg(output).status()?;
+    if !status.success() {
+        return Err(LinkError::Compiler(status));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use llvm::context::Context;
+
+    use super::{declare_builtins, Builtin};
+
+    #[test]
+    fn test_builtin_names() {
+        for builtin in Builtin::ALL {
+            assert_eq!(Builtin::from_name(builtin.name()), Some(builtin));
+        }
+        assert_eq!(Builtin::from_name("print"), None);
+    }
+
+    #[test]
+    fn test_declare_builtins() {
+        let context = Context::create();
+        let module = context.module("test");
+
+        declare_builtins(&context, &module);
+        declare_builtins(&context, &module);
+
+        for builtin in Builtin::ALL {
+            assert!(module.get_function(builtin.symbol()).is_some());
+        }
+    }
+}
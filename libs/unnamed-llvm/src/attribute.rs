@@ -1,7 +1,129 @@
-use llvm_sys::prelude::LLVMAttributeRef;
+use llvm_sys::{
+    core::{
+        LLVMGetEnumAttributeKindForName, LLVMIsEnumAttribute, LLVMIsStringAttribute,
+        LLVMIsTypeAttribute,
+    },
+    prelude::LLVMAttributeRef,
+};
 
-pub use crate::extra::{AttributeKind, AttributeLocation};
-use crate::{context::Context, impl_as_raw, impl_get_context};
+pub use crate::extra::AttributeLocation;
+use crate::{context::Context, impl_as_raw, impl_get_context, AsRaw};
+
+macro_rules! attribute_kinds {
+    ($kind: ident { $($variant: ident => $name: literal),* $(,)? }) => {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub enum $kind {
+            $($variant),*
+        }
+
+        impl $kind {
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $name),*
+                }
+            }
+
+            pub fn id(&self) -> Option<u32> {
+                let name = self.name();
+                let id =
+                    unsafe { LLVMGetEnumAttributeKindForName(name.as_ptr().cast(), name.len()) };
+
+                (id != 0).then_some(id)
+            }
+        }
+    };
+}
+
+attribute_kinds!(AttributeKind {
+    AllocAlign => "allocalign",
+    AllocatedPointer => "allocptr",
+    AlwaysInline => "alwaysinline",
+    Builtin => "builtin",
+    Cold => "cold",
+    Convergent => "convergent",
+    DisableSanitizerInstrumentation => "disable_sanitizer_instrumentation",
+    FnRetThunkExtern => "fn_ret_thunk_extern",
+    Hot => "hot",
+    ImmArg => "immarg",
+    InReg => "inreg",
+    InlineHint => "inlinehint",
+    JumpTable => "jumptable",
+    MinSize => "minsize",
+    MustProgress => "mustprogress",
+    Naked => "naked",
+    Nest => "nest",
+    NoAlias => "noalias",
+    NoBuiltin => "nobuiltin",
+    NoCallback => "nocallback",
+    NoCapture => "nocapture",
+    NoCfCheck => "nocf_check",
+    NoDuplicate => "noduplicate",
+    NoFree => "nofree",
+    NoImplicitFloat => "noimplicitfloat",
+    NoInline => "noinline",
+    NoMerge => "nomerge",
+    NoProfile => "noprofile",
+    NoRecurse => "norecurse",
+    NoRedZone => "noredzone",
+    NoReturn => "noreturn",
+    NoSanitizeBounds => "nosanitize_bounds",
+    NoSanitizeCoverage => "nosanitize_coverage",
+    NoSync => "nosync",
+    NoUndef => "noundef",
+    NoUnwind => "nounwind",
+    NonLazyBind => "nonlazybind",
+    NonNull => "nonnull",
+    NullPointerIsValid => "null_pointer_is_valid",
+    OptForFuzzing => "optforfuzzing",
+    OptimizeForSize => "optsize",
+    OptimizeNone => "optnone",
+    PresplitCoroutine => "presplitcoroutine",
+    ReadNone => "readnone",
+    ReadOnly => "readonly",
+    Returned => "returned",
+    ReturnsTwice => "returns_twice",
+    SExt => "signext",
+    SafeStack => "safestack",
+    SanitizeAddress => "sanitize_address",
+    SanitizeHWAddress => "sanitize_hwaddress",
+    SanitizeMemTag => "sanitize_memtag",
+    SanitizeMemory => "sanitize_memory",
+    SanitizeThread => "sanitize_thread",
+    ShadowCallStack => "shadowcallstack",
+    Speculatable => "speculatable",
+    SpeculativeLoadHardening => "speculative_load_hardening",
+    StackProtect => "ssp",
+    StackProtectReq => "sspreq",
+    StackProtectStrong => "sspstrong",
+    StrictFP => "strictfp",
+    SwiftAsync => "swiftasync",
+    SwiftError => "swifterror",
+    SwiftSelf => "swiftself",
+    WillReturn => "willreturn",
+    WriteOnly => "writeonly",
+    ZExt => "zeroext",
+});
+
+attribute_kinds!(IntAttributeKind {
+    Alignment => "align",
+    AllocKind => "allockind",
+    AllocSize => "allocsize",
+    Dereferenceable => "dereferenceable",
+    DereferenceableOrNull => "dereferenceable_or_null",
+    Memory => "memory",
+    StackAlignment => "alignstack",
+    UWTable => "uwtable",
+    VScaleRange => "vscale_range",
+});
+
+attribute_kinds!(TypeAttributeKind {
+    ByRef => "byref",
+    ByVal => "byval",
+    ElementType => "elementtype",
+    InAlloca => "inalloca",
+    Preallocated => "preallocated",
+    StructRet => "sret",
+});
 
 #[derive(Debug, Clone)]
 pub struct Attribute<'ctx> {
@@ -13,7 +135,39 @@ impl<'ctx> Attribute<'ctx> {
     pub(crate) fn new(_context: &'ctx Context, inner: LLVMAttributeRef) -> Self {
         Self { context: _context, inner }
     }
+
+    pub fn is_enum(&self) -> bool {
+        unsafe { LLVMIsEnumAttribute(self.as_raw()) != 0 }
+    }
+
+    pub fn is_type(&self) -> bool {
+        unsafe { LLVMIsTypeAttribute(self.as_raw()) != 0 }
+    }
+
+    pub fn is_string(&self) -> bool {
+        unsafe { LLVMIsStringAttribute(self.as_raw()) != 0 }
+    }
 }
 
 impl_as_raw!(Attribute<'ctx>.inner -> LLVMAttributeRef);
 impl_get_context!(Attribute<'ctx>.context);
+
+#[cfg(test)]
+mod tests {
+    use crate::context::Context;
+
+    use super::{AttributeKind, IntAttributeKind, TypeAttributeKind};
+
+    #[test]
+    fn test_create_attributes() {
+        let context = Context::create();
+
+        assert!(AttributeKind::NoReturn.id().is_some());
+        assert!(context.attribute(AttributeKind::NoReturn).unwrap().is_enum());
+        assert!(context.int_attribute(IntAttributeKind::Alignment, 16).unwrap().is_enum());
+
+        let byval = context.type_attribute(TypeAttributeKind::ByVal, context.int::<8>().into());
+        assert!(byval.unwrap().is_type());
+        assert!(context.string_attribute("target-cpu", "generic").is_string());
+    }
+}
@@ -1,29 +1,56 @@
+use std::{
+    cell::Cell,
+    cmp::Ordering,
+    ops::{BitOr, BitOrAssign},
+    ptr,
+};
+
 use llvm_sys::{
     core::{
-        LLVMBuildAdd, LLVMBuildBr, LLVMBuildCall2, LLVMBuildCondBr, LLVMBuildFAdd, LLVMBuildFCmp,
-        LLVMBuildFDiv, LLVMBuildFMul, LLVMBuildFPToSI, LLVMBuildFPToUI, LLVMBuildFPTrunc,
-        LLVMBuildFSub, LLVMBuildICmp, LLVMBuildMul, LLVMBuildPhi, LLVMBuildRet, LLVMBuildSDiv,
-        LLVMBuildSIToFP, LLVMBuildSub, LLVMBuildTrunc, LLVMBuildUDiv, LLVMBuildUIToFP,
-        LLVMBuildZExt, LLVMPositionBuilderAtEnd,
+        LLVMAddCase, LLVMAddDestination, LLVMBuildAdd, LLVMBuildAlloca, LLVMBuildAtomicCmpXchg,
+        LLVMBuildAtomicRMW, LLVMBuildBr, LLVMBuildCall2, LLVMBuildCast, LLVMBuildCondBr,
+        LLVMBuildExtractElement, LLVMBuildExtractValue, LLVMBuildFAdd, LLVMBuildFCmp,
+        LLVMBuildFDiv, LLVMBuildFMul, LLVMBuildFPToSI, LLVMBuildFPToUI, LLVMBuildFSub,
+        LLVMBuildFence, LLVMBuildICmp, LLVMBuildIndirectBr, LLVMBuildInsertElement,
+        LLVMBuildInsertValue, LLVMBuildInvoke2, LLVMBuildLandingPad, LLVMBuildLoad2, LLVMBuildMul,
+        LLVMBuildNSWAdd, LLVMBuildNSWMul, LLVMBuildNSWSub, LLVMBuildNUWAdd, LLVMBuildNUWMul,
+        LLVMBuildNUWSub, LLVMBuildPhi, LLVMBuildResume, LLVMBuildRet, LLVMBuildRetVoid,
+        LLVMBuildSDiv, LLVMBuildSIToFP, LLVMBuildSRem, LLVMBuildSelect, LLVMBuildShuffleVector,
+        LLVMBuildStore, LLVMBuildSub, LLVMBuildSwitch, LLVMBuildUDiv, LLVMBuildUIToFP,
+        LLVMBuildURem, LLVMBuildUnreachable, LLVMClearInsertionPosition, LLVMGetBasicBlockParent,
+        LLVMGetCurrentDebugLocation2, LLVMGetGlobalParent, LLVMGetInsertBlock,
+        LLVMPositionBuilderAtEnd, LLVMPositionBuilderBefore, LLVMSetAtomicSingleThread,
+        LLVMSetCurrentDebugLocation2, LLVMSetOrdering,
     },
-    prelude::LLVMBuilderRef,
-    LLVMIntPredicate, LLVMRealPredicate,
+    prelude::{LLVMBuilderRef, LLVMMetadataRef, LLVMValueRef},
+    LLVMAtomicOrdering, LLVMAtomicRMWBinOp, LLVMIntPredicate, LLVMOpcode, LLVMRealPredicate,
 };
 
 use crate::{
     context::Context,
-    impl_as_raw, impl_get_context, to_c_str,
-    types::{float::FloatType, function::FunctionType, integer::IntegerType, Type},
+    debug_info::DILocation,
+    error::{AtomicError, CastError, IntrinsicError},
+    extra::{LLVMRustGetInsertPoint, LLVMRustSetFastMathFlags},
+    impl_as_raw, impl_get_context,
+    module::Module,
+    signature::{Params, StaticType, TypedFunctionValue},
+    to_c_str,
+    types::{
+        float::FloatType, function::FunctionType, integer::IntegerType, pointer::PointerType, Type,
+    },
     values::{
-        base_value::BaseValue, basic_block::BasicBlock, float::FloatValue, function::FunctionValue,
-        integer::IntegerValue, phi_node::PhiNodeValue, TypeOf, Value,
+        base_value::BaseValue, basic_block::BasicBlock, call_site::CallSiteValue,
+        float::FloatValue, function::FunctionValue, inline_asm::InlineAsm,
+        instruction::InstructionValue, integer::IntegerValue, landing_pad::LandingPadValue,
+        phi_node::PhiNodeValue, pointer::PointerValue, structure::StructValue, vector::VectorValue,
+        TypeOf, Value,
     },
-    AsRaw, GetContext,
+    AsRaw,
 };
 
 macro_rules! int_op {
     ($name: ident($op: ident)) => {
-        pub fn $name<L, R>(&self, lhs: L, rhs: R, name: &str) -> IntegerValue
+        pub fn $name<L, R>(&self, lhs: L, rhs: R, name: &str) -> IntegerValue<'ctx>
         where
             IntegerValue<'ctx>: From<L>,
             IntegerValue<'ctx>: From<R>,
@@ -35,14 +62,14 @@ macro_rules! int_op {
 
             let int_ref = unsafe { $op(self.as_raw(), lhs.as_raw(), rhs.as_raw(), name.as_ptr()) };
 
-            IntegerValue(BaseValue::new(self.get_context(), int_ref))
+            IntegerValue(BaseValue::new(self.context, int_ref))
         }
     };
 }
 
 macro_rules! float_op {
     ($name: ident($op: ident)) => {
-        pub fn $name<L, R>(&self, lhs: L, rhs: R, name: &str) -> FloatValue
+        pub fn $name<L, R>(&self, lhs: L, rhs: R, name: &str) -> FloatValue<'ctx>
         where
             FloatValue<'ctx>: From<L>,
             FloatValue<'ctx>: From<R>,
@@ -55,49 +82,339 @@ macro_rules! float_op {
             let float_ref =
                 unsafe { $op(self.as_raw(), lhs.as_raw(), rhs.as_raw(), name.as_ptr()) };
 
-            FloatValue::new(BaseValue::new(self.get_context(), float_ref), rhs.kind)
+            self.apply_fast_math_flags(float_ref);
+
+            FloatValue::new(BaseValue::new(self.context, float_ref), rhs.kind)
         }
     };
 }
 
-macro_rules! int_cast {
-    ($name: ident($cast: ident); assert(|$value: ident, $ty: ident| $assert: expr)) => {
-        pub fn $name<V>(&self, $value: V, $ty: IntegerType<'ctx>, name: &str) -> IntegerValue
+macro_rules! vector_op {
+    ($name: ident($op: ident)) => {
+        pub fn $name<L, R>(&self, lhs: L, rhs: R, name: &str) -> VectorValue<'ctx>
         where
-            IntegerValue<'ctx>: From<V>,
+            VectorValue<'ctx>: From<L>,
+            VectorValue<'ctx>: From<R>,
         {
-            let $value: IntegerValue<'ctx> = $value.into();
+            let lhs = VectorValue::from(lhs);
+            let rhs = VectorValue::from(rhs);
+
             let name = to_c_str(name);
 
-            assert!($assert);
+            let vector_ref =
+                unsafe { $op(self.as_raw(), lhs.as_raw(), rhs.as_raw(), name.as_ptr()) };
+
+            self.apply_fast_math_flags(vector_ref);
+
+            VectorValue(BaseValue::new(self.context, vector_ref))
+        }
+    };
+}
 
-            let int_ref =
-                unsafe { $cast(self.as_raw(), $value.as_raw(), $ty.as_raw(), name.as_ptr()) };
+macro_rules! int_cast {
+    ($name: ident($op: ident)) => {
+        pub fn $name<V>(
+            &self,
+            value: V,
+            ty: IntegerType<'ctx>,
+            name: &str,
+        ) -> Result<IntegerValue<'ctx>, CastError>
+        where
+            IntegerValue<'ctx>: From<V>,
+        {
+            let value = IntegerValue::from(value);
 
-            IntegerValue(BaseValue::new(self.get_context(), int_ref))
+            self.build_cast(CastOp::$op, value, ty, name).map(Into::into)
         }
     };
 }
 
 macro_rules! float_cast {
-    ($name: ident($cast: ident); assert(|$value: ident, $ty: ident| $assert: expr)) => {
-        pub fn $name<V>(&self, $value: V, $ty: FloatType<'ctx>, name: &str) -> FloatValue
+    ($name: ident($op: ident)) => {
+        pub fn $name<V>(
+            &self,
+            value: V,
+            ty: FloatType<'ctx>,
+            name: &str,
+        ) -> Result<FloatValue<'ctx>, CastError>
         where
             FloatValue<'ctx>: From<V>,
         {
-            let $value = FloatValue::from($value);
-            let name = to_c_str(name);
+            let value = FloatValue::from(value);
+
+            self.build_cast(CastOp::$op, value, ty, name).map(Into::into)
+        }
+    };
+}
+
+macro_rules! float_intrinsic {
+    ($name: ident($intrinsic: literal)) => {
+        pub fn $name<V>(&self, value: V, name: &str) -> Result<FloatValue<'ctx>, IntrinsicError>
+        where
+            FloatValue<'ctx>: From<V>,
+        {
+            let value = FloatValue::from(value);
 
-            assert!($assert);
+            let float_ref = self.build_intrinsic_call(
+                $intrinsic,
+                &[value.type_of().into()],
+                &[value.as_raw()],
+                name,
+            )?;
 
-            let float_ref =
-                unsafe { $cast(self.as_raw(), $value.as_raw(), $ty.as_raw(), name.as_ptr()) };
+            Ok(FloatValue::new(BaseValue::new(self.context, float_ref), value.kind))
+        }
+    };
+}
+
+macro_rules! float_binary_intrinsic {
+    ($name: ident($intrinsic: literal)) => {
+        pub fn $name<L, R>(
+            &self,
+            lhs: L,
+            rhs: R,
+            name: &str,
+        ) -> Result<FloatValue<'ctx>, IntrinsicError>
+        where
+            FloatValue<'ctx>: From<L>,
+            FloatValue<'ctx>: From<R>,
+        {
+            let lhs = FloatValue::from(lhs);
+            let rhs = FloatValue::from(rhs);
+
+            let float_ref = self.build_intrinsic_call(
+                $intrinsic,
+                &[lhs.type_of().into()],
+                &[lhs.as_raw(), rhs.as_raw()],
+                name,
+            )?;
+
+            Ok(FloatValue::new(BaseValue::new(self.context, float_ref), lhs.kind))
+        }
+    };
+}
+
+macro_rules! int_intrinsic {
+    ($name: ident($intrinsic: literal)) => {
+        pub fn $name<V>(&self, value: V, name: &str) -> Result<IntegerValue<'ctx>, IntrinsicError>
+        where
+            IntegerValue<'ctx>: From<V>,
+        {
+            let value = IntegerValue::from(value);
+
+            let int_ref = self.build_intrinsic_call(
+                $intrinsic,
+                &[value.type_of().into()],
+                &[value.as_raw()],
+                name,
+            )?;
+
+            Ok(IntegerValue(BaseValue::new(self.context, int_ref)))
+        }
+    };
+    ($name: ident($intrinsic: literal, $flag: ident)) => {
+        pub fn $name<V>(
+            &self,
+            value: V,
+            $flag: bool,
+            name: &str,
+        ) -> Result<IntegerValue<'ctx>, IntrinsicError>
+        where
+            IntegerValue<'ctx>: From<V>,
+        {
+            let value = IntegerValue::from(value);
+            let flag = self.context.int::<1>().constant($flag as u64).as_raw();
+
+            let int_ref = self.build_intrinsic_call(
+                $intrinsic,
+                &[value.type_of().into()],
+                &[value.as_raw(), flag],
+                name,
+            )?;
+
+            Ok(IntegerValue(BaseValue::new(self.context, int_ref)))
+        }
+    };
+}
+
+macro_rules! overflow_intrinsic {
+    ($name: ident($intrinsic: literal)) => {
+        pub fn $name<L, R>(
+            &self,
+            lhs: L,
+            rhs: R,
+            name: &str,
+        ) -> Result<StructValue<'ctx>, IntrinsicError>
+        where
+            IntegerValue<'ctx>: From<L>,
+            IntegerValue<'ctx>: From<R>,
+        {
+            let lhs = IntegerValue::from(lhs);
+            let rhs = IntegerValue::from(rhs);
 
-            FloatValue::new(BaseValue::new(self.get_context(), float_ref), $ty.kind)
+            let struct_ref = self.build_intrinsic_call(
+                $intrinsic,
+                &[lhs.type_of().into()],
+                &[lhs.as_raw(), rhs.as_raw()],
+                name,
+            )?;
+
+            Ok(StructValue(BaseValue::new(self.context, struct_ref)))
         }
     };
 }
 
+pub type SwitchCase<'ctx> = (IntegerValue<'ctx>, BasicBlock<'ctx>);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CastOp {
+    Trunc,
+    ZeroExtend,
+    SignExtend,
+    FloatTrunc,
+    FloatExtend,
+    FloatToUnsignedInt,
+    FloatToSignedInt,
+    UnsignedIntToFloat,
+    SignedIntToFloat,
+    PtrToInt,
+    IntToPtr,
+    BitCast,
+    AddressSpaceCast,
+}
+
+impl CastOp {
+    pub fn infer(from: Type, to: Type, signed: bool) -> Result<Self, CastError> {
+        let op = match (from, to) {
+            (Type::Integer(from), Type::Integer(to)) => match from.width().cmp(&to.width()) {
+                Ordering::Less if signed => Self::SignExtend,
+                Ordering::Less => Self::ZeroExtend,
+                Ordering::Greater => Self::Trunc,
+                Ordering::Equal => Self::BitCast,
+            },
+            (Type::Float(from_float), Type::Float(to_float))
+                if from_float.kind != to_float.kind && from_float.size() == to_float.size() =>
+            {
+                return Err(CastError::InvalidTypes { op: None, from: from.kind(), to: to.kind() });
+            }
+            (Type::Float(from), Type::Float(to)) => match from.size().cmp(&to.size()) {
+                Ordering::Less => Self::FloatExtend,
+                Ordering::Greater => Self::FloatTrunc,
+                Ordering::Equal => Self::BitCast,
+            },
+            (Type::Float(..), Type::Integer(..)) if signed => Self::FloatToSignedInt,
+            (Type::Float(..), Type::Integer(..)) => Self::FloatToUnsignedInt,
+            (Type::Integer(..), Type::Float(..)) if signed => Self::SignedIntToFloat,
+            (Type::Integer(..), Type::Float(..)) => Self::UnsignedIntToFloat,
+            (Type::Pointer(..), Type::Integer(..)) => Self::PtrToInt,
+            (Type::Integer(..), Type::Pointer(..)) => Self::IntToPtr,
+            (Type::Pointer(from), Type::Pointer(to))
+                if from.address_space() != to.address_space() =>
+            {
+                Self::AddressSpaceCast
+            }
+            (Type::Pointer(..), Type::Pointer(..)) => Self::BitCast,
+            (from, to) => {
+                return Err(CastError::InvalidTypes { op: None, from: from.kind(), to: to.kind() })
+            }
+        };
+
+        Ok(op)
+    }
+
+    pub fn validate(&self, from: Type, to: Type) -> Result<(), CastError> {
+        let op = *self;
+
+        let check_width = |from: u32, to: u32, valid: bool| {
+            if valid {
+                Ok(())
+            } else {
+                Err(CastError::InvalidWidth { op, from, to })
+            }
+        };
+
+        match (op, from, to) {
+            (Self::Trunc, Type::Integer(from), Type::Integer(to)) => {
+                check_width(from.width(), to.width(), from.width() > to.width())
+            }
+            (Self::ZeroExtend | Self::SignExtend, Type::Integer(from), Type::Integer(to)) => {
+                check_width(from.width(), to.width(), from.width() < to.width())
+            }
+            (Self::FloatTrunc, Type::Float(from), Type::Float(to)) => {
+                check_width(from.size(), to.size(), from.size() > to.size())
+            }
+            (Self::FloatExtend, Type::Float(from), Type::Float(to)) => {
+                check_width(from.size(), to.size(), from.size() < to.size())
+            }
+            (
+                Self::FloatToUnsignedInt | Self::FloatToSignedInt,
+                Type::Float(..),
+                Type::Integer(..),
+            )
+            | (
+                Self::UnsignedIntToFloat | Self::SignedIntToFloat,
+                Type::Integer(..),
+                Type::Float(..),
+            )
+            | (Self::PtrToInt, Type::Pointer(..), Type::Integer(..))
+            | (Self::IntToPtr, Type::Integer(..), Type::Pointer(..)) => Ok(()),
+            (Self::BitCast, Type::Pointer(from), Type::Pointer(to))
+                if from.address_space() == to.address_space() =>
+            {
+                Ok(())
+            }
+            (
+                Self::BitCast,
+                Type::Integer(..) | Type::Float(..),
+                Type::Integer(..) | Type::Float(..),
+            ) => {
+                let (from, to) = (Self::scalar_width(from), Self::scalar_width(to));
+                check_width(from, to, from == to)
+            }
+            (Self::AddressSpaceCast | Self::BitCast, Type::Pointer(from), Type::Pointer(to)) => {
+                let (from, to) = (from.address_space(), to.address_space());
+
+                if op == Self::AddressSpaceCast && from != to {
+                    Ok(())
+                } else {
+                    Err(CastError::InvalidAddressSpace { op, from, to })
+                }
+            }
+            (op, from, to) => {
+                Err(CastError::InvalidTypes { op: Some(op), from: from.kind(), to: to.kind() })
+            }
+        }
+    }
+
+    fn scalar_width(ty: Type) -> u32 {
+        match ty {
+            Type::Integer(int) => int.width(),
+            Type::Float(float) => float.size(),
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl From<CastOp> for LLVMOpcode {
+    fn from(op: CastOp) -> Self {
+        match op {
+            CastOp::Trunc => LLVMOpcode::LLVMTrunc,
+            CastOp::ZeroExtend => LLVMOpcode::LLVMZExt,
+            CastOp::SignExtend => LLVMOpcode::LLVMSExt,
+            CastOp::FloatTrunc => LLVMOpcode::LLVMFPTrunc,
+            CastOp::FloatExtend => LLVMOpcode::LLVMFPExt,
+            CastOp::FloatToUnsignedInt => LLVMOpcode::LLVMFPToUI,
+            CastOp::FloatToSignedInt => LLVMOpcode::LLVMFPToSI,
+            CastOp::UnsignedIntToFloat => LLVMOpcode::LLVMUIToFP,
+            CastOp::SignedIntToFloat => LLVMOpcode::LLVMSIToFP,
+            CastOp::PtrToInt => LLVMOpcode::LLVMPtrToInt,
+            CastOp::IntToPtr => LLVMOpcode::LLVMIntToPtr,
+            CastOp::BitCast => LLVMOpcode::LLVMBitCast,
+            CastOp::AddressSpaceCast => LLVMOpcode::LLVMAddrSpaceCast,
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum IntPredicate {
     EQ,
@@ -172,200 +489,1310 @@ impl From<FloatPredicate> for LLVMRealPredicate {
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AtomicOrdering {
+    NotAtomic,
+    Unordered,
+    Monotonic,
+    Acquire,
+    Release,
+    AcquireRelease,
+    SequentiallyConsistent,
+}
+
+impl AtomicOrdering {
+    fn check(
+        self,
+        instruction: &'static str,
+        invalid: &[AtomicOrdering],
+    ) -> Result<AtomicOrdering, AtomicError> {
+        if self == AtomicOrdering::NotAtomic || invalid.contains(&self) {
+            return Err(AtomicError::InvalidOrdering { instruction, ordering: self });
+        }
+
+        Ok(self)
+    }
+}
+
+impl From<AtomicOrdering> for LLVMAtomicOrdering {
+    fn from(ordering: AtomicOrdering) -> Self {
+        match ordering {
+            AtomicOrdering::NotAtomic => LLVMAtomicOrdering::LLVMAtomicOrderingNotAtomic,
+            AtomicOrdering::Unordered => LLVMAtomicOrdering::LLVMAtomicOrderingUnordered,
+            AtomicOrdering::Monotonic => LLVMAtomicOrdering::LLVMAtomicOrderingMonotonic,
+            AtomicOrdering::Acquire => LLVMAtomicOrdering::LLVMAtomicOrderingAcquire,
+            AtomicOrdering::Release => LLVMAtomicOrdering::LLVMAtomicOrderingRelease,
+            AtomicOrdering::AcquireRelease => LLVMAtomicOrdering::LLVMAtomicOrderingAcquireRelease,
+            AtomicOrdering::SequentiallyConsistent => {
+                LLVMAtomicOrdering::LLVMAtomicOrderingSequentiallyConsistent
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SyncScope {
+    SingleThread,
+    System,
+}
+
+impl SyncScope {
+    fn is_single_thread(&self) -> i32 {
+        matches!(self, SyncScope::SingleThread) as i32
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AtomicRMWBinOp {
+    Xchg,
+    Add,
+    Sub,
+    And,
+    Nand,
+    Or,
+    Xor,
+    Max,
+    Min,
+    UMax,
+    UMin,
+    FAdd,
+    FSub,
+    FMax,
+    FMin,
+    UIncWrap,
+    UDecWrap,
+}
+
+impl From<AtomicRMWBinOp> for LLVMAtomicRMWBinOp {
+    fn from(op: AtomicRMWBinOp) -> Self {
+        match op {
+            AtomicRMWBinOp::Xchg => LLVMAtomicRMWBinOp::LLVMAtomicRMWBinOpXchg,
+            AtomicRMWBinOp::Add => LLVMAtomicRMWBinOp::LLVMAtomicRMWBinOpAdd,
+            AtomicRMWBinOp::Sub => LLVMAtomicRMWBinOp::LLVMAtomicRMWBinOpSub,
+            AtomicRMWBinOp::And => LLVMAtomicRMWBinOp::LLVMAtomicRMWBinOpAnd,
+            AtomicRMWBinOp::Nand => LLVMAtomicRMWBinOp::LLVMAtomicRMWBinOpNand,
+            AtomicRMWBinOp::Or => LLVMAtomicRMWBinOp::LLVMAtomicRMWBinOpOr,
+            AtomicRMWBinOp::Xor => LLVMAtomicRMWBinOp::LLVMAtomicRMWBinOpXor,
+            AtomicRMWBinOp::Max => LLVMAtomicRMWBinOp::LLVMAtomicRMWBinOpMax,
+            AtomicRMWBinOp::Min => LLVMAtomicRMWBinOp::LLVMAtomicRMWBinOpMin,
+            AtomicRMWBinOp::UMax => LLVMAtomicRMWBinOp::LLVMAtomicRMWBinOpUMax,
+            AtomicRMWBinOp::UMin => LLVMAtomicRMWBinOp::LLVMAtomicRMWBinOpUMin,
+            AtomicRMWBinOp::FAdd => LLVMAtomicRMWBinOp::LLVMAtomicRMWBinOpFAdd,
+            AtomicRMWBinOp::FSub => LLVMAtomicRMWBinOp::LLVMAtomicRMWBinOpFSub,
+            AtomicRMWBinOp::FMax => LLVMAtomicRMWBinOp::LLVMAtomicRMWBinOpFMax,
+            AtomicRMWBinOp::FMin => LLVMAtomicRMWBinOp::LLVMAtomicRMWBinOpFMin,
+            AtomicRMWBinOp::UIncWrap => LLVMAtomicRMWBinOp::LLVMAtomicRMWBinOpUIncWrap,
+            AtomicRMWBinOp::UDecWrap => LLVMAtomicRMWBinOp::LLVMAtomicRMWBinOpUDecWrap,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FastMathFlags(u32);
+
+impl FastMathFlags {
+    pub const NONE: Self = Self(0);
+    pub const ALLOW_REASSOC: Self = Self(1 << 0);
+    pub const NO_NANS: Self = Self(1 << 1);
+    pub const NO_INFS: Self = Self(1 << 2);
+    pub const NO_SIGNED_ZEROS: Self = Self(1 << 3);
+    pub const ALLOW_RECIPROCAL: Self = Self(1 << 4);
+    pub const ALLOW_CONTRACT: Self = Self(1 << 5);
+    pub const APPROX_FUNC: Self = Self(1 << 6);
+    pub const FAST: Self = Self((1 << 7) - 1);
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn contains(&self, other: FastMathFlags) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for FastMathFlags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for FastMathFlags {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
 #[derive(Debug)]
 pub struct Builder<'ctx> {
     context: &'ctx Context,
     inner: LLVMBuilderRef,
+    fast_math_flags: Cell<FastMathFlags>,
 }
 
 impl<'ctx> Builder<'ctx> {
     pub(crate) fn new(context: &'ctx Context, inner: LLVMBuilderRef) -> Self {
-        Self { context, inner }
+        Self { context, inner, fast_math_flags: Cell::new(FastMathFlags::NONE) }
     }
 
-    pub fn position_at_end(&self, basic_block: &BasicBlock<'ctx>) {
-        unsafe { LLVMPositionBuilderAtEnd(self.as_raw(), basic_block.as_raw()) }
+    pub fn fast_math_flags(&self) -> FastMathFlags {
+        self.fast_math_flags.get()
     }
 
-    int_op!(add(LLVMBuildAdd));
-    int_op!(sub(LLVMBuildSub));
-    int_op!(mul(LLVMBuildMul));
-    int_op!(signed_div(LLVMBuildSDiv));
-    int_op!(unsigned_div(LLVMBuildUDiv));
+    pub fn set_fast_math_flags(&self, flags: FastMathFlags) {
+        self.fast_math_flags.set(flags)
+    }
 
-    float_op!(float_add(LLVMBuildFAdd));
-    float_op!(float_sub(LLVMBuildFSub));
-    float_op!(float_mul(LLVMBuildFMul));
-    float_op!(float_div(LLVMBuildFDiv));
+    fn apply_fast_math_flags(&self, value_ref: LLVMValueRef) {
+        let fast_math_flags = self.fast_math_flags.get();
+        if !fast_math_flags.is_empty() {
+            unsafe { LLVMRustSetFastMathFlags(value_ref, fast_math_flags.0) }
+        }
+    }
 
-    int_cast!(trunc(LLVMBuildTrunc); assert(|value, ty| value.type_of().width() > ty.width()));
-    int_cast!(zero_extend(LLVMBuildZExt); assert(|value, ty| value.type_of().width() < ty.width()));
-    int_cast!(sign_extend(LLVMBuildZExt); assert(|value, ty| value.type_of().width() < ty.width()));
+    pub fn position_at_end(&self, basic_block: &BasicBlock<'ctx>) {
+        unsafe { LLVMPositionBuilderAtEnd(self.as_raw(), basic_block.as_raw()) }
+    }
 
-    float_cast!(float_trunc(LLVMBuildFPTrunc); assert(|value, ty| value.type_of().size() > ty.size()));
-    float_cast!(float_extend(LLVMBuildFPTrunc); assert(|value, ty| value.type_of().size() < ty.size()));
+    pub fn position_before(&self, instruction: &InstructionValue<'ctx>) {
+        unsafe { LLVMPositionBuilderBefore(self.as_raw(), instruction.as_raw()) }
+    }
 
-    pub fn float_to_signed_int<F>(
-        &self,
-        float: F,
-        ty: IntegerType<'ctx>,
-        name: &str,
-    ) -> IntegerValue
-    where
-        FloatValue<'ctx>: From<F>,
-    {
-        let float = FloatValue::from(float);
-        let name = to_c_str(name);
+    pub fn clear_insertion_position(&self) {
+        unsafe { LLVMClearInsertionPosition(self.as_raw()) }
+    }
 
-        let int_value_ref =
-            unsafe { LLVMBuildFPToSI(self.as_raw(), float.as_raw(), ty.as_raw(), name.as_ptr()) };
+    pub fn get_insert_block(&self) -> Option<BasicBlock<'ctx>> {
+        let basic_block_ref = unsafe { LLVMGetInsertBlock(self.as_raw()) };
 
-        IntegerValue(BaseValue::new(self.get_context(), int_value_ref))
+        BasicBlock::from_raw(self.context, basic_block_ref)
     }
 
-    pub fn float_to_unsigned_int<F>(
-        &self,
-        float: F,
-        ty: IntegerType<'ctx>,
-        name: &str,
-    ) -> IntegerValue
-    where
-        FloatValue<'ctx>: From<F>,
-    {
-        let float = FloatValue::from(float);
-        let name = to_c_str(name);
+    pub fn save_insert_point(&self) -> InsertPointGuard<'_, 'ctx> {
+        let debug_location = unsafe { LLVMGetCurrentDebugLocation2(self.as_raw()) };
+        let instruction_ref = unsafe { LLVMRustGetInsertPoint(self.as_raw()) };
 
-        let int_value_ref =
-            unsafe { LLVMBuildFPToUI(self.as_raw(), float.as_raw(), ty.as_raw(), name.as_ptr()) };
+        InsertPointGuard {
+            builder: self,
+            basic_block: self.get_insert_block(),
+            instruction: InstructionValue::from_raw(self.context, instruction_ref),
+            debug_location,
+        }
+    }
 
-        IntegerValue(BaseValue::new(self.get_context(), int_value_ref))
+    pub fn set_debug_location(&self, location: &DILocation<'ctx>) {
+        unsafe { LLVMSetCurrentDebugLocation2(self.as_raw(), location.as_raw()) }
     }
 
-    pub fn signed_int_to_float<I>(&self, integer: I, ty: FloatType<'ctx>, name: &str) -> FloatValue
+    pub fn clear_debug_location(&self) {
+        unsafe { LLVMSetCurrentDebugLocation2(self.as_raw(), ptr::null_mut()) }
+    }
+
+    pub fn alloca<T>(&self, ty: T, name: &str) -> PointerValue<'ctx>
     where
-        IntegerValue<'ctx>: From<I>,
+        Type<'ctx>: From<T>,
     {
-        let float: IntegerValue<'ctx> = integer.into();
+        let ty = Type::from(ty);
         let name = to_c_str(name);
 
-        let float_value_ref =
-            unsafe { LLVMBuildSIToFP(self.as_raw(), float.as_raw(), ty.as_raw(), name.as_ptr()) };
+        let pointer_ref = unsafe { LLVMBuildAlloca(self.as_raw(), ty.as_raw(), name.as_ptr()) };
 
-        FloatValue::new(BaseValue::new(self.get_context(), float_value_ref), ty.kind)
+        PointerValue(BaseValue::new(self.context, pointer_ref))
     }
 
-    pub fn unsigned_int_to_float<I>(
-        &self,
-        integer: I,
-        ty: FloatType<'ctx>,
-        name: &str,
-    ) -> FloatValue
+    pub fn entry_alloca<T>(&self, ty: T, name: &str) -> PointerValue<'ctx>
     where
-        IntegerValue<'ctx>: From<I>,
+        Type<'ctx>: From<T>,
     {
-        let integer = IntegerValue::from(integer);
-        let name = to_c_str(name);
+        let entry = self
+            .get_insert_block()
+            .and_then(|basic_block| basic_block.parent().entry_basic_block())
+            .expect("builder is not positioned in a function");
 
-        let float_value_ref =
-            unsafe { LLVMBuildUIToFP(self.as_raw(), integer.as_raw(), ty.as_raw(), name.as_ptr()) };
+        let _guard = self.save_insert_point();
+        match entry.first_instruction() {
+            Some(instruction) => self.position_before(&instruction),
+            None => self.position_at_end(&entry),
+        }
 
-        FloatValue::new(BaseValue::new(self.get_context(), float_value_ref), ty.kind)
+        self.alloca(ty, name)
     }
 
-    pub fn phi<T>(&self, ty: T, name: &str) -> PhiNodeValue
+    pub fn load<T, P>(&self, ty: T, pointer: P, name: &str) -> Value<'ctx>
     where
         Type<'ctx>: From<T>,
+        PointerValue<'ctx>: From<P>,
     {
         let ty = Type::from(ty);
-        let str = to_c_str(name);
+        let pointer = PointerValue::from(pointer);
+        let name = to_c_str(name);
 
-        let phi_node_ref = unsafe { LLVMBuildPhi(self.as_raw(), ty.as_raw(), str.as_ptr()) };
+        let value_ref =
+            unsafe { LLVMBuildLoad2(self.as_raw(), ty.as_raw(), pointer.as_raw(), name.as_ptr()) };
 
-        PhiNodeValue(BaseValue::new(self.get_context(), phi_node_ref))
+        Value::from_unknown(BaseValue::new(self.context, value_ref))
     }
 
-    pub fn icmp<L, R>(&self, op: IntPredicate, lhs: L, rhs: R, name: &str) -> IntegerValue
+    pub fn store<V, P>(&self, value: V, pointer: P) -> InstructionValue<'ctx>
     where
-        IntegerValue<'ctx>: From<L>,
-        IntegerValue<'ctx>: From<R>,
+        Value<'ctx>: From<V>,
+        PointerValue<'ctx>: From<P>,
     {
-        let lhs = IntegerValue::from(lhs);
-        let rhs = IntegerValue::from(rhs);
-
-        let name = to_c_str(name);
+        let value = Value::from(value);
+        let pointer = PointerValue::from(pointer);
 
-        let bool_ref = unsafe {
-            LLVMBuildICmp(self.as_raw(), op.into(), lhs.as_raw(), rhs.as_raw(), name.as_ptr())
-        };
+        let store_ref = unsafe { LLVMBuildStore(self.as_raw(), value.as_raw(), pointer.as_raw()) };
 
-        IntegerValue(BaseValue::new(self.get_context(), bool_ref))
+        InstructionValue(BaseValue::new(self.context, store_ref))
     }
 
-    pub fn fcmp<L, R>(&self, op: FloatPredicate, lhs: L, rhs: R, name: &str) -> IntegerValue
+    pub fn atomic_load<T, P>(
+        &self,
+        ty: T,
+        pointer: P,
+        ordering: AtomicOrdering,
+        scope: SyncScope,
+        name: &str,
+    ) -> Result<Value<'ctx>, AtomicError>
     where
-        FloatValue<'ctx>: From<L>,
-        FloatValue<'ctx>: From<R>,
+        Type<'ctx>: From<T>,
+        PointerValue<'ctx>: From<P>,
     {
-        let lhs = FloatValue::from(lhs);
-        let rhs = FloatValue::from(rhs);
-
-        let name = to_c_str(name);
-
-        let bool_ref = unsafe {
-            LLVMBuildFCmp(self.as_raw(), op.into(), lhs.as_raw(), rhs.as_raw(), name.as_ptr())
-        };
-
-        IntegerValue(BaseValue::new(self.get_context(), bool_ref))
-    }
+        let ordering =
+            ordering.check("load", &[AtomicOrdering::Release, AtomicOrdering::AcquireRelease])?;
+        let value = self.load(ty, pointer, name);
 
-    pub fn ret<V: Into<Value<'ctx>>>(&self, value: V) {
-        let value: Value<'ctx> = value.into();
         unsafe {
-            LLVMBuildRet(self.as_raw(), value.as_raw());
+            LLVMSetOrdering(value.as_raw(), ordering.into());
+            LLVMSetAtomicSingleThread(value.as_raw(), scope.is_single_thread());
         }
+
+        Ok(value)
     }
 
-    pub fn cond_br<V>(&self, value: V, then_br: &BasicBlock<'ctx>, else_br: &BasicBlock<'ctx>)
+    pub fn atomic_store<V, P>(
+        &self,
+        value: V,
+        pointer: P,
+        ordering: AtomicOrdering,
+        scope: SyncScope,
+    ) -> Result<InstructionValue<'ctx>, AtomicError>
     where
         Value<'ctx>: From<V>,
+        PointerValue<'ctx>: From<P>,
     {
-        let value = Value::from(value);
+        let ordering =
+            ordering.check("store", &[AtomicOrdering::Acquire, AtomicOrdering::AcquireRelease])?;
+        let store = self.store(value, pointer);
 
         unsafe {
-            LLVMBuildCondBr(self.as_raw(), value.as_raw(), then_br.as_raw(), else_br.as_raw());
+            LLVMSetOrdering(store.as_raw(), ordering.into());
+            LLVMSetAtomicSingleThread(store.as_raw(), scope.is_single_thread());
         }
-    }
 
-    pub fn br(&self, to: &BasicBlock) {
-        unsafe {
-            LLVMBuildBr(self.as_raw(), to.as_raw());
-        }
+        Ok(store)
     }
 
-    pub fn call(
+    pub fn atomic_rmw<P, V>(
         &self,
-        func_ty: FunctionType<'ctx>,
-        func: &FunctionValue<'ctx>,
-        args: &[Value<'ctx>],
-        name: &str,
-    ) -> Value {
-        let name = to_c_str(name);
-        let mut args = args.iter().map(|arg| arg.as_raw()).collect::<Vec<_>>();
+        op: AtomicRMWBinOp,
+        pointer: P,
+        value: V,
+        ordering: AtomicOrdering,
+        scope: SyncScope,
+    ) -> Result<Value<'ctx>, AtomicError>
+    where
+        PointerValue<'ctx>: From<P>,
+        Value<'ctx>: From<V>,
+    {
+        let ordering = ordering.check("atomicrmw", &[AtomicOrdering::Unordered])?;
+        let pointer = PointerValue::from(pointer);
+        let value = Value::from(value);
 
         let value_ref = unsafe {
-            LLVMBuildCall2(
+            LLVMBuildAtomicRMW(
                 self.as_raw(),
-                func_ty.as_raw(),
-                func.as_raw(),
+                op.into(),
+                pointer.as_raw(),
+                value.as_raw(),
+                ordering.into(),
+                scope.is_single_thread(),
+            )
+        };
+
+        Ok(Value::from_unknown(BaseValue::new(self.context, value_ref)))
+    }
+
+    pub fn cmpxchg<P, C, N>(
+        &self,
+        pointer: P,
+        compare: C,
+        new: N,
+        success: AtomicOrdering,
+        failure: AtomicOrdering,
+        scope: SyncScope,
+    ) -> Result<StructValue<'ctx>, AtomicError>
+    where
+        PointerValue<'ctx>: From<P>,
+        Value<'ctx>: From<C>,
+        Value<'ctx>: From<N>,
+    {
+        let success = success.check("cmpxchg", &[AtomicOrdering::Unordered])?;
+        let failure = failure.check(
+            "cmpxchg failure",
+            &[AtomicOrdering::Unordered, AtomicOrdering::Release, AtomicOrdering::AcquireRelease],
+        )?;
+        let pointer = PointerValue::from(pointer);
+        let compare = Value::from(compare);
+        let new = Value::from(new);
+
+        let struct_ref = unsafe {
+            LLVMBuildAtomicCmpXchg(
+                self.as_raw(),
+                pointer.as_raw(),
+                compare.as_raw(),
+                new.as_raw(),
+                success.into(),
+                failure.into(),
+                scope.is_single_thread(),
+            )
+        };
+
+        Ok(StructValue(BaseValue::new(self.context, struct_ref)))
+    }
+
+    pub fn fence(&self, ordering: AtomicOrdering, scope: SyncScope) -> Result<(), AtomicError> {
+        let ordering =
+            ordering.check("fence", &[AtomicOrdering::Unordered, AtomicOrdering::Monotonic])?;
+        let name = to_c_str("");
+
+        unsafe {
+            LLVMBuildFence(self.as_raw(), ordering.into(), scope.is_single_thread(), name.as_ptr());
+        }
+
+        Ok(())
+    }
+
+    int_op!(add(LLVMBuildAdd));
+    int_op!(sub(LLVMBuildSub));
+    int_op!(mul(LLVMBuildMul));
+    int_op!(signed_div(LLVMBuildSDiv));
+    int_op!(unsigned_div(LLVMBuildUDiv));
+    int_op!(signed_rem(LLVMBuildSRem));
+    int_op!(unsigned_rem(LLVMBuildURem));
+
+    int_op!(nsw_add(LLVMBuildNSWAdd));
+    int_op!(nsw_sub(LLVMBuildNSWSub));
+    int_op!(nsw_mul(LLVMBuildNSWMul));
+    int_op!(nuw_add(LLVMBuildNUWAdd));
+    int_op!(nuw_sub(LLVMBuildNUWSub));
+    int_op!(nuw_mul(LLVMBuildNUWMul));
+
+    float_op!(float_add(LLVMBuildFAdd));
+    float_op!(float_sub(LLVMBuildFSub));
+    float_op!(float_mul(LLVMBuildFMul));
+    float_op!(float_div(LLVMBuildFDiv));
+
+    vector_op!(vector_add(LLVMBuildAdd));
+    vector_op!(vector_sub(LLVMBuildSub));
+    vector_op!(vector_mul(LLVMBuildMul));
+    vector_op!(vector_signed_div(LLVMBuildSDiv));
+    vector_op!(vector_unsigned_div(LLVMBuildUDiv));
+    vector_op!(vector_float_add(LLVMBuildFAdd));
+    vector_op!(vector_float_sub(LLVMBuildFSub));
+    vector_op!(vector_float_mul(LLVMBuildFMul));
+    vector_op!(vector_float_div(LLVMBuildFDiv));
+
+    int_cast!(trunc(Trunc));
+    int_cast!(zero_extend(ZeroExtend));
+    int_cast!(sign_extend(SignExtend));
+
+    float_cast!(float_trunc(FloatTrunc));
+    float_cast!(float_extend(FloatExtend));
+
+    pub fn build_cast<V, T>(
+        &self,
+        op: CastOp,
+        value: V,
+        ty: T,
+        name: &str,
+    ) -> Result<Value<'ctx>, CastError>
+    where
+        Value<'ctx>: From<V>,
+        Type<'ctx>: From<T>,
+    {
+        let value = Value::from(value);
+        let ty = Type::from(ty);
+
+        op.validate(value.type_of(), ty)?;
+
+        let name = to_c_str(name);
+
+        let value_ref = unsafe {
+            LLVMBuildCast(self.as_raw(), op.into(), value.as_raw(), ty.as_raw(), name.as_ptr())
+        };
+
+        Ok(Value::from_unknown(BaseValue::new(self.context, value_ref)))
+    }
+
+    pub fn cast<V, T>(
+        &self,
+        value: V,
+        ty: T,
+        signed: bool,
+        name: &str,
+    ) -> Result<Value<'ctx>, CastError>
+    where
+        Value<'ctx>: From<V>,
+        Type<'ctx>: From<T>,
+    {
+        let value = Value::from(value);
+        let ty = Type::from(ty);
+
+        let op = CastOp::infer(value.type_of(), ty, signed)?;
+
+        self.build_cast::<Value, Type>(op, value, ty, name)
+    }
+
+    pub fn bit_cast<V, T>(&self, value: V, ty: T, name: &str) -> Result<Value<'ctx>, CastError>
+    where
+        Value<'ctx>: From<V>,
+        Type<'ctx>: From<T>,
+    {
+        self.build_cast(CastOp::BitCast, value, ty, name)
+    }
+
+    pub fn ptr_to_int<P>(
+        &self,
+        pointer: P,
+        ty: IntegerType<'ctx>,
+        name: &str,
+    ) -> Result<IntegerValue<'ctx>, CastError>
+    where
+        PointerValue<'ctx>: From<P>,
+    {
+        let pointer = PointerValue::from(pointer);
+
+        self.build_cast(CastOp::PtrToInt, pointer, ty, name).map(Into::into)
+    }
+
+    pub fn int_to_ptr<I>(
+        &self,
+        integer: I,
+        ty: PointerType<'ctx>,
+        name: &str,
+    ) -> Result<PointerValue<'ctx>, CastError>
+    where
+        IntegerValue<'ctx>: From<I>,
+    {
+        let integer = IntegerValue::from(integer);
+
+        self.build_cast(CastOp::IntToPtr, integer, ty, name).map(Into::into)
+    }
+
+    pub fn address_space_cast<P>(
+        &self,
+        pointer: P,
+        ty: PointerType<'ctx>,
+        name: &str,
+    ) -> Result<PointerValue<'ctx>, CastError>
+    where
+        PointerValue<'ctx>: From<P>,
+    {
+        let pointer = PointerValue::from(pointer);
+
+        self.build_cast(CastOp::AddressSpaceCast, pointer, ty, name).map(Into::into)
+    }
+
+    pub fn float_to_signed_int<F>(
+        &self,
+        float: F,
+        ty: IntegerType<'ctx>,
+        name: &str,
+    ) -> IntegerValue<'ctx>
+    where
+        FloatValue<'ctx>: From<F>,
+    {
+        let float = FloatValue::from(float);
+        let name = to_c_str(name);
+
+        let int_value_ref =
+            unsafe { LLVMBuildFPToSI(self.as_raw(), float.as_raw(), ty.as_raw(), name.as_ptr()) };
+
+        IntegerValue(BaseValue::new(self.context, int_value_ref))
+    }
+
+    pub fn float_to_unsigned_int<F>(
+        &self,
+        float: F,
+        ty: IntegerType<'ctx>,
+        name: &str,
+    ) -> IntegerValue<'ctx>
+    where
+        FloatValue<'ctx>: From<F>,
+    {
+        let float = FloatValue::from(float);
+        let name = to_c_str(name);
+
+        let int_value_ref =
+            unsafe { LLVMBuildFPToUI(self.as_raw(), float.as_raw(), ty.as_raw(), name.as_ptr()) };
+
+        IntegerValue(BaseValue::new(self.context, int_value_ref))
+    }
+
+    pub fn signed_int_to_float<I>(&self, integer: I, ty: FloatType<'ctx>, name: &str) -> FloatValue
+    where
+        IntegerValue<'ctx>: From<I>,
+    {
+        let float: IntegerValue<'ctx> = integer.into();
+        let name = to_c_str(name);
+
+        let float_value_ref =
+            unsafe { LLVMBuildSIToFP(self.as_raw(), float.as_raw(), ty.as_raw(), name.as_ptr()) };
+
+        FloatValue::new(BaseValue::new(self.context, float_value_ref), ty.kind)
+    }
+
+    pub fn unsigned_int_to_float<I>(
+        &self,
+        integer: I,
+        ty: FloatType<'ctx>,
+        name: &str,
+    ) -> FloatValue
+    where
+        IntegerValue<'ctx>: From<I>,
+    {
+        let integer = IntegerValue::from(integer);
+        let name = to_c_str(name);
+
+        let float_value_ref =
+            unsafe { LLVMBuildUIToFP(self.as_raw(), integer.as_raw(), ty.as_raw(), name.as_ptr()) };
+
+        FloatValue::new(BaseValue::new(self.context, float_value_ref), ty.kind)
+    }
+
+    pub fn phi<T>(&self, ty: T, name: &str) -> PhiNodeValue<'ctx>
+    where
+        Type<'ctx>: From<T>,
+    {
+        let ty = Type::from(ty);
+        let str = to_c_str(name);
+
+        let phi_node_ref = unsafe { LLVMBuildPhi(self.as_raw(), ty.as_raw(), str.as_ptr()) };
+
+        PhiNodeValue(BaseValue::new(self.context, phi_node_ref))
+    }
+
+    pub fn icmp<L, R>(&self, op: IntPredicate, lhs: L, rhs: R, name: &str) -> IntegerValue<'ctx>
+    where
+        IntegerValue<'ctx>: From<L>,
+        IntegerValue<'ctx>: From<R>,
+    {
+        let lhs = IntegerValue::from(lhs);
+        let rhs = IntegerValue::from(rhs);
+
+        let name = to_c_str(name);
+
+        let bool_ref = unsafe {
+            LLVMBuildICmp(self.as_raw(), op.into(), lhs.as_raw(), rhs.as_raw(), name.as_ptr())
+        };
+
+        IntegerValue(BaseValue::new(self.context, bool_ref))
+    }
+
+    pub fn fcmp<L, R>(&self, op: FloatPredicate, lhs: L, rhs: R, name: &str) -> IntegerValue<'ctx>
+    where
+        FloatValue<'ctx>: From<L>,
+        FloatValue<'ctx>: From<R>,
+    {
+        let lhs = FloatValue::from(lhs);
+        let rhs = FloatValue::from(rhs);
+
+        let name = to_c_str(name);
+
+        let bool_ref = unsafe {
+            LLVMBuildFCmp(self.as_raw(), op.into(), lhs.as_raw(), rhs.as_raw(), name.as_ptr())
+        };
+
+        IntegerValue(BaseValue::new(self.context, bool_ref))
+    }
+
+    pub fn ret<V: Into<Value<'ctx>>>(&self, value: V) {
+        let value: Value<'ctx> = value.into();
+        unsafe {
+            LLVMBuildRet(self.as_raw(), value.as_raw());
+        }
+    }
+
+    pub fn ret_void(&self) {
+        unsafe {
+            LLVMBuildRetVoid(self.as_raw());
+        }
+    }
+
+    pub fn unreachable(&self) {
+        unsafe {
+            LLVMBuildUnreachable(self.as_raw());
+        }
+    }
+
+    pub fn cond_br<V>(&self, value: V, then_br: &BasicBlock<'ctx>, else_br: &BasicBlock<'ctx>)
+    where
+        Value<'ctx>: From<V>,
+    {
+        let value = Value::from(value);
+
+        unsafe {
+            LLVMBuildCondBr(self.as_raw(), value.as_raw(), then_br.as_raw(), else_br.as_raw());
+        }
+    }
+
+    pub fn br(&self, to: &BasicBlock) {
+        unsafe {
+            LLVMBuildBr(self.as_raw(), to.as_raw());
+        }
+    }
+
+    pub fn switch<V>(&self, value: V, default: &BasicBlock<'ctx>, cases: &[SwitchCase<'ctx>])
+    where
+        IntegerValue<'ctx>: From<V>,
+    {
+        let value = IntegerValue::from(value);
+
+        unsafe {
+            let switch_ref = LLVMBuildSwitch(
+                self.as_raw(),
+                value.as_raw(),
+                default.as_raw(),
+                cases.len() as u32,
+            );
+
+            for (on, dest) in cases {
+                LLVMAddCase(switch_ref, on.as_raw(), dest.as_raw());
+            }
+        }
+    }
+
+    pub fn indirect_br<A>(&self, address: A, destinations: &[BasicBlock<'ctx>])
+    where
+        PointerValue<'ctx>: From<A>,
+    {
+        let address = PointerValue::from(address);
+
+        unsafe {
+            let indirect_br_ref =
+                LLVMBuildIndirectBr(self.as_raw(), address.as_raw(), destinations.len() as u32);
+
+            for dest in destinations {
+                LLVMAddDestination(indirect_br_ref, dest.as_raw());
+            }
+        }
+    }
+
+    pub fn select<C, T, E>(
+        &self,
+        condition: C,
+        then_value: T,
+        else_value: E,
+        name: &str,
+    ) -> Value<'ctx>
+    where
+        IntegerValue<'ctx>: From<C>,
+        Value<'ctx>: From<T>,
+        Value<'ctx>: From<E>,
+    {
+        let condition = IntegerValue::from(condition);
+        let then_value = Value::from(then_value);
+        let else_value = Value::from(else_value);
+
+        let name = to_c_str(name);
+
+        let value_ref = unsafe {
+            LLVMBuildSelect(
+                self.as_raw(),
+                condition.as_raw(),
+                then_value.as_raw(),
+                else_value.as_raw(),
+                name.as_ptr(),
+            )
+        };
+
+        Value::from_unknown(BaseValue::new(self.context, value_ref))
+    }
+
+    pub fn call(
+        &self,
+        func_ty: FunctionType<'ctx>,
+        func: &FunctionValue<'ctx>,
+        args: &[Value<'ctx>],
+        name: &str,
+    ) -> CallSiteValue<'ctx> {
+        let name = to_c_str(name);
+        let mut args = args.iter().map(|arg| arg.as_raw()).collect::<Vec<_>>();
+
+        let value_ref = unsafe {
+            LLVMBuildCall2(
+                self.as_raw(),
+                func_ty.as_raw(),
+                func.as_raw(),
+                args.as_mut_ptr(),
+                args.len() as u32,
+                name.as_ptr(),
+            )
+        };
+
+        CallSiteValue(BaseValue::new(self.context, value_ref))
+    }
+
+    pub fn inline_asm_call(
+        &self,
+        asm: &InlineAsm<'ctx>,
+        args: &[Value<'ctx>],
+        name: &str,
+    ) -> CallSiteValue<'ctx> {
+        let func_ty = asm.function_type();
+        let name = to_c_str(if let Type::Void(_) = func_ty.return_ty() { "" } else { name });
+        let mut args = args.iter().map(|arg| arg.as_raw()).collect::<Vec<_>>();
+
+        let value_ref = unsafe {
+            LLVMBuildCall2(
+                self.as_raw(),
+                func_ty.as_raw(),
+                asm.as_raw(),
                 args.as_mut_ptr(),
                 args.len() as u32,
                 name.as_ptr(),
             )
         };
 
-        Value::from_unknown(BaseValue::new(self.get_context(), value_ref))
+        CallSiteValue(BaseValue::new(self.context, value_ref))
+    }
+
+    pub fn typed_call<P: Params, R: StaticType>(
+        &self,
+        func: &TypedFunctionValue<'ctx, P, R>,
+        args: P::Values<'ctx>,
+        name: &str,
+    ) -> R::Value<'ctx> {
+        let func_ty = func.function_type().as_function_type();
+        let name = to_c_str(if let Type::Void(_) = func_ty.return_ty() { "" } else { name });
+        let mut args = P::into_values(args).iter().map(|arg| arg.as_raw()).collect::<Vec<_>>();
+
+        let value_ref = unsafe {
+            LLVMBuildCall2(
+                self.as_raw(),
+                func_ty.as_raw(),
+                func.as_function().as_raw(),
+                args.as_mut_ptr(),
+                args.len() as u32,
+                name.as_ptr(),
+            )
+        };
+
+        R::from_value(Value::from_unknown(BaseValue::new(self.context, value_ref)))
+    }
+
+    pub fn invoke(
+        &self,
+        func_ty: FunctionType<'ctx>,
+        func: &FunctionValue<'ctx>,
+        args: &[Value<'ctx>],
+        then_br: &BasicBlock<'ctx>,
+        catch_br: &BasicBlock<'ctx>,
+        name: &str,
+    ) -> CallSiteValue<'ctx> {
+        let name = to_c_str(name);
+        let mut args = args.iter().map(|arg| arg.as_raw()).collect::<Vec<_>>();
+
+        let value_ref = unsafe {
+            LLVMBuildInvoke2(
+                self.as_raw(),
+                func_ty.as_raw(),
+                func.as_raw(),
+                args.as_mut_ptr(),
+                args.len() as u32,
+                then_br.as_raw(),
+                catch_br.as_raw(),
+                name.as_ptr(),
+            )
+        };
+
+        CallSiteValue(BaseValue::new(self.context, value_ref))
+    }
+
+    pub fn landing_pad<T>(
+        &self,
+        ty: T,
+        personality: &FunctionValue<'ctx>,
+        name: &str,
+    ) -> LandingPadValue<'ctx>
+    where
+        Type<'ctx>: From<T>,
+    {
+        let ty = Type::from(ty);
+        let name = to_c_str(name);
+
+        let landing_pad_ref = unsafe {
+            LLVMBuildLandingPad(self.as_raw(), ty.as_raw(), personality.as_raw(), 0, name.as_ptr())
+        };
+
+        LandingPadValue(BaseValue::new(self.context, landing_pad_ref))
+    }
+
+    pub fn resume<V>(&self, exception: V)
+    where
+        Value<'ctx>: From<V>,
+    {
+        let exception = Value::from(exception);
+
+        unsafe {
+            LLVMBuildResume(self.as_raw(), exception.as_raw());
+        }
+    }
+
+    pub fn extract_value<V>(&self, aggregate: V, idx: u32, name: &str) -> Value<'ctx>
+    where
+        Value<'ctx>: From<V>,
+    {
+        let aggregate = Value::from(aggregate);
+        let name = to_c_str(name);
+
+        let value_ref =
+            unsafe { LLVMBuildExtractValue(self.as_raw(), aggregate.as_raw(), idx, name.as_ptr()) };
+
+        Value::from_unknown(BaseValue::new(self.context, value_ref))
+    }
+
+    pub fn insert_value<A, V>(&self, aggregate: A, value: V, idx: u32, name: &str) -> Value<'ctx>
+    where
+        Value<'ctx>: From<A>,
+        Value<'ctx>: From<V>,
+    {
+        let aggregate = Value::from(aggregate);
+        let value = Value::from(value);
+        let name = to_c_str(name);
+
+        let value_ref = unsafe {
+            LLVMBuildInsertValue(
+                self.as_raw(),
+                aggregate.as_raw(),
+                value.as_raw(),
+                idx,
+                name.as_ptr(),
+            )
+        };
+
+        Value::from_unknown(BaseValue::new(self.context, value_ref))
+    }
+
+    pub fn extract_element<V, I>(&self, vector: V, idx: I, name: &str) -> Value<'ctx>
+    where
+        VectorValue<'ctx>: From<V>,
+        IntegerValue<'ctx>: From<I>,
+    {
+        let vector = VectorValue::from(vector);
+        let idx = IntegerValue::from(idx);
+        let name = to_c_str(name);
+
+        let value_ref = unsafe {
+            LLVMBuildExtractElement(self.as_raw(), vector.as_raw(), idx.as_raw(), name.as_ptr())
+        };
+
+        Value::from_unknown(BaseValue::new(self.context, value_ref))
+    }
+
+    pub fn insert_element<V, E, I>(
+        &self,
+        vector: V,
+        element: E,
+        idx: I,
+        name: &str,
+    ) -> VectorValue<'ctx>
+    where
+        VectorValue<'ctx>: From<V>,
+        Value<'ctx>: From<E>,
+        IntegerValue<'ctx>: From<I>,
+    {
+        let vector = VectorValue::from(vector);
+        let element = Value::from(element);
+        let idx = IntegerValue::from(idx);
+        let name = to_c_str(name);
+
+        let vector_ref = unsafe {
+            LLVMBuildInsertElement(
+                self.as_raw(),
+                vector.as_raw(),
+                element.as_raw(),
+                idx.as_raw(),
+                name.as_ptr(),
+            )
+        };
+
+        VectorValue(BaseValue::new(self.context, vector_ref))
+    }
+
+    pub fn shuffle_vector<L, R>(
+        &self,
+        lhs: L,
+        rhs: R,
+        mask: &VectorValue<'ctx>,
+        name: &str,
+    ) -> VectorValue<'ctx>
+    where
+        VectorValue<'ctx>: From<L>,
+        VectorValue<'ctx>: From<R>,
+    {
+        let lhs = VectorValue::from(lhs);
+        let rhs = VectorValue::from(rhs);
+        let name = to_c_str(name);
+
+        let vector_ref = unsafe {
+            LLVMBuildShuffleVector(
+                self.as_raw(),
+                lhs.as_raw(),
+                rhs.as_raw(),
+                mask.as_raw(),
+                name.as_ptr(),
+            )
+        };
+
+        VectorValue(BaseValue::new(self.context, vector_ref))
+    }
+
+    fn build_intrinsic_call(
+        &self,
+        intrinsic: &str,
+        overloads: &[Type],
+        args: &[LLVMValueRef],
+        name: &str,
+    ) -> Result<LLVMValueRef, IntrinsicError> {
+        let module = unsafe {
+            let basic_block_ref = LLVMGetInsertBlock(self.as_raw());
+            if basic_block_ref.is_null() {
+                return Err(IntrinsicError::Unpositioned);
+            }
+
+            let module_ref = LLVMGetGlobalParent(LLVMGetBasicBlockParent(basic_block_ref));
+
+            Module::new(self.context, module_ref)
+        };
+
+        let function = module.intrinsic(intrinsic, overloads)?;
+        let name = to_c_str(name);
+        let mut args = args.to_vec();
+
+        Ok(unsafe {
+            LLVMBuildCall2(
+                self.as_raw(),
+                function.function_type().as_raw(),
+                function.as_raw(),
+                args.as_mut_ptr(),
+                args.len() as u32,
+                name.as_ptr(),
+            )
+        })
+    }
+
+    float_intrinsic!(sqrt("llvm.sqrt"));
+    float_intrinsic!(fabs("llvm.fabs"));
+    float_intrinsic!(floor("llvm.floor"));
+    float_intrinsic!(ceil("llvm.ceil"));
+    float_intrinsic!(round("llvm.round"));
+    float_intrinsic!(sin("llvm.sin"));
+    float_intrinsic!(cos("llvm.cos"));
+    float_intrinsic!(exp("llvm.exp"));
+    float_intrinsic!(log("llvm.log"));
+
+    float_binary_intrinsic!(pow("llvm.pow"));
+    float_binary_intrinsic!(min_num("llvm.minnum"));
+    float_binary_intrinsic!(max_num("llvm.maxnum"));
+    float_binary_intrinsic!(copy_sign("llvm.copysign"));
+
+    int_intrinsic!(ctpop("llvm.ctpop"));
+    int_intrinsic!(bit_reverse("llvm.bitreverse"));
+    int_intrinsic!(byte_swap("llvm.bswap"));
+    int_intrinsic!(ctlz("llvm.ctlz", zero_is_poison));
+    int_intrinsic!(cttz("llvm.cttz", zero_is_poison));
+
+    overflow_intrinsic!(signed_add_with_overflow("llvm.sadd.with.overflow"));
+    overflow_intrinsic!(unsigned_add_with_overflow("llvm.uadd.with.overflow"));
+    overflow_intrinsic!(signed_sub_with_overflow("llvm.ssub.with.overflow"));
+    overflow_intrinsic!(unsigned_sub_with_overflow("llvm.usub.with.overflow"));
+    overflow_intrinsic!(signed_mul_with_overflow("llvm.smul.with.overflow"));
+    overflow_intrinsic!(unsigned_mul_with_overflow("llvm.umul.with.overflow"));
+
+    pub fn memcpy<D, S, L>(
+        &self,
+        dest: D,
+        src: S,
+        len: L,
+        volatile: bool,
+    ) -> Result<(), IntrinsicError>
+    where
+        PointerValue<'ctx>: From<D>,
+        PointerValue<'ctx>: From<S>,
+        IntegerValue<'ctx>: From<L>,
+    {
+        let dest = PointerValue::from(dest);
+        let src = PointerValue::from(src);
+        let len = IntegerValue::from(len);
+        let volatile = self.context.int::<1>().constant(volatile as u64).as_raw();
+
+        self.build_intrinsic_call(
+            "llvm.memcpy",
+            &[dest.type_of().into(), src.type_of().into(), len.type_of().into()],
+            &[dest.as_raw(), src.as_raw(), len.as_raw(), volatile],
+            "",
+        )?;
+
+        Ok(())
+    }
+
+    pub fn memset<D, V, L>(
+        &self,
+        dest: D,
+        value: V,
+        len: L,
+        volatile: bool,
+    ) -> Result<(), IntrinsicError>
+    where
+        PointerValue<'ctx>: From<D>,
+        IntegerValue<'ctx>: From<V>,
+        IntegerValue<'ctx>: From<L>,
+    {
+        let dest = PointerValue::from(dest);
+        let value = IntegerValue::from(value);
+        let len = IntegerValue::from(len);
+        let volatile = self.context.int::<1>().constant(volatile as u64).as_raw();
+
+        self.build_intrinsic_call(
+            "llvm.memset",
+            &[dest.type_of().into(), len.type_of().into()],
+            &[dest.as_raw(), value.as_raw(), len.as_raw(), volatile],
+            "",
+        )?;
+
+        Ok(())
+    }
+
+    pub fn trap(&self) -> Result<(), IntrinsicError> {
+        self.build_intrinsic_call("llvm.trap", &[], &[], "")?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct InsertPointGuard<'b, 'ctx> {
+    builder: &'b Builder<'ctx>,
+    basic_block: Option<BasicBlock<'ctx>>,
+    instruction: Option<InstructionValue<'ctx>>,
+    debug_location: LLVMMetadataRef,
+}
+
+impl<'b, 'ctx> Drop for InsertPointGuard<'b, 'ctx> {
+    fn drop(&mut self) {
+        match (&self.instruction, &self.basic_block) {
+            (Some(instruction), _) => self.builder.position_before(instruction),
+            (None, Some(basic_block)) => self.builder.position_at_end(basic_block),
+            (None, None) => self.builder.clear_insertion_position(),
+        }
+
+        unsafe { LLVMSetCurrentDebugLocation2(self.builder.as_raw(), self.debug_location) }
     }
 }
 
 impl_as_raw!(Builder<'ctx>.inner -> LLVMBuilderRef);
 impl_get_context!(Builder<'ctx>.context);
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        context::Context,
+        error::{AtomicError, CastError, IntrinsicError},
+        types::TypeKind,
+        values::{integer::IntegerValue, pointer::PointerValue, vector::VectorValue},
+    };
+
+    use super::{AtomicOrdering, AtomicRMWBinOp, CastOp, FastMathFlags, SyncScope};
+
+    #[test]
+    fn test_entry_alloca_restores_insert_point() {
+        let context = Context::create();
+        let module = context.module("test");
+        let builder = context.builder();
+
+        let int_32 = context.int::<32>();
+        let function =
+            module.add_function("test", context.function(&[int_32.into()], int_32.into()));
+        let entry = function.append_basic_block("entry");
+        builder.position_at_end(&entry);
+
+        let param = IntegerValue::from(function.param(0));
+        let first = builder.add(param.clone(), param.clone(), "first");
+        builder.ret(first.clone());
+
+        builder.position_before(&entry.last_instruction().unwrap());
+        builder.entry_alloca(int_32, "slot");
+        builder.add(first, param, "second");
+
+        module.verify().unwrap();
+
+        let ir = module.print_to_string().to_string_lossy().into_owned();
+        let slot = ir.find("%slot = alloca").unwrap();
+        let first = ir.find("%first = add").unwrap();
+        let second = ir.find("%second = add").unwrap();
+        let ret = ir.find("ret i32 %first").unwrap();
+
+        assert!(slot < first && first < second && second < ret);
+    }
+
+    #[test]
+    fn test_infer_float_casts() {
+        let context = Context::create();
+        let (f16, bf16) = (context.f16().into(), context.bf16().into());
+
+        assert_eq!(CastOp::infer(f16, context.f32().into(), false), Ok(CastOp::FloatExtend));
+        assert_eq!(CastOp::infer(context.f64().into(), bf16, false), Ok(CastOp::FloatTrunc));
+        assert_eq!(CastOp::infer(f16, f16, false), Ok(CastOp::BitCast));
+        assert_eq!(
+            CastOp::infer(bf16, f16, false),
+            Err(CastError::InvalidTypes { op: None, from: TypeKind::BFloat, to: TypeKind::Half })
+        );
+    }
+
+    #[test]
+    fn test_intrinsic_calls() {
+        let context = Context::create();
+        let module = context.module("test");
+        let builder = context.builder();
+
+        let float = context.f64();
+        let int_32 = context.int::<32>();
+        assert_eq!(
+            builder.sqrt(float.constant(4.0), "").unwrap_err(),
+            IntrinsicError::Unpositioned
+        );
+
+        let ty = context.function(&[float.into(), int_32.into()], context.void().into());
+        let function = module.add_function("test", ty);
+        builder.position_at_end(&function.append_basic_block("entry"));
+
+        builder.sqrt(function.param(0), "root").unwrap();
+        builder.ctpop(function.param(1), "ones").unwrap();
+        builder.signed_add_with_overflow(function.param(1), function.param(1), "sum").unwrap();
+        builder.trap().unwrap();
+        builder.ret_void();
+
+        module.verify().unwrap();
+        let ir = module.print_to_string().to_string_lossy().into_owned();
+        assert!(ir.contains("%root = call double @llvm.sqrt.f64(double %0)"));
+        assert!(ir.contains("%ones = call i32 @llvm.ctpop.i32(i32 %1)"));
+        assert!(ir.contains("@llvm.sadd.with.overflow.i32(i32 %1, i32 %1)"));
+        assert!(ir.contains("call void @llvm.trap()"));
+    }
+
+    #[test]
+    fn test_atomic_orderings() {
+        let context = Context::create();
+        let module = context.module("test");
+        let builder = context.builder();
+
+        let int_8 = context.int::<8>();
+        let ty = context.function(&[context.pointer(0).into()], context.void().into());
+        let function = module.add_function("test", ty);
+        builder.position_at_end(&function.append_basic_block("entry"));
+
+        let pointer = PointerValue::from(function.param(0));
+        let one = int_8.constant(1);
+        let invalid =
+            |instruction, ordering| AtomicError::InvalidOrdering { instruction, ordering };
+        let system = SyncScope::System;
+
+        for ordering in [AtomicOrdering::Release, AtomicOrdering::AcquireRelease] {
+            let load = builder.atomic_load(int_8, pointer.clone(), ordering, system, "");
+            assert_eq!(load.unwrap_err(), invalid("load", ordering));
+        }
+        for ordering in [AtomicOrdering::Acquire, AtomicOrdering::AcquireRelease] {
+            let store = builder.atomic_store(one.clone(), pointer.clone(), ordering, system);
+            assert_eq!(store.unwrap_err(), invalid("store", ordering));
+        }
+        for ordering in [AtomicOrdering::Release, AtomicOrdering::AcquireRelease] {
+            let cmpxchg = builder.cmpxchg(
+                pointer.clone(),
+                one.clone(),
+                one.clone(),
+                AtomicOrdering::SequentiallyConsistent,
+                ordering,
+                system,
+            );
+            assert_eq!(cmpxchg.unwrap_err(), invalid("cmpxchg failure", ordering));
+        }
+        for ordering in [AtomicOrdering::Unordered, AtomicOrdering::Monotonic] {
+            assert_eq!(builder.fence(ordering, system).unwrap_err(), invalid("fence", ordering));
+        }
+        let rmw = builder.atomic_rmw(
+            AtomicRMWBinOp::Add,
+            pointer.clone(),
+            one.clone(),
+            AtomicOrdering::NotAtomic,
+            system,
+        );
+        assert_eq!(rmw.unwrap_err(), invalid("atomicrmw", AtomicOrdering::NotAtomic));
+
+        builder.atomic_load(int_8, pointer.clone(), AtomicOrdering::Acquire, system, "").unwrap();
+        builder
+            .atomic_store(one.clone(), pointer.clone(), AtomicOrdering::Release, system)
+            .unwrap();
+        builder
+            .atomic_rmw(
+                AtomicRMWBinOp::Add,
+                pointer.clone(),
+                one.clone(),
+                AtomicOrdering::Monotonic,
+                system,
+            )
+            .unwrap();
+        builder
+            .cmpxchg(
+                pointer,
+                one.clone(),
+                one,
+                AtomicOrdering::AcquireRelease,
+                AtomicOrdering::Acquire,
+                system,
+            )
+            .unwrap();
+        builder.fence(AtomicOrdering::SequentiallyConsistent, SyncScope::SingleThread).unwrap();
+        builder.ret_void();
+
+        module.verify().unwrap();
+    }
+
+    #[test]
+    fn test_fast_math_flags() {
+        let context = Context::create();
+        let module = context.module("test");
+        let builder = context.builder();
+
+        let f32 = context.f32();
+        let vector = context.vector(f32.into(), 4).unwrap();
+        let ty = context.function(&[vector.into(), vector.into()], vector.into());
+        let function = module.add_function("test", ty);
+        builder.position_at_end(&function.append_basic_block("entry"));
+        builder.set_fast_math_flags(FastMathFlags::NO_NANS | FastMathFlags::ALLOW_CONTRACT);
+
+        let folded = builder.float_add(f32.constant(1.0), f32.constant(2.0), "folded");
+        assert_eq!(folded.as_constant_f64(), Some(3.0));
+
+        let lhs = VectorValue::from(function.param(0));
+        let rhs = VectorValue::from(function.param(1));
+        let product = builder.vector_float_mul(lhs, rhs, "product");
+        builder.ret(product);
+
+        module.verify().unwrap();
+        let ir = module.print_to_string().to_string_lossy().into_owned();
+        assert!(ir.contains("%product = fmul nnan contract <4 x float>"));
+    }
+}
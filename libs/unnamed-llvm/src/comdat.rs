@@ -0,0 +1,62 @@
+use llvm_sys::{
+    comdat::{LLVMComdatSelectionKind, LLVMGetComdatSelectionKind, LLVMSetComdatSelectionKind},
+    prelude::LLVMComdatRef,
+};
+
+use crate::{context::Context, impl_as_raw, impl_get_context, AsRaw};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComdatSelectionKind {
+    Any,
+    ExactMatch,
+    Largest,
+    NoDeduplicate,
+    SameSize,
+}
+
+impl From<ComdatSelectionKind> for LLVMComdatSelectionKind {
+    fn from(value: ComdatSelectionKind) -> Self {
+        match value {
+            ComdatSelectionKind::Any => Self::LLVMAnyComdatSelectionKind,
+            ComdatSelectionKind::ExactMatch => Self::LLVMExactMatchComdatSelectionKind,
+            ComdatSelectionKind::Largest => Self::LLVMLargestComdatSelectionKind,
+            ComdatSelectionKind::NoDeduplicate => Self::LLVMNoDuplicatesComdatSelectionKind,
+            ComdatSelectionKind::SameSize => Self::LLVMSameSizeComdatSelectionKind,
+        }
+    }
+}
+
+impl From<LLVMComdatSelectionKind> for ComdatSelectionKind {
+    fn from(value: LLVMComdatSelectionKind) -> Self {
+        match value {
+            LLVMComdatSelectionKind::LLVMAnyComdatSelectionKind => Self::Any,
+            LLVMComdatSelectionKind::LLVMExactMatchComdatSelectionKind => Self::ExactMatch,
+            LLVMComdatSelectionKind::LLVMLargestComdatSelectionKind => Self::Largest,
+            LLVMComdatSelectionKind::LLVMNoDuplicatesComdatSelectionKind => Self::NoDeduplicate,
+            LLVMComdatSelectionKind::LLVMSameSizeComdatSelectionKind => Self::SameSize,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Comdat<'ctx> {
+    context: &'ctx Context,
+    inner: LLVMComdatRef,
+}
+
+impl<'ctx> Comdat<'ctx> {
+    pub(crate) fn new(context: &'ctx Context, inner: LLVMComdatRef) -> Self {
+        Self { context, inner }
+    }
+
+    pub fn selection_kind(&self) -> ComdatSelectionKind {
+        unsafe { LLVMGetComdatSelectionKind(self.as_raw()).into() }
+    }
+
+    pub fn set_selection_kind(&self, kind: ComdatSelectionKind) {
+        unsafe { LLVMSetComdatSelectionKind(self.as_raw(), kind.into()) }
+    }
+}
+
+impl_as_raw!(Comdat<'ctx>.inner -> LLVMComdatRef);
+impl_get_context!(Comdat<'ctx>.context);
@@ -1,24 +1,44 @@
 use llvm_sys::{
     core::{
-        LLVMContextCreate, LLVMCreateBuilderInContext, LLVMFunctionType, LLVMInt16TypeInContext,
-        LLVMInt1TypeInContext, LLVMInt32TypeInContext, LLVMInt64TypeInContext,
-        LLVMInt8TypeInContext, LLVMIntTypeInContext, LLVMModuleCreateWithNameInContext,
-        LLVMPointerTypeInContext,
+        LLVMArrayType, LLVMBFloatTypeInContext, LLVMConstArray, LLVMConstStringInContext,
+        LLVMConstStructInContext, LLVMConstVector, LLVMContextCreate, LLVMCreateBuilderInContext,
+        LLVMCreateEnumAttribute, LLVMCreateStringAttribute, LLVMCreateTypeAttribute,
+        LLVMDoubleTypeInContext, LLVMFP128TypeInContext, LLVMFloatTypeInContext, LLVMFunctionType,
+        LLVMHalfTypeInContext, LLVMInt16TypeInContext, LLVMInt1TypeInContext,
+        LLVMInt32TypeInContext, LLVMInt64TypeInContext, LLVMInt8TypeInContext,
+        LLVMIntTypeInContext, LLVMIsConstant, LLVMMDNodeInContext2, LLVMMDStringInContext2,
+        LLVMModuleCreateWithNameInContext, LLVMPointerTypeInContext, LLVMStructTypeInContext,
+        LLVMTypeOf, LLVMValueAsMetadata, LLVMVectorType, LLVMVoidTypeInContext,
     },
     prelude::LLVMContextRef,
+    target::LLVMIntPtrTypeForASInContext,
 };
 
 use crate::{
-    attribute::{Attribute, AttributeKind},
+    attribute::{Attribute, AttributeKind, IntAttributeKind, TypeAttributeKind},
     builder::Builder,
-    extra::LLVMCreateAttribute,
     impl_as_raw,
+    metadata::Metadata,
     module::Module,
+    signature::{Params, StaticType, TypedFunctionType},
+    target::TargetData,
     to_c_str,
     types::{
-        base_type::BaseType, function::FunctionType, integer::IntegerType, pointer::PointerType,
+        array::ArrayType,
+        base_type::BaseType,
+        float::{FloatKind, FloatType},
+        function::FunctionType,
+        integer::IntegerType,
+        pointer::PointerType,
+        structure::StructType,
+        vector::VectorType,
+        void::VoidType,
         Type,
     },
+    values::{
+        array::ArrayValue, base_value::BaseValue, structure::StructValue, vector::VectorValue,
+        Value,
+    },
     AsRaw, Assert, GetContext, True,
 };
 #[derive(Debug)]
@@ -29,7 +49,7 @@ impl Context {
         Self(unsafe { LLVMContextCreate() })
     }
 
-    pub fn module(&self, name: &str) -> Module {
+    pub fn module(&self, name: &str) -> Module<'_> {
         let name = to_c_str(name);
 
         let module_ref = unsafe { LLVMModuleCreateWithNameInContext(name.as_ptr(), self.as_raw()) };
@@ -37,19 +57,43 @@ impl Context {
         Module::new(self, module_ref)
     }
 
-    pub fn builder(&self) -> Builder {
+    pub fn builder(&self) -> Builder<'_> {
         unsafe {
             let builder_ref = LLVMCreateBuilderInContext(self.as_raw());
             Builder::new(self, builder_ref)
         }
     }
 
-    pub fn attribute(&self, kind: AttributeKind) -> Attribute {
-        let attribute_ref = unsafe { LLVMCreateAttribute(self.as_raw(), kind) };
+    pub fn attribute(&self, kind: AttributeKind) -> Option<Attribute<'_>> {
+        let attribute_ref = unsafe { LLVMCreateEnumAttribute(self.as_raw(), kind.id()?, 0) };
+        Some(Attribute::new(self, attribute_ref))
+    }
+
+    pub fn int_attribute(&self, kind: IntAttributeKind, value: u64) -> Option<Attribute<'_>> {
+        let attribute_ref = unsafe { LLVMCreateEnumAttribute(self.as_raw(), kind.id()?, value) };
+        Some(Attribute::new(self, attribute_ref))
+    }
+
+    pub fn type_attribute(&self, kind: TypeAttributeKind, ty: Type) -> Option<Attribute<'_>> {
+        let attribute_ref =
+            unsafe { LLVMCreateTypeAttribute(self.as_raw(), kind.id()?, ty.as_raw()) };
+        Some(Attribute::new(self, attribute_ref))
+    }
+
+    pub fn string_attribute(&self, key: &str, value: &str) -> Attribute<'_> {
+        let attribute_ref = unsafe {
+            LLVMCreateStringAttribute(
+                self.as_raw(),
+                key.as_ptr().cast(),
+                key.len() as u32,
+                value.as_ptr().cast(),
+                value.len() as u32,
+            )
+        };
         Attribute::new(self, attribute_ref)
     }
 
-    pub fn int<const WIDTH: u32>(&self) -> IntegerType
+    pub fn int<const WIDTH: u32>(&self) -> IntegerType<'_>
     where
         Assert<{ WIDTH > 0 }>: True,
     {
@@ -67,7 +111,45 @@ impl Context {
         IntegerType(BaseType::new(self, type_ref))
     }
 
-    pub fn function(&self, params: &[Type], return_ty: Type) -> FunctionType {
+    pub fn int_ptr(&self, target_data: &TargetData, address_space: u32) -> IntegerType<'_> {
+        let type_ref = unsafe {
+            LLVMIntPtrTypeForASInContext(self.as_raw(), target_data.as_raw(), address_space)
+        };
+
+        IntegerType(BaseType::new(self, type_ref))
+    }
+
+    pub fn f16(&self) -> FloatType<'_> {
+        let float_ref = unsafe { LLVMHalfTypeInContext(self.as_raw()) };
+
+        FloatType::new(BaseType::new(self, float_ref), FloatKind::Half)
+    }
+
+    pub fn bf16(&self) -> FloatType<'_> {
+        let float_ref = unsafe { LLVMBFloatTypeInContext(self.as_raw()) };
+
+        FloatType::new(BaseType::new(self, float_ref), FloatKind::BFloat)
+    }
+
+    pub fn f32(&self) -> FloatType<'_> {
+        let float_ref = unsafe { LLVMFloatTypeInContext(self.as_raw()) };
+
+        FloatType::new(BaseType::new(self, float_ref), FloatKind::Float)
+    }
+
+    pub fn f64(&self) -> FloatType<'_> {
+        let float_ref = unsafe { LLVMDoubleTypeInContext(self.as_raw()) };
+
+        FloatType::new(BaseType::new(self, float_ref), FloatKind::Double)
+    }
+
+    pub fn f128(&self) -> FloatType<'_> {
+        let float_ref = unsafe { LLVMFP128TypeInContext(self.as_raw()) };
+
+        FloatType::new(BaseType::new(self, float_ref), FloatKind::Fp128)
+    }
+
+    pub fn function(&self, params: &[Type], return_ty: Type) -> FunctionType<'_> {
         let mut params = params.iter().map(|param| param.as_raw()).collect::<Vec<_>>();
 
         let function_ref = unsafe {
@@ -77,11 +159,132 @@ impl Context {
         FunctionType(BaseType::new(self, function_ref))
     }
 
-    pub fn pointer(&self, address_space: u32) -> PointerType {
+    pub fn fn_type<P: Params, R: StaticType>(&self) -> TypedFunctionType<'_, P, R> {
+        let params = P::static_types(self);
+
+        TypedFunctionType::new(self.function(&params, R::static_type(self)))
+    }
+
+    pub fn pointer(&self, address_space: u32) -> PointerType<'_> {
         let pointer_ref = unsafe { LLVMPointerTypeInContext(self.as_raw(), address_space) };
 
         PointerType(BaseType::new(self, pointer_ref))
     }
+
+    pub fn structure(&self, fields: &[Type], packed: bool) -> StructType<'_> {
+        let mut fields = fields.iter().map(|field| field.as_raw()).collect::<Vec<_>>();
+
+        let struct_ref = unsafe {
+            LLVMStructTypeInContext(
+                self.as_raw(),
+                fields.as_mut_ptr(),
+                fields.len() as u32,
+                packed as i32,
+            )
+        };
+
+        StructType(BaseType::new(self, struct_ref))
+    }
+
+    pub fn void(&self) -> VoidType<'_> {
+        let void_ref = unsafe { LLVMVoidTypeInContext(self.as_raw()) };
+
+        VoidType(BaseType::new(self, void_ref))
+    }
+
+    pub fn array(&self, element_ty: Type, len: u32) -> ArrayType<'_> {
+        let array_ref = unsafe { LLVMArrayType(element_ty.as_raw(), len) };
+
+        ArrayType(BaseType::new(self, array_ref))
+    }
+
+    pub fn vector(&self, element_ty: Type, len: u32) -> Option<VectorType<'_>> {
+        if len == 0 || !matches!(element_ty, Type::Integer(_) | Type::Float(_) | Type::Pointer(_)) {
+            return None;
+        }
+
+        let vector_ref = unsafe { LLVMVectorType(element_ty.as_raw(), len) };
+
+        Some(VectorType(BaseType::new(self, vector_ref)))
+    }
+
+    pub fn const_struct(&self, fields: &[Value], packed: bool) -> StructValue<'_> {
+        let mut fields = fields.iter().map(|field| field.as_raw()).collect::<Vec<_>>();
+
+        let struct_ref = unsafe {
+            LLVMConstStructInContext(
+                self.as_raw(),
+                fields.as_mut_ptr(),
+                fields.len() as u32,
+                packed as i32,
+            )
+        };
+
+        StructValue(BaseValue::new(self, struct_ref))
+    }
+
+    pub fn const_array(&self, element_ty: Type, values: &[Value]) -> ArrayValue<'_> {
+        let mut values = values.iter().map(|value| value.as_raw()).collect::<Vec<_>>();
+
+        let array_ref = unsafe {
+            LLVMConstArray(element_ty.as_raw(), values.as_mut_ptr(), values.len() as u32)
+        };
+
+        ArrayValue(BaseValue::new(self, array_ref))
+    }
+
+    pub fn const_vector(&self, values: &[Value]) -> Option<VectorValue<'_>> {
+        let element_ty = unsafe { LLVMTypeOf(values.first()?.as_raw()) };
+        if values.iter().any(|value| unsafe {
+            LLVMTypeOf(value.as_raw()) != element_ty || LLVMIsConstant(value.as_raw()) == 0
+        }) {
+            return None;
+        }
+
+        let mut values = values.iter().map(|value| value.as_raw()).collect::<Vec<_>>();
+
+        let vector_ref = unsafe { LLVMConstVector(values.as_mut_ptr(), values.len() as u32) };
+
+        Some(VectorValue(BaseValue::new(self, vector_ref)))
+    }
+
+    pub fn const_string(&self, string: &str, null_terminated: bool) -> ArrayValue<'_> {
+        let array_ref = unsafe {
+            LLVMConstStringInContext(
+                self.as_raw(),
+                string.as_ptr().cast(),
+                string.len() as u32,
+                !null_terminated as i32,
+            )
+        };
+
+        ArrayValue(BaseValue::new(self, array_ref))
+    }
+
+    pub fn metadata_string(&self, string: &str) -> Metadata<'_> {
+        let metadata_ref =
+            unsafe { LLVMMDStringInContext2(self.as_raw(), string.as_ptr().cast(), string.len()) };
+
+        Metadata::new(self, metadata_ref)
+    }
+
+    pub fn metadata_node(&self, operands: &[Metadata]) -> Metadata<'_> {
+        let mut operands = operands.iter().map(|operand| operand.as_raw()).collect::<Vec<_>>();
+
+        let metadata_ref =
+            unsafe { LLVMMDNodeInContext2(self.as_raw(), operands.as_mut_ptr(), operands.len()) };
+
+        Metadata::new(self, metadata_ref)
+    }
+
+    pub fn value_metadata<'ctx, V>(&'ctx self, value: V) -> Metadata<'ctx>
+    where
+        Value<'ctx>: From<V>,
+    {
+        let value = Value::from(value);
+
+        Metadata::new(self, unsafe { LLVMValueAsMetadata(value.as_raw()) })
+    }
 }
 
 impl GetContext for Context {
@@ -91,3 +294,32 @@ impl GetContext for Context {
 }
 
 impl_as_raw!(Context.0 -> LLVMContextRef);
+
+#[cfg(test)]
+mod tests {
+    use crate::values::TypeOf;
+
+    use super::Context;
+
+    #[test]
+    fn test_vector_constructors() {
+        let context = Context::create();
+        let int_32 = context.int::<32>();
+        let f32 = context.f32();
+
+        assert!(context.vector(int_32.into(), 0).is_none());
+        assert!(context.vector(context.void().into(), 4).is_none());
+        assert_eq!(context.vector(f32.into(), 4).unwrap().len(), 4);
+
+        assert!(context.const_vector(&[]).is_none());
+        assert!(context
+            .const_vector(&[int_32.constant(1).into(), f32.constant(1.0).into()])
+            .is_none());
+        assert!(context
+            .const_vector(&[int_32.constant(1).into(), context.int::<64>().constant(1).into()])
+            .is_none());
+
+        let vector = context.const_vector(&[int_32.constant(1).into(), int_32.constant(2).into()]);
+        assert_eq!(vector.unwrap().type_of().len(), 2);
+    }
+}
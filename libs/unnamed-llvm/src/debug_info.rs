@@ -0,0 +1,416 @@
+use std::{marker::PhantomData, ptr};
+
+use llvm_sys::{
+    debuginfo::{
+        LLVMDIBuilderCreateAutoVariable, LLVMDIBuilderCreateBasicType,
+        LLVMDIBuilderCreateCompileUnit, LLVMDIBuilderCreateDebugLocation,
+        LLVMDIBuilderCreateExpression, LLVMDIBuilderCreateFile, LLVMDIBuilderCreateFunction,
+        LLVMDIBuilderCreateLexicalBlock, LLVMDIBuilderCreateParameterVariable,
+        LLVMDIBuilderCreateSubroutineType, LLVMDIBuilderFinalize, LLVMDIBuilderInsertDeclareAtEnd,
+        LLVMDIFlagZero, LLVMDWARFEmissionKind, LLVMDWARFSourceLanguage, LLVMDisposeDIBuilder,
+    },
+    prelude::{LLVMDIBuilderRef, LLVMMetadataRef},
+};
+
+use crate::{
+    context::Context,
+    impl_as_raw, impl_get_context,
+    module::Module,
+    values::{basic_block::BasicBlock, pointer::PointerValue},
+    AsRaw, GetContext,
+};
+
+macro_rules! debug_metadata {
+    ($($name: ident),* $(,)?) => {
+        $(
+            #[derive(Debug, Clone, Copy)]
+            pub struct $name<'ctx> {
+                context: &'ctx Context,
+                inner: LLVMMetadataRef,
+            }
+
+            impl<'ctx> $name<'ctx> {
+                pub(crate) fn new(context: &'ctx Context, inner: LLVMMetadataRef) -> Self {
+                    Self { context, inner }
+                }
+            }
+
+            impl_as_raw!($name<'ctx>.inner -> LLVMMetadataRef);
+            impl_get_context!($name<'ctx>.context);
+        )*
+    };
+}
+
+debug_metadata!(
+    DIFile,
+    DICompileUnit,
+    DISubprogram,
+    DILexicalBlock,
+    DIType,
+    DISubroutineType,
+    DILocalVariable,
+    DILocation,
+);
+
+macro_rules! impl_scope_downcast {
+    ($($name: ident -> DIScope::$variant: ident),* $(,)?) => {
+        $(
+            impl<'ctx> From<$name<'ctx>> for DIScope<'ctx> {
+                fn from(scope: $name<'ctx>) -> Self {
+                    DIScope::$variant(scope)
+                }
+            }
+        )*
+    };
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum DIScope<'ctx> {
+    File(DIFile<'ctx>),
+    CompileUnit(DICompileUnit<'ctx>),
+    Subprogram(DISubprogram<'ctx>),
+    LexicalBlock(DILexicalBlock<'ctx>),
+}
+
+impl_scope_downcast!(
+    DIFile -> DIScope::File,
+    DICompileUnit -> DIScope::CompileUnit,
+    DISubprogram -> DIScope::Subprogram,
+    DILexicalBlock -> DIScope::LexicalBlock,
+);
+
+impl<'ctx> AsRaw for DIScope<'ctx> {
+    type Raw = LLVMMetadataRef;
+
+    fn as_raw(&self) -> Self::Raw {
+        match self {
+            DIScope::File(scope) => scope.as_raw(),
+            DIScope::CompileUnit(scope) => scope.as_raw(),
+            DIScope::Subprogram(scope) => scope.as_raw(),
+            DIScope::LexicalBlock(scope) => scope.as_raw(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TypeEncoding {
+    Boolean,
+    Float,
+    Signed,
+    SignedChar,
+    Unsigned,
+    UnsignedChar,
+}
+
+impl From<TypeEncoding> for u32 {
+    fn from(encoding: TypeEncoding) -> Self {
+        match encoding {
+            TypeEncoding::Boolean => 0x02,
+            TypeEncoding::Float => 0x04,
+            TypeEncoding::Signed => 0x05,
+            TypeEncoding::SignedChar => 0x06,
+            TypeEncoding::Unsigned => 0x07,
+            TypeEncoding::UnsignedChar => 0x08,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourceLanguage {
+    C,
+    C89,
+    C99,
+    C11,
+    CPlusPlus,
+    CPlusPlus11,
+    CPlusPlus14,
+    Rust,
+    Swift,
+}
+
+impl From<SourceLanguage> for LLVMDWARFSourceLanguage {
+    fn from(language: SourceLanguage) -> Self {
+        match language {
+            SourceLanguage::C => Self::LLVMDWARFSourceLanguageC,
+            SourceLanguage::C89 => Self::LLVMDWARFSourceLanguageC89,
+            SourceLanguage::C99 => Self::LLVMDWARFSourceLanguageC99,
+            SourceLanguage::C11 => Self::LLVMDWARFSourceLanguageC11,
+            SourceLanguage::CPlusPlus => Self::LLVMDWARFSourceLanguageC_plus_plus,
+            SourceLanguage::CPlusPlus11 => Self::LLVMDWARFSourceLanguageC_plus_plus_11,
+            SourceLanguage::CPlusPlus14 => Self::LLVMDWARFSourceLanguageC_plus_plus_14,
+            SourceLanguage::Rust => Self::LLVMDWARFSourceLanguageRust,
+            SourceLanguage::Swift => Self::LLVMDWARFSourceLanguageSwift,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct DebugInfoBuilder<'m, 'ctx> {
+    context: &'ctx Context,
+    inner: LLVMDIBuilderRef,
+    _module: PhantomData<&'m Module<'ctx>>,
+}
+
+impl<'m, 'ctx> DebugInfoBuilder<'m, 'ctx> {
+    pub(crate) fn new(context: &'ctx Context, inner: LLVMDIBuilderRef) -> Self {
+        Self { context, inner, _module: PhantomData }
+    }
+
+    pub fn create_file(&self, filename: &str, directory: &str) -> DIFile<'ctx> {
+        let file_ref = unsafe {
+            LLVMDIBuilderCreateFile(
+                self.as_raw(),
+                filename.as_ptr().cast(),
+                filename.len(),
+                directory.as_ptr().cast(),
+                directory.len(),
+            )
+        };
+
+        DIFile::new(self.context, file_ref)
+    }
+
+    pub fn create_compile_unit(
+        &self,
+        file: &DIFile<'ctx>,
+        language: SourceLanguage,
+        producer: &str,
+        optimized: bool,
+    ) -> DICompileUnit<'ctx> {
+        let compile_unit_ref = unsafe {
+            LLVMDIBuilderCreateCompileUnit(
+                self.as_raw(),
+                language.into(),
+                file.as_raw(),
+                producer.as_ptr().cast(),
+                producer.len(),
+                optimized as i32,
+                ptr::null(),
+                0,
+                0,
+                ptr::null(),
+                0,
+                LLVMDWARFEmissionKind::LLVMDWARFEmissionKindFull,
+                0,
+                0,
+                0,
+                ptr::null(),
+                0,
+                ptr::null(),
+                0,
+            )
+        };
+
+        DICompileUnit::new(self.context, compile_unit_ref)
+    }
+
+    pub fn create_basic_type(
+        &self,
+        name: &str,
+        size_in_bits: u64,
+        encoding: TypeEncoding,
+    ) -> DIType<'ctx> {
+        let type_ref = unsafe {
+            LLVMDIBuilderCreateBasicType(
+                self.as_raw(),
+                name.as_ptr().cast(),
+                name.len(),
+                size_in_bits,
+                encoding.into(),
+                LLVMDIFlagZero,
+            )
+        };
+
+        DIType::new(self.context, type_ref)
+    }
+
+    pub fn create_subroutine_type(
+        &self,
+        file: &DIFile<'ctx>,
+        return_ty: Option<&DIType<'ctx>>,
+        params: &[DIType<'ctx>],
+    ) -> DISubroutineType<'ctx> {
+        let mut types = vec![return_ty.map_or(ptr::null_mut(), |ty| ty.as_raw())];
+        types.extend(params.iter().map(|param| param.as_raw()));
+
+        let subroutine_ref = unsafe {
+            LLVMDIBuilderCreateSubroutineType(
+                self.as_raw(),
+                file.as_raw(),
+                types.as_mut_ptr(),
+                types.len() as u32,
+                LLVMDIFlagZero,
+            )
+        };
+
+        DISubroutineType::new(self.context, subroutine_ref)
+    }
+
+    pub fn create_function(
+        &self,
+        scope: DIScope<'ctx>,
+        name: &str,
+        file: &DIFile<'ctx>,
+        line: u32,
+        ty: &DISubroutineType<'ctx>,
+        local: bool,
+    ) -> DISubprogram<'ctx> {
+        let subprogram_ref = unsafe {
+            LLVMDIBuilderCreateFunction(
+                self.as_raw(),
+                scope.as_raw(),
+                name.as_ptr().cast(),
+                name.len(),
+                name.as_ptr().cast(),
+                name.len(),
+                file.as_raw(),
+                line,
+                ty.as_raw(),
+                local as i32,
+                1,
+                line,
+                LLVMDIFlagZero,
+                0,
+            )
+        };
+
+        DISubprogram::new(self.context, subprogram_ref)
+    }
+
+    pub fn create_lexical_block(
+        &self,
+        scope: DIScope<'ctx>,
+        file: &DIFile<'ctx>,
+        line: u32,
+        column: u32,
+    ) -> DILexicalBlock<'ctx> {
+        let block_ref = unsafe {
+            LLVMDIBuilderCreateLexicalBlock(
+                self.as_raw(),
+                scope.as_raw(),
+                file.as_raw(),
+                line,
+                column,
+            )
+        };
+
+        DILexicalBlock::new(self.context, block_ref)
+    }
+
+    pub fn create_auto_variable(
+        &self,
+        scope: DIScope<'ctx>,
+        name: &str,
+        file: &DIFile<'ctx>,
+        line: u32,
+        ty: &DIType<'ctx>,
+    ) -> DILocalVariable<'ctx> {
+        let variable_ref = unsafe {
+            LLVMDIBuilderCreateAutoVariable(
+                self.as_raw(),
+                scope.as_raw(),
+                name.as_ptr().cast(),
+                name.len(),
+                file.as_raw(),
+                line,
+                ty.as_raw(),
+                1,
+                LLVMDIFlagZero,
+                0,
+            )
+        };
+
+        DILocalVariable::new(self.context, variable_ref)
+    }
+
+    pub fn create_parameter_variable(
+        &self,
+        scope: DIScope<'ctx>,
+        name: &str,
+        arg_no: u32,
+        file: &DIFile<'ctx>,
+        line: u32,
+        ty: &DIType<'ctx>,
+    ) -> DILocalVariable<'ctx> {
+        let variable_ref = unsafe {
+            LLVMDIBuilderCreateParameterVariable(
+                self.as_raw(),
+                scope.as_raw(),
+                name.as_ptr().cast(),
+                name.len(),
+                arg_no,
+                file.as_raw(),
+                line,
+                ty.as_raw(),
+                1,
+                LLVMDIFlagZero,
+            )
+        };
+
+        DILocalVariable::new(self.context, variable_ref)
+    }
+
+    pub fn create_debug_location(
+        &self,
+        line: u32,
+        column: u32,
+        scope: DIScope<'ctx>,
+    ) -> DILocation<'ctx> {
+        let location_ref = unsafe {
+            LLVMDIBuilderCreateDebugLocation(
+                self.context.as_raw(),
+                line,
+                column,
+                scope.as_raw(),
+                ptr::null_mut(),
+            )
+        };
+
+        DILocation::new(self.context, location_ref)
+    }
+
+    pub fn insert_declare_at_end(
+        &self,
+        storage: &PointerValue<'ctx>,
+        variable: &DILocalVariable<'ctx>,
+        location: &DILocation<'ctx>,
+        basic_block: &BasicBlock<'ctx>,
+    ) {
+        unsafe {
+            let expression_ref = LLVMDIBuilderCreateExpression(self.as_raw(), ptr::null_mut(), 0);
+
+            LLVMDIBuilderInsertDeclareAtEnd(
+                self.as_raw(),
+                storage.as_raw(),
+                variable.as_raw(),
+                expression_ref,
+                location.as_raw(),
+                basic_block.as_raw(),
+            );
+        }
+    }
+
+    pub fn finalize(&self) {
+        unsafe { LLVMDIBuilderFinalize(self.as_raw()) }
+    }
+}
+
+impl<'m, 'ctx> Drop for DebugInfoBuilder<'m, 'ctx> {
+    fn drop(&mut self) {
+        unsafe { LLVMDisposeDIBuilder(self.inner) }
+    }
+}
+
+impl<'m, 'ctx> AsRaw for DebugInfoBuilder<'m, 'ctx> {
+    type Raw = LLVMDIBuilderRef;
+
+    fn as_raw(&self) -> Self::Raw {
+        self.inner
+    }
+}
+
+impl<'m, 'ctx> GetContext for DebugInfoBuilder<'m, 'ctx> {
+    fn get_context(&self) -> &Context {
+        self.context
+    }
+}
@@ -1,12 +1,18 @@
 use std::{
-    ffi::CString,
+    ffi::CStr,
     fmt::{self, Display},
+    mem,
 };
 
 use llvm_sys::error::{
     LLVMConsumeError, LLVMDisposeErrorMessage, LLVMErrorRef, LLVMGetErrorMessage,
 };
 
+use crate::{
+    builder::{AtomicOrdering, CastOp},
+    types::TypeKind,
+};
+
 #[derive(Debug)]
 pub struct ErrorMessage(*mut i8);
 impl Drop for ErrorMessage {
@@ -17,7 +23,7 @@ impl Drop for ErrorMessage {
 
 impl Display for ErrorMessage {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let message = unsafe { CString::from_raw(self.0) };
+        let message = unsafe { CStr::from_ptr(self.0) };
         write!(f, "{}", message.to_string_lossy())
     }
 }
@@ -27,10 +33,10 @@ pub struct Error(pub(crate) LLVMErrorRef);
 
 impl Error {
     pub fn get_error_message(self) -> ErrorMessage {
-        unsafe {
-            let error_message = LLVMGetErrorMessage(self.0);
-            ErrorMessage(error_message)
-        }
+        let error_message = unsafe { LLVMGetErrorMessage(self.0) };
+        mem::forget(self);
+
+        ErrorMessage(error_message)
     }
 
     pub fn is_success(&self) -> bool {
@@ -47,3 +53,129 @@ impl Drop for Error {
         unsafe { LLVMConsumeError(self.0) }
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CastError {
+    InvalidWidth { op: CastOp, from: u32, to: u32 },
+    InvalidAddressSpace { op: CastOp, from: u32, to: u32 },
+    InvalidTypes { op: Option<CastOp>, from: TypeKind, to: TypeKind },
+}
+
+impl Display for CastError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CastError::InvalidWidth { op, from, to } => {
+                write!(f, "{op:?} cannot convert a {from}-bit value to {to} bits")
+            }
+            CastError::InvalidAddressSpace { op, from, to } => {
+                write!(f, "{op:?} cannot convert address space {from} to {to}")
+            }
+            CastError::InvalidTypes { op: Some(op), from, to } => {
+                write!(f, "{op:?} cannot convert {from:?} to {to:?}")
+            }
+            CastError::InvalidTypes { op: None, from, to } => {
+                write!(f, "no cast converts {from:?} to {to:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CastError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AtomicError {
+    InvalidOrdering { instruction: &'static str, ordering: AtomicOrdering },
+}
+
+impl Display for AtomicError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AtomicError::InvalidOrdering { instruction, ordering } => {
+                write!(f, "{ordering:?} is not a valid ordering for {instruction}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for AtomicError {}
+
+#[derive(Debug, Clone)]
+pub enum TargetError {
+    NotRegistered { name: &'static str },
+    InvalidTriple { triple: String, message: String },
+    Mismatch { triple: String, expected: &'static str, found: String },
+    EmitFailed { message: String },
+}
+
+impl Display for TargetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TargetError::NotRegistered { name } => write!(f, "target `{name}` is not registered"),
+            TargetError::InvalidTriple { triple, message } => {
+                write!(f, "invalid target triple `{triple}`: {message}")
+            }
+            TargetError::Mismatch { triple, expected, found } => {
+                write!(f, "target triple `{triple}` resolves to `{found}`, expected `{expected}`")
+            }
+            TargetError::EmitFailed { message } => write!(f, "failed to emit code: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for TargetError {}
+
+#[derive(Debug, Clone)]
+pub enum LinkError {
+    Failed { module: String, message: String },
+}
+
+impl Display for LinkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LinkError::Failed { module, message } => {
+                write!(f, "failed to link module `{module}`: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for LinkError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IntrinsicError {
+    Unknown { name: String },
+    OverloadMismatch { name: String, overloaded: bool },
+    Unpositioned,
+}
+
+impl Display for IntrinsicError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IntrinsicError::Unknown { name } => write!(f, "unknown intrinsic `{name}`"),
+            IntrinsicError::OverloadMismatch { name, overloaded: true } => {
+                write!(f, "intrinsic `{name}` is overloaded and needs overload types")
+            }
+            IntrinsicError::OverloadMismatch { name, overloaded: false } => {
+                write!(f, "intrinsic `{name}` is not overloaded and takes no overload types")
+            }
+            IntrinsicError::Unpositioned => {
+                write!(f, "builder is not positioned in a basic block")
+            }
+        }
+    }
+}
+
+impl std::error::Error for IntrinsicError {}
+
+#[derive(Debug, Clone)]
+pub struct VerifyError {
+    pub message: String,
+}
+
+impl Display for VerifyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "module verification failed: {}", self.message)
+    }
+}
+
+impl std::error::Error for VerifyError {}
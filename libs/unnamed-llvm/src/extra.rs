@@ -1,6 +1,12 @@
 #![allow(non_snake_case, clippy::missing_safety_doc)]
 
-use llvm_sys::prelude::{LLVMAttributeRef, LLVMContextRef, LLVMValueRef};
+use std::ffi::c_char;
+
+use llvm_sys::{
+    error::LLVMErrorRef,
+    prelude::{LLVMAttributeRef, LLVMBuilderRef, LLVMValueRef},
+    target_machine::LLVMTargetMachineRef,
+};
 
 pub unsafe fn LLVMAddFunctionAttributes(
     llfn: LLVMValueRef,
@@ -36,21 +42,12 @@ impl From<AttributeLocation> for u32 {
     }
 }
 
-#[repr(C)]
-#[derive(Copy, Clone, Debug)]
-pub enum AttributeKind {
-    AlwaysInline = 0,
-    Cold = 1,
-    Hot = 2,
-    NoAlias = 3,
-    NoCapture = 4,
-    NoInline = 5,
-    NoUnwind = 6,
-    NoReturn = 7,
-}
-
 extern "C" {
-    pub fn LLVMCreateAttribute(C: LLVMContextRef, attr: AttributeKind) -> LLVMAttributeRef;
+    pub fn LLVMRustRunFunctionPasses(
+        Fn: LLVMValueRef,
+        Passes: *const c_char,
+        TM: LLVMTargetMachineRef,
+    ) -> LLVMErrorRef;
 
     fn LLVMRustAddFunctionAttributes(
         Fn: LLVMValueRef,
@@ -59,6 +56,10 @@ extern "C" {
         AttrsLen: usize,
     );
 
+    pub fn LLVMRustSetFastMathFlags(Instr: LLVMValueRef, Flags: u32);
+
+    pub fn LLVMRustGetInsertPoint(B: LLVMBuilderRef) -> LLVMValueRef;
+
     fn LLVMRustAddCallSiteAttributes(
         Instr: LLVMValueRef,
         index: u32,
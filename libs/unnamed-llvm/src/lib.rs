@@ -7,11 +7,15 @@ use context::Context;
 
 pub mod attribute;
 pub mod builder;
+pub mod comdat;
 pub mod context;
+pub mod debug_info;
 pub mod error;
 pub mod extra;
+pub mod metadata;
 pub mod module;
 pub mod pass_manager;
+pub mod signature;
 pub mod target;
 pub mod types;
 pub mod values;
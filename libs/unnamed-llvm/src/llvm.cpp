@@ -1,41 +1,13 @@
+#include "llvm/IR/IRBuilder.h"
 #include "llvm/IR/Instructions.h"
+#include "llvm/Passes/PassBuilder.h"
+#include "llvm/Target/TargetMachine.h"
+#include "llvm-c/Error.h"
+#include "llvm-c/TargetMachine.h"
 
 using namespace llvm;
 using namespace llvm::sys;
 
-enum LLVMRustAttribute {
-  AlwaysInline = 0,
-  Cold = 1,
-  Hot = 2,
-  NoAlias = 3,
-  NoCapture = 4,
-  NoInline = 5,
-  NoUnwind = 6,
-  NoReturn = 7
-};
-
-static Attribute::AttrKind fromRust(LLVMRustAttribute Kind) {
-  switch (Kind) {
-    case AlwaysInline:
-        return Attribute::AlwaysInline;
-    case Cold:
-        return Attribute::Cold;
-    case Hot:
-        return Attribute::Hot;
-    case NoAlias:
-        return Attribute::NoAlias;
-    case NoCapture:
-        return Attribute::NoCapture;
-    case NoInline:
-        return Attribute::NoInline;
-    case NoUnwind:
-        return Attribute::NoUnwind;
-    case NoReturn:
-        return Attribute::NoReturn;
-  }
-  report_fatal_error("bad AttributeKind");
-}
-
 template<typename T> static inline void AddAttributes(T *t, unsigned Index,
                                                       LLVMAttributeRef *Attrs, size_t AttrsLen) {
   AttributeList PAL = t->getAttributes();
@@ -59,7 +31,52 @@ extern "C" void LLVMRustAddCallSiteAttributes(LLVMValueRef Instr, unsigned Index
   AddAttributes(Call, Index, Attrs, AttrsLen);
 }
 
-extern "C" LLVMAttributeRef LLVMCreateAttribute(LLVMContextRef C,
-                                                      LLVMRustAttribute RustAttr) {
-  return wrap(Attribute::get(*unwrap(C), fromRust(RustAttr)));
+extern "C" LLVMErrorRef LLVMRustRunFunctionPasses(LLVMValueRef Fn, const char *Passes,
+                                                  LLVMTargetMachineRef TM) {
+  Function *F = unwrap<Function>(Fn);
+  TargetMachine *Machine = reinterpret_cast<TargetMachine *>(TM);
+
+  LoopAnalysisManager LAM;
+  FunctionAnalysisManager FAM;
+  CGSCCAnalysisManager CGAM;
+  ModuleAnalysisManager MAM;
+
+  PassBuilder PB(Machine);
+  PB.registerModuleAnalyses(MAM);
+  PB.registerCGSCCAnalyses(CGAM);
+  PB.registerFunctionAnalyses(FAM);
+  PB.registerLoopAnalyses(LAM);
+  PB.crossRegisterProxies(LAM, FAM, CGAM, MAM);
+
+  FunctionPassManager FPM;
+  if (Error Err = PB.parsePassPipeline(FPM, Passes))
+    return wrap(std::move(Err));
+
+  FPM.run(*F, FAM);
+  return LLVMErrorSuccess;
+}
+
+extern "C" void LLVMRustSetFastMathFlags(LLVMValueRef V, unsigned Flags) {
+  Instruction *I = dyn_cast<Instruction>(unwrap(V));
+  if (!I || !isa<FPMathOperator>(I))
+    return;
+
+  FastMathFlags FMF;
+  FMF.setAllowReassoc(Flags & (1 << 0));
+  FMF.setNoNaNs(Flags & (1 << 1));
+  FMF.setNoInfs(Flags & (1 << 2));
+  FMF.setNoSignedZeros(Flags & (1 << 3));
+  FMF.setAllowReciprocal(Flags & (1 << 4));
+  FMF.setAllowContract(Flags & (1 << 5));
+  FMF.setApproxFunc(Flags & (1 << 6));
+  I->setFastMathFlags(FMF);
+}
+
+extern "C" LLVMValueRef LLVMRustGetInsertPoint(LLVMBuilderRef B) {
+  IRBuilder<> *Builder = unwrap(B);
+  BasicBlock *BB = Builder->GetInsertBlock();
+  if (!BB || Builder->GetInsertPoint() == BB->end())
+    return nullptr;
+
+  return wrap(&*Builder->GetInsertPoint());
 }
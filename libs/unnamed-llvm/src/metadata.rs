@@ -0,0 +1,68 @@
+use llvm_sys::{
+    core::{LLVMGetMDKindIDInContext, LLVMMetadataAsValue},
+    prelude::{LLVMMetadataRef, LLVMValueRef},
+};
+
+use crate::{context::Context, impl_as_raw, impl_get_context, AsRaw};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetadataKind {
+    Tbaa,
+    Prof,
+    FpMath,
+    Range,
+    TbaaStruct,
+    InvariantLoad,
+    AliasScope,
+    NoAlias,
+    NonTemporal,
+    NonNull,
+    Dereferenceable,
+    DereferenceableOrNull,
+    Align,
+    NoUndef,
+}
+
+impl MetadataKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            MetadataKind::Tbaa => "tbaa",
+            MetadataKind::Prof => "prof",
+            MetadataKind::FpMath => "fpmath",
+            MetadataKind::Range => "range",
+            MetadataKind::TbaaStruct => "tbaa.struct",
+            MetadataKind::InvariantLoad => "invariant.load",
+            MetadataKind::AliasScope => "alias.scope",
+            MetadataKind::NoAlias => "noalias",
+            MetadataKind::NonTemporal => "nontemporal",
+            MetadataKind::NonNull => "nonnull",
+            MetadataKind::Dereferenceable => "dereferenceable",
+            MetadataKind::DereferenceableOrNull => "dereferenceable_or_null",
+            MetadataKind::Align => "align",
+            MetadataKind::NoUndef => "noundef",
+        }
+    }
+}
+
+pub(crate) fn kind_id(context: &Context, name: &str) -> u32 {
+    unsafe { LLVMGetMDKindIDInContext(context.as_raw(), name.as_ptr().cast(), name.len() as u32) }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Metadata<'ctx> {
+    context: &'ctx Context,
+    inner: LLVMMetadataRef,
+}
+
+impl<'ctx> Metadata<'ctx> {
+    pub(crate) fn new(context: &'ctx Context, inner: LLVMMetadataRef) -> Self {
+        Self { context, inner }
+    }
+
+    pub(crate) fn as_value_ref(&self) -> LLVMValueRef {
+        unsafe { LLVMMetadataAsValue(self.context.as_raw(), self.inner) }
+    }
+}
+
+impl_as_raw!(Metadata<'ctx>.inner -> LLVMMetadataRef);
+impl_get_context!(Metadata<'ctx>.context);
@@ -1,20 +1,46 @@
-use std::ffi::CStr;
+use std::{
+    ffi::{c_void, CStr},
+    ptr,
+};
 
 use llvm_sys::{
-    core::{LLVMAddFunction, LLVMPrintModuleToString},
-    prelude::LLVMModuleRef,
+    analysis::{LLVMVerifierFailureAction, LLVMVerifyModule},
+    comdat::LLVMGetOrInsertComdat,
+    core::{
+        LLVMAddFunction, LLVMAddGlobal, LLVMAddGlobalInAddressSpace, LLVMAddModuleFlag,
+        LLVMCloneModule, LLVMConstInt, LLVMContextGetDiagnosticContext,
+        LLVMContextGetDiagnosticHandler, LLVMContextSetDiagnosticHandler, LLVMDisposeMessage,
+        LLVMGetDataLayoutStr, LLVMGetDiagInfoDescription, LLVMGetDiagInfoSeverity,
+        LLVMGetFirstFunction, LLVMGetIntrinsicDeclaration, LLVMGetModuleIdentifier,
+        LLVMGetNamedFunction, LLVMGetNamedGlobal, LLVMGetSourceFileName, LLVMGetTarget,
+        LLVMInt32TypeInContext, LLVMIntrinsicIsOverloaded, LLVMLookupIntrinsicID,
+        LLVMPrintModuleToString, LLVMSetModuleIdentifier, LLVMSetSourceFileName, LLVMSetTarget,
+        LLVMValueAsMetadata,
+    },
+    debuginfo::{LLVMCreateDIBuilder, LLVMDebugMetadataVersion, LLVMGetModuleDebugMetadataVersion},
+    linker::LLVMLinkModules2,
+    prelude::{LLVMDiagnosticInfoRef, LLVMModuleRef},
+    target::LLVMSetModuleDataLayout,
     transforms::pass_builder::LLVMRunPasses,
+    LLVMDiagnosticSeverity, LLVMModuleFlagBehavior,
 };
 
 use crate::{
+    comdat::Comdat,
     context::Context,
-    error::Error,
+    debug_info::DebugInfoBuilder,
+    error::{Error, IntrinsicError, LinkError, VerifyError},
     impl_as_raw, impl_get_context,
-    pass_manager::PassManagerOptions,
-    target::TargetMachine,
+    pass_manager::{PassManagerOptions, PassPipeline},
+    signature::{Params, StaticType, TypedFunctionType, TypedFunctionValue},
+    target::{TargetData, TargetMachine},
     to_c_str,
-    types::function::FunctionType,
-    values::{base_value::BaseValue, function::FunctionValue},
+    types::{function::FunctionType, Type},
+    values::{
+        base_value::BaseValue,
+        function::{FunctionIter, FunctionValue},
+        global::GlobalValue,
+    },
     AsRaw,
 };
 
@@ -31,7 +57,7 @@ impl<'ctx> Module<'ctx> {
     pub fn run_passes(
         &self,
         passes: &str,
-        target_machine: TargetMachine,
+        target_machine: &TargetMachine,
         options: PassManagerOptions,
     ) -> Result<(), Error> {
         let passes = to_c_str(passes);
@@ -48,6 +74,111 @@ impl<'ctx> Module<'ctx> {
         Ok(())
     }
 
+    pub fn name(&self) -> &CStr {
+        let mut len = 0;
+
+        unsafe { CStr::from_ptr(LLVMGetModuleIdentifier(self.inner, &mut len)) }
+    }
+
+    pub fn set_name(&self, name: &str) {
+        unsafe { LLVMSetModuleIdentifier(self.inner, name.as_ptr().cast(), name.len()) }
+    }
+
+    pub fn source_filename(&self) -> &CStr {
+        let mut len = 0;
+
+        unsafe { CStr::from_ptr(LLVMGetSourceFileName(self.inner, &mut len)) }
+    }
+
+    pub fn set_source_filename(&self, filename: &str) {
+        unsafe { LLVMSetSourceFileName(self.inner, filename.as_ptr().cast(), filename.len()) }
+    }
+
+    pub fn triple(&self) -> &CStr {
+        unsafe { CStr::from_ptr(LLVMGetTarget(self.inner)) }
+    }
+
+    pub fn data_layout(&self) -> &CStr {
+        unsafe { CStr::from_ptr(LLVMGetDataLayoutStr(self.inner)) }
+    }
+
+    pub fn link_in(&self, other: &Module<'ctx>) -> Result<(), LinkError> {
+        let module = other.name().to_string_lossy().into_owned();
+        let mut messages = Vec::<String>::new();
+
+        let failed = unsafe {
+            let context_ref = self.context.as_raw();
+            let handler = LLVMContextGetDiagnosticHandler(context_ref);
+            let diagnostic_context = LLVMContextGetDiagnosticContext(context_ref);
+
+            LLVMContextSetDiagnosticHandler(
+                context_ref,
+                Some(collect_diagnostic),
+                (&mut messages as *mut Vec<String>).cast(),
+            );
+            // LLVMLinkModules2 destroys the source module, so link a copy and leave `other` and
+            // the values borrowed from it intact.
+            let failed = LLVMLinkModules2(self.inner, LLVMCloneModule(other.inner)) != 0;
+            LLVMContextSetDiagnosticHandler(context_ref, handler, diagnostic_context);
+
+            failed
+        };
+
+        if failed {
+            return Err(LinkError::Failed { module, message: messages.join("\n") });
+        }
+
+        Ok(())
+    }
+
+    pub fn verify(&self) -> Result<(), VerifyError> {
+        let mut message = ptr::null_mut();
+
+        unsafe {
+            let failed = LLVMVerifyModule(
+                self.inner,
+                LLVMVerifierFailureAction::LLVMReturnStatusAction,
+                &mut message,
+            ) != 0;
+
+            let error = CStr::from_ptr(message).to_string_lossy().into_owned();
+            LLVMDisposeMessage(message);
+
+            if failed {
+                return Err(VerifyError { message: error });
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn set_data_layout(&self, data_layout: &TargetData) {
+        unsafe { LLVMSetModuleDataLayout(self.inner, data_layout.as_raw()) }
+    }
+
+    pub fn set_triple(&self, triple: &str) {
+        let triple = to_c_str(triple);
+
+        unsafe { LLVMSetTarget(self.inner, triple.as_ptr()) }
+    }
+
+    pub fn run_pipeline(
+        &self,
+        pipeline: &PassPipeline,
+        target_machine: &TargetMachine,
+        options: PassManagerOptions,
+    ) -> Result<(), Error> {
+        self.run_passes(&pipeline.to_string(), target_machine, options)
+    }
+
+    pub fn comdat(&self, name: &str) -> Comdat<'ctx> {
+        let name = to_c_str(name);
+
+        let comdat_ref = unsafe { LLVMGetOrInsertComdat(self.inner, name.as_ptr()) };
+
+        Comdat::new(self.context, comdat_ref)
+    }
+
     pub fn add_function(&self, name: &str, function: FunctionType<'ctx>) -> FunctionValue<'ctx> {
         let name = to_c_str(name);
 
@@ -56,6 +187,112 @@ impl<'ctx> Module<'ctx> {
         FunctionValue(BaseValue::new(self.context, function_ref))
     }
 
+    pub fn add_typed_function<P: Params, R: StaticType>(
+        &self,
+        name: &str,
+        function: TypedFunctionType<'ctx, P, R>,
+    ) -> TypedFunctionValue<'ctx, P, R> {
+        TypedFunctionValue::new(self.add_function(name, function.as_function_type()))
+    }
+
+    pub fn get_function(&self, name: &str) -> Option<FunctionValue<'ctx>> {
+        let name = to_c_str(name);
+
+        let function_ref = unsafe { LLVMGetNamedFunction(self.inner, name.as_ptr()) };
+        if function_ref.is_null() {
+            return None;
+        }
+
+        Some(FunctionValue(BaseValue::new(self.context, function_ref)))
+    }
+
+    pub fn intrinsic(
+        &self,
+        name: &str,
+        overloads: &[Type],
+    ) -> Result<FunctionValue<'ctx>, IntrinsicError> {
+        let id = unsafe { LLVMLookupIntrinsicID(name.as_ptr().cast(), name.len()) };
+        if id == 0 {
+            return Err(IntrinsicError::Unknown { name: name.to_owned() });
+        }
+
+        let overloaded = unsafe { LLVMIntrinsicIsOverloaded(id) } != 0;
+        if overloads.is_empty() == overloaded {
+            return Err(IntrinsicError::OverloadMismatch { name: name.to_owned(), overloaded });
+        }
+
+        let mut overloads = overloads.iter().map(|ty| ty.as_raw()).collect::<Vec<_>>();
+
+        let function_ref = unsafe {
+            LLVMGetIntrinsicDeclaration(self.inner, id, overloads.as_mut_ptr(), overloads.len())
+        };
+
+        Ok(FunctionValue(BaseValue::new(self.context, function_ref)))
+    }
+
+    pub fn functions(&self) -> FunctionIter<'ctx> {
+        let function_ref = unsafe { LLVMGetFirstFunction(self.inner) };
+
+        FunctionIter { context: self.context, next: function_ref }
+    }
+
+    pub fn add_global(&self, name: &str, ty: Type<'ctx>) -> GlobalValue<'ctx> {
+        let name = to_c_str(name);
+
+        let global_ref = unsafe { LLVMAddGlobal(self.inner, ty.as_raw(), name.as_ptr()) };
+
+        GlobalValue(BaseValue::new(self.context, global_ref))
+    }
+
+    pub fn add_global_in_address_space(
+        &self,
+        name: &str,
+        ty: Type<'ctx>,
+        address_space: u32,
+    ) -> GlobalValue<'ctx> {
+        let name = to_c_str(name);
+
+        let global_ref = unsafe {
+            LLVMAddGlobalInAddressSpace(self.inner, ty.as_raw(), name.as_ptr(), address_space)
+        };
+
+        GlobalValue(BaseValue::new(self.context, global_ref))
+    }
+
+    pub fn get_global(&self, name: &str) -> Option<GlobalValue<'ctx>> {
+        let name = to_c_str(name);
+
+        let global_ref = unsafe { LLVMGetNamedGlobal(self.inner, name.as_ptr()) };
+        if global_ref.is_null() {
+            return None;
+        }
+
+        Some(GlobalValue(BaseValue::new(self.context, global_ref)))
+    }
+
+    pub fn debug_info_builder(&self) -> DebugInfoBuilder<'_, 'ctx> {
+        unsafe {
+            if LLVMGetModuleDebugMetadataVersion(self.inner) == 0 {
+                let key = "Debug Info Version";
+                let version = LLVMConstInt(
+                    LLVMInt32TypeInContext(self.context.as_raw()),
+                    LLVMDebugMetadataVersion() as u64,
+                    0,
+                );
+
+                LLVMAddModuleFlag(
+                    self.inner,
+                    LLVMModuleFlagBehavior::LLVMModuleFlagBehaviorWarning,
+                    key.as_ptr().cast(),
+                    key.len(),
+                    LLVMValueAsMetadata(version),
+                );
+            }
+
+            DebugInfoBuilder::new(self.context, LLVMCreateDIBuilder(self.inner))
+        }
+    }
+
     pub fn print_to_string(&self) -> &CStr {
         unsafe {
             let ptr = LLVMPrintModuleToString(self.inner);
@@ -64,5 +301,58 @@ impl<'ctx> Module<'ctx> {
     }
 }
 
+extern "C" fn collect_diagnostic(info: LLVMDiagnosticInfoRef, messages: *mut c_void) {
+    unsafe {
+        if !matches!(LLVMGetDiagInfoSeverity(info), LLVMDiagnosticSeverity::LLVMDSError) {
+            return;
+        }
+
+        let description = LLVMGetDiagInfoDescription(info);
+        let messages = &mut *messages.cast::<Vec<String>>();
+        messages.push(CStr::from_ptr(description).to_string_lossy().into_owned());
+
+        LLVMDisposeMessage(description);
+    }
+}
+
+impl<'ctx> Clone for Module<'ctx> {
+    fn clone(&self) -> Self {
+        let module_ref = unsafe { LLVMCloneModule(self.inner) };
+
+        Module::new(self.context, module_ref)
+    }
+}
+
 impl_as_raw!(Module<'ctx>.inner -> LLVMModuleRef);
 impl_get_context!(Module<'ctx>.context);
+
+#[cfg(test)]
+mod tests {
+    use crate::context::Context;
+
+    #[test]
+    fn test_link_in_and_clone() {
+        let context = Context::create();
+        let int_32 = context.int::<32>();
+        let ty = context.function(&[], int_32.into());
+
+        let main = context.module("main");
+        main.add_function("answer", ty);
+
+        let other = context.module("other");
+        let function = other.add_function("answer", ty);
+        let builder = context.builder();
+        builder.position_at_end(&function.append_basic_block("entry"));
+        builder.ret(int_32.constant(42));
+
+        let pristine = main.clone();
+        main.link_in(&other).unwrap();
+        assert!(other.print_to_string().to_string_lossy().contains("ret i32 42"));
+        main.verify().unwrap();
+
+        let linked = main.print_to_string().to_string_lossy().into_owned();
+        let original = pristine.print_to_string().to_string_lossy().into_owned();
+        assert!(linked.contains("ret i32 42"));
+        assert!(!original.contains("ret i32 42"));
+    }
+}
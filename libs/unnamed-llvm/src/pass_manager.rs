@@ -1,12 +1,17 @@
+use std::fmt::{self, Display};
+
 use llvm_sys::transforms::pass_builder::{
     LLVMCreatePassBuilderOptions, LLVMPassBuilderOptionsRef,
-    LLVMPassBuilderOptionsSetCallGraphProfile, LLVMPassBuilderOptionsSetForgetAllSCEVInLoopUnroll,
+    LLVMPassBuilderOptionsSetCallGraphProfile, LLVMPassBuilderOptionsSetDebugLogging,
+    LLVMPassBuilderOptionsSetForgetAllSCEVInLoopUnroll,
     LLVMPassBuilderOptionsSetLicmMssaNoAccForPromotionCap, LLVMPassBuilderOptionsSetLicmMssaOptCap,
     LLVMPassBuilderOptionsSetLoopInterleaving, LLVMPassBuilderOptionsSetLoopUnrolling,
     LLVMPassBuilderOptionsSetLoopVectorization, LLVMPassBuilderOptionsSetMergeFunctions,
     LLVMPassBuilderOptionsSetSLPVectorization, LLVMPassBuilderOptionsSetVerifyEach,
 };
 
+use crate::target::OptimizationLevel;
+
 macro_rules! option {
     ($name: ident($arg: ident: $ty: ty) -> $func: ident) => {
         pub fn $name(&self, $arg: $ty) {
@@ -20,6 +25,14 @@ macro_rules! option {
     };
 }
 
+macro_rules! pass {
+    ($name: ident -> $pass: literal) => {
+        pub fn $name(self) -> Self {
+            self.pass($pass)
+        }
+    };
+}
+
 pub struct PassManagerOptions(pub LLVMPassBuilderOptionsRef);
 
 impl PassManagerOptions {
@@ -28,7 +41,7 @@ impl PassManagerOptions {
     }
 
     option!(set_call_graph_profile(call_graph: bool) -> LLVMPassBuilderOptionsSetCallGraphProfile(call_graph as i32));
-    option!(set_debug_logging(debug_logging: bool) -> LLVMPassBuilderOptionsSetCallGraphProfile(debug_logging as i32));
+    option!(set_debug_logging(debug_logging: bool) -> LLVMPassBuilderOptionsSetDebugLogging(debug_logging as i32));
     option!(set_forget_all_scev_in_loop_unroll(forget_scev: bool) -> LLVMPassBuilderOptionsSetForgetAllSCEVInLoopUnroll(forget_scev as i32));
     option!(set_licm_mssa_no_acc_for_promotion_cap(cap: u32) -> LLVMPassBuilderOptionsSetLicmMssaNoAccForPromotionCap(cap));
     option!(set_licm_mssa_opt_cap(cap: u32) -> LLVMPassBuilderOptionsSetLicmMssaOptCap(cap));
@@ -39,3 +52,131 @@ impl PassManagerOptions {
     option!(set_slp_vectorization(enable: bool) -> LLVMPassBuilderOptionsSetSLPVectorization(enable as i32));
     option!(set_verify_each(enable: bool) -> LLVMPassBuilderOptionsSetVerifyEach(enable as i32));
 }
+
+macro_rules! pipeline {
+    ($name: ident) => {
+        #[derive(Debug, Clone, Default)]
+        pub struct $name(Vec<String>);
+
+        impl $name {
+            pub fn new() -> Self {
+                Self(Vec::new())
+            }
+
+            fn pass(mut self, pass: impl Into<String>) -> Self {
+                self.0.push(pass.into());
+                self
+            }
+
+            pub fn is_empty(&self) -> bool {
+                self.0.is_empty()
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", self.0.join(","))
+            }
+        }
+    };
+}
+
+pipeline!(PassPipeline);
+pipeline!(FunctionPipeline);
+
+impl PassPipeline {
+    pub fn default_pipeline(self, opt_level: OptimizationLevel) -> Self {
+        let level = match opt_level {
+            OptimizationLevel::None => "O0",
+            OptimizationLevel::Less => "O1",
+            OptimizationLevel::Default => "O2",
+            OptimizationLevel::Aggressive => "O3",
+        };
+
+        self.pass(format!("default<{level}>"))
+    }
+
+    pub fn function(self, pipeline: FunctionPipeline) -> Self {
+        self.pass(format!("function({pipeline})"))
+    }
+
+    pass!(verify -> "verify");
+    pass!(inline -> "inline");
+}
+
+impl FunctionPipeline {
+    pass!(verify -> "verify");
+    pass!(instcombine -> "instcombine");
+    pass!(mem2reg -> "mem2reg");
+    pass!(gvn -> "gvn");
+    pass!(loop_unroll -> "loop-unroll");
+    pass!(simplify_cfg -> "simplifycfg");
+    pass!(sroa -> "sroa");
+    pass!(dce -> "dce");
+}
+
+impl From<OptimizationLevel> for PassPipeline {
+    fn from(opt_level: OptimizationLevel) -> Self {
+        Self::new().default_pipeline(opt_level)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        context::Context,
+        target::{OptimizationLevel, Target, TargetMachine, X86},
+    };
+
+    use super::{FunctionPipeline, PassManagerOptions, PassPipeline};
+
+    const LEVELS: [(OptimizationLevel, &str); 4] = [
+        (OptimizationLevel::None, "default<O0>"),
+        (OptimizationLevel::Less, "default<O1>"),
+        (OptimizationLevel::Default, "default<O2>"),
+        (OptimizationLevel::Aggressive, "default<O3>"),
+    ];
+
+    #[test]
+    fn test_render_pipelines() {
+        for (level, expected) in LEVELS {
+            assert_eq!(PassPipeline::from(level).to_string(), expected);
+            assert_eq!(
+                PassPipeline::from(level).verify().to_string(),
+                format!("{expected},verify")
+            );
+        }
+
+        let function = FunctionPipeline::new().mem2reg().instcombine().gvn().loop_unroll().verify();
+        assert_eq!(function.to_string(), "mem2reg,instcombine,gvn,loop-unroll,verify");
+
+        let pipeline = PassPipeline::new().inline().function(function).verify();
+        assert_eq!(
+            pipeline.to_string(),
+            "inline,function(mem2reg,instcombine,gvn,loop-unroll,verify),verify"
+        );
+        assert!(PassPipeline::new().is_empty());
+        assert!(!pipeline.is_empty());
+    }
+
+    #[test]
+    fn test_run_pipelines() {
+        let context = Context::create();
+        let module = context.module("test");
+
+        let int_32 = context.int::<32>();
+        let function = module.add_function("zero", context.function(&[], int_32.into()));
+        let builder = context.builder();
+        builder.position_at_end(&function.append_basic_block("entry"));
+        builder.ret(int_32.constant(0));
+
+        for (level, _) in LEVELS {
+            let target = Target::<X86>::initialize().unwrap();
+            let machine =
+                TargetMachine::from_host(target, level, Default::default(), Default::default());
+            let pipeline = PassPipeline::from(level).verify();
+
+            module.run_pipeline(&pipeline, &machine, PassManagerOptions::create()).unwrap();
+        }
+    }
+}
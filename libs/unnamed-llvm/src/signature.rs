@@ -0,0 +1,247 @@
+use std::marker::PhantomData;
+
+use crate::{
+    context::Context,
+    types::{function::FunctionType, Type},
+    values::{
+        float::FloatValue, function::FunctionValue, integer::IntValue, pointer::PointerValue, Value,
+    },
+};
+
+pub trait StaticType {
+    type Value<'ctx>;
+
+    fn static_type(context: &Context) -> Type<'_>;
+    fn from_value(value: Value<'_>) -> Self::Value<'_>;
+}
+
+macro_rules! impl_int_static_type {
+    ($($ty: ty => $width: literal),* $(,)?) => {
+        $(
+            impl StaticType for $ty {
+                type Value<'ctx> = IntValue<'ctx, $width>;
+
+                fn static_type(context: &Context) -> Type<'_> {
+                    context.int::<$width>().into()
+                }
+
+                fn from_value(value: Value<'_>) -> Self::Value<'_> {
+                    value.into()
+                }
+            }
+        )*
+    };
+}
+
+impl_int_static_type!(
+    bool => 1,
+    i8 => 8,
+    u8 => 8,
+    i16 => 16,
+    u16 => 16,
+    i32 => 32,
+    u32 => 32,
+    i64 => 64,
+    u64 => 64,
+    i128 => 128,
+    u128 => 128,
+);
+
+macro_rules! impl_float_static_type {
+    ($($ty: ident),* $(,)?) => {
+        $(
+            impl StaticType for $ty {
+                type Value<'ctx> = FloatValue<'ctx>;
+
+                fn static_type(context: &Context) -> Type<'_> {
+                    context.$ty().into()
+                }
+
+                fn from_value(value: Value<'_>) -> Self::Value<'_> {
+                    value.into()
+                }
+            }
+        )*
+    };
+}
+
+impl_float_static_type!(f32, f64);
+
+impl<T> StaticType for *const T {
+    type Value<'ctx> = PointerValue<'ctx>;
+
+    fn static_type(context: &Context) -> Type<'_> {
+        context.pointer(0).into()
+    }
+
+    fn from_value(value: Value<'_>) -> Self::Value<'_> {
+        value.into()
+    }
+}
+
+impl<T> StaticType for *mut T {
+    type Value<'ctx> = PointerValue<'ctx>;
+
+    fn static_type(context: &Context) -> Type<'_> {
+        context.pointer(0).into()
+    }
+
+    fn from_value(value: Value<'_>) -> Self::Value<'_> {
+        value.into()
+    }
+}
+
+impl StaticType for () {
+    type Value<'ctx> = ();
+
+    fn static_type(context: &Context) -> Type<'_> {
+        context.void().into()
+    }
+
+    fn from_value(_: Value<'_>) -> Self::Value<'_> {}
+}
+
+pub trait Params {
+    type Values<'ctx>;
+    type ExternFn<R>;
+
+    fn static_types(context: &Context) -> Vec<Type<'_>>;
+    fn into_values(values: Self::Values<'_>) -> Vec<Value<'_>>;
+}
+
+pub trait ParamAt<const IDX: u32> {
+    type Param: StaticType;
+}
+
+macro_rules! impl_param_at {
+    (($($ty: ident),*) $param: ident: $idx: literal $(, $rest: ident: $rest_idx: literal)*) => {
+        impl<$($ty: StaticType),*> ParamAt<$idx> for ($($ty,)*) {
+            type Param = $param;
+        }
+
+        impl_param_at!(($($ty),*) $($rest: $rest_idx),*);
+    };
+    (($($ty: ident),*)) => {};
+}
+
+macro_rules! impl_params {
+    ($(($($ty: ident: $idx: literal),*)),* $(,)?) => {
+        $(
+            impl<$($ty: StaticType),*> Params for ($($ty,)*)
+            where
+                $(for<'ctx> $ty::Value<'ctx>: Into<Value<'ctx>>,)*
+            {
+                type Values<'ctx> = ($($ty::Value<'ctx>,)*);
+                type ExternFn<R> = extern "C" fn($($ty),*) -> R;
+
+                #[allow(unused_variables)]
+                fn static_types(context: &Context) -> Vec<Type<'_>> {
+                    vec![$($ty::static_type(context)),*]
+                }
+
+                #[allow(non_snake_case)]
+                fn into_values(values: Self::Values<'_>) -> Vec<Value<'_>> {
+                    let ($($ty,)*) = values;
+
+                    vec![$($ty.into()),*]
+                }
+            }
+
+            impl_param_at!(($($ty),*) $($ty: $idx),*);
+        )*
+    };
+}
+
+impl_params!(
+    (),
+    (A: 0),
+    (A: 0, B: 1),
+    (A: 0, B: 1, C: 2),
+    (A: 0, B: 1, C: 2, D: 3),
+    (A: 0, B: 1, C: 2, D: 3, E: 4),
+    (A: 0, B: 1, C: 2, D: 3, E: 4, F: 5),
+    (A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6),
+    (A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7),
+);
+
+pub type ExternFn<P, R> = <P as Params>::ExternFn<R>;
+
+#[derive(Debug)]
+pub struct TypedFunctionType<'ctx, P, R> {
+    inner: FunctionType<'ctx>,
+    _signature: PhantomData<fn(P) -> R>,
+}
+
+impl<'ctx, P: Params, R: StaticType> TypedFunctionType<'ctx, P, R> {
+    pub(crate) fn new(inner: FunctionType<'ctx>) -> Self {
+        Self { inner, _signature: PhantomData }
+    }
+
+    pub fn as_function_type(&self) -> FunctionType<'ctx> {
+        self.inner
+    }
+}
+
+impl<'ctx, P, R> Clone for TypedFunctionType<'ctx, P, R> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'ctx, P, R> Copy for TypedFunctionType<'ctx, P, R> {}
+
+#[derive(Debug)]
+pub struct TypedFunctionValue<'ctx, P, R> {
+    inner: FunctionValue<'ctx>,
+    _signature: PhantomData<fn(P) -> R>,
+}
+
+impl<'ctx, P: Params, R: StaticType> TypedFunctionValue<'ctx, P, R> {
+    pub(crate) fn new(inner: FunctionValue<'ctx>) -> Self {
+        Self { inner, _signature: PhantomData }
+    }
+
+    pub fn as_function(&self) -> &FunctionValue<'ctx> {
+        &self.inner
+    }
+
+    pub fn function_type(&self) -> TypedFunctionType<'ctx, P, R> {
+        TypedFunctionType::new(self.inner.function_type())
+    }
+
+    pub fn param<const IDX: u32>(&self) -> <P::Param as StaticType>::Value<'ctx>
+    where
+        P: ParamAt<IDX>,
+    {
+        P::Param::from_value(self.inner.param(IDX))
+    }
+}
+
+impl<'ctx, P, R> Clone for TypedFunctionValue<'ctx, P, R> {
+    fn clone(&self) -> Self {
+        Self { inner: self.inner.clone(), _signature: PhantomData }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{context::Context, values::integer::IntegerValue};
+
+    #[test]
+    fn test_typed_call_widths() {
+        let context = Context::create();
+        let module = context.module("test");
+        let builder = context.builder();
+
+        let callee = module.add_typed_function("callee", context.fn_type::<(i32, u8), i64>());
+        let caller = module.add_typed_function("caller", context.fn_type::<(i32, bool), i64>());
+        builder.position_at_end(&caller.as_function().append_basic_block("entry"));
+
+        let byte = context.int::<8>().constant(1).with_width::<8>().unwrap();
+        let result = builder.typed_call(&callee, (caller.param::<0>(), byte), "result");
+        builder.ret(IntegerValue::from(result));
+
+        assert!(context.int::<32>().constant(0).with_width::<64>().is_none());
+        module.verify().unwrap();
+    }
+}
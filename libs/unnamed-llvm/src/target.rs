@@ -1,16 +1,44 @@
-use std::marker::PhantomData;
+use std::{
+    ffi::{CStr, CString},
+    marker::PhantomData,
+    path::Path,
+    ptr, slice,
+};
 
 use llvm_sys::{
-    target::{LLVMInitializeX86Target, LLVMInitializeX86TargetInfo, LLVMInitializeX86TargetMC},
+    core::{LLVMDisposeMemoryBuffer, LLVMDisposeMessage, LLVMGetBufferSize, LLVMGetBufferStart},
+    target::{
+        LLVMABIAlignmentOfType, LLVMABISizeOfType, LLVMByteOrder, LLVMByteOrdering,
+        LLVMCopyStringRepOfTargetData, LLVMCreateTargetData, LLVMDisposeTargetData,
+        LLVMInitializeAArch64AsmPrinter, LLVMInitializeAArch64Target,
+        LLVMInitializeAArch64TargetInfo, LLVMInitializeAArch64TargetMC,
+        LLVMInitializeARMAsmPrinter, LLVMInitializeARMTarget, LLVMInitializeARMTargetInfo,
+        LLVMInitializeARMTargetMC, LLVMInitializeRISCVAsmPrinter, LLVMInitializeRISCVTarget,
+        LLVMInitializeRISCVTargetInfo, LLVMInitializeRISCVTargetMC,
+        LLVMInitializeWebAssemblyAsmPrinter, LLVMInitializeWebAssemblyTarget,
+        LLVMInitializeWebAssemblyTargetInfo, LLVMInitializeWebAssemblyTargetMC,
+        LLVMInitializeX86AsmPrinter, LLVMInitializeX86Target, LLVMInitializeX86TargetInfo,
+        LLVMInitializeX86TargetMC, LLVMOffsetOfElement, LLVMPointerSizeForAS,
+        LLVMPreferredAlignmentOfType, LLVMSizeOfTypeInBits, LLVMStoreSizeOfType, LLVMTargetDataRef,
+    },
     target_machine::{
-        LLVMCodeGenOptLevel, LLVMCodeModel, LLVMCreateTargetMachine, LLVMGetFirstTarget,
-        LLVMRelocMode, LLVMTargetMachineRef, LLVMTargetRef,
+        LLVMCodeGenFileType, LLVMCodeGenOptLevel, LLVMCodeModel, LLVMCreateTargetDataLayout,
+        LLVMCreateTargetMachine, LLVMGetTargetFromName, LLVMGetTargetFromTriple,
+        LLVMGetTargetMachineTriple, LLVMGetTargetName, LLVMRelocMode, LLVMTargetMachineEmitToFile,
+        LLVMTargetMachineEmitToMemoryBuffer, LLVMTargetMachineRef, LLVMTargetRef,
     },
 };
 
-use crate::{impl_as_raw, AsRaw};
+use crate::{
+    error::TargetError,
+    impl_as_raw,
+    module::Module,
+    to_c_str,
+    types::{structure::StructType, Type},
+    AsRaw,
+};
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum OptimizationLevel {
     None,
     Less,
@@ -97,15 +125,36 @@ impl From<RelocMode> for LLVMRelocMode {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileType {
+    Assembly,
+    Object,
+}
+
+impl From<FileType> for LLVMCodeGenFileType {
+    fn from(value: FileType) -> Self {
+        match value {
+            FileType::Assembly => Self::LLVMAssemblyFile,
+            FileType::Object => Self::LLVMObjectFile,
+        }
+    }
+}
+
 pub trait InitTarget {
+    const NAME: &'static str;
+    const NAMES: &'static [&'static str];
+
     fn init();
 }
 
 macro_rules! create_target {
-    ($name: ident : $($stage: ident),* $(,)? ) => {
+    ($name: ident($target_name: literal $(, $alias: literal)*) : $($stage: ident),* $(,)? ) => {
         #[derive(Default)]
         pub struct $name;
         impl InitTarget for $name {
+            const NAME: &'static str = $target_name;
+            const NAMES: &'static [&'static str] = &[$target_name $(, $alias)*];
+
             fn init() {
                 unsafe {
                     $($stage());*
@@ -115,14 +164,62 @@ macro_rules! create_target {
     };
 }
 
-create_target! { X86: LLVMInitializeX86Target, LLVMInitializeX86TargetInfo, LLVMInitializeX86TargetMC }
+create_target! { X86("x86-64", "x86"): LLVMInitializeX86Target, LLVMInitializeX86TargetInfo, LLVMInitializeX86TargetMC, LLVMInitializeX86AsmPrinter }
+create_target! { AArch64("aarch64", "aarch64_be", "aarch64_32", "arm64", "arm64_32"): LLVMInitializeAArch64Target, LLVMInitializeAArch64TargetInfo, LLVMInitializeAArch64TargetMC, LLVMInitializeAArch64AsmPrinter }
+create_target! { RISCV("riscv64", "riscv32"): LLVMInitializeRISCVTarget, LLVMInitializeRISCVTargetInfo, LLVMInitializeRISCVTargetMC, LLVMInitializeRISCVAsmPrinter }
+create_target! { ARM("arm", "armeb", "thumb", "thumbeb"): LLVMInitializeARMTarget, LLVMInitializeARMTargetInfo, LLVMInitializeARMTargetMC, LLVMInitializeARMAsmPrinter }
+create_target! { WebAssembly("wasm32", "wasm64"): LLVMInitializeWebAssemblyTarget, LLVMInitializeWebAssemblyTargetInfo, LLVMInitializeWebAssemblyTargetMC, LLVMInitializeWebAssemblyAsmPrinter }
 
 pub struct Target<I: InitTarget>(LLVMTargetRef, PhantomData<I>);
 
 impl<I: InitTarget> Target<I> {
-    pub fn initialize() -> Self {
+    pub fn initialize() -> Result<Self, TargetError> {
         I::init();
-        unsafe { Self(LLVMGetFirstTarget(), Default::default()) }
+
+        let name = to_c_str(I::NAME);
+        let target_ref = unsafe { LLVMGetTargetFromName(name.as_ptr()) };
+        if target_ref.is_null() {
+            return Err(TargetError::NotRegistered { name: I::NAME });
+        }
+
+        Ok(Self(target_ref, Default::default()))
+    }
+
+    pub fn from_triple(triple: &str) -> Result<Self, TargetError> {
+        I::init();
+
+        let c_triple = to_c_str(triple);
+        let mut target_ref = ptr::null_mut();
+        let mut message = ptr::null_mut();
+
+        unsafe {
+            if LLVMGetTargetFromTriple(c_triple.as_ptr(), &mut target_ref, &mut message) != 0 {
+                let error = TargetError::InvalidTriple {
+                    triple: triple.to_owned(),
+                    message: CStr::from_ptr(message).to_string_lossy().into_owned(),
+                };
+                LLVMDisposeMessage(message);
+
+                return Err(error);
+            }
+        }
+
+        let target = Self(target_ref, Default::default());
+
+        let found = target.name().to_string_lossy();
+        if !I::NAMES.contains(&found.as_ref()) {
+            return Err(TargetError::Mismatch {
+                triple: triple.to_owned(),
+                expected: I::NAME,
+                found: found.into_owned(),
+            });
+        }
+
+        Ok(target)
+    }
+
+    pub fn name(&self) -> &CStr {
+        unsafe { CStr::from_ptr(LLVMGetTargetName(self.0)) }
     }
 }
 
@@ -159,10 +256,189 @@ impl TargetMachine {
             ))
         }
     }
+
+    pub fn new<I: InitTarget>(
+        target: Target<I>,
+        triple: &str,
+        cpu: &str,
+        features: &str,
+        opt_level: OptimizationLevel,
+        reloc_mode: RelocMode,
+        code_model: CodeModel,
+    ) -> Self {
+        let triple = to_c_str(triple);
+        let cpu = to_c_str(cpu);
+        let features = to_c_str(features);
+
+        unsafe {
+            Self(LLVMCreateTargetMachine(
+                target.0,
+                triple.as_ptr(),
+                cpu.as_ptr(),
+                features.as_ptr(),
+                opt_level.into(),
+                reloc_mode.into(),
+                code_model.into(),
+            ))
+        }
+    }
+
+    pub fn data_layout(&self) -> TargetData {
+        TargetData(unsafe { LLVMCreateTargetDataLayout(self.0) })
+    }
+
+    pub fn triple(&self) -> CString {
+        unsafe {
+            let ptr = LLVMGetTargetMachineTriple(self.0);
+            let triple = CStr::from_ptr(ptr).to_owned();
+            LLVMDisposeMessage(ptr);
+
+            triple
+        }
+    }
+
+    pub fn emit_to_file(
+        &self,
+        module: &Module,
+        path: &Path,
+        file_type: FileType,
+    ) -> Result<(), TargetError> {
+        let path = to_c_str(&path.to_string_lossy());
+        let mut message = ptr::null_mut();
+
+        unsafe {
+            if LLVMTargetMachineEmitToFile(
+                self.0,
+                module.as_raw(),
+                path.as_ptr(),
+                file_type.into(),
+                &mut message,
+            ) != 0
+            {
+                return Err(emit_error(message));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn emit_to_memory(
+        &self,
+        module: &Module,
+        file_type: FileType,
+    ) -> Result<Vec<u8>, TargetError> {
+        let mut message = ptr::null_mut();
+        let mut buffer = ptr::null_mut();
+
+        unsafe {
+            if LLVMTargetMachineEmitToMemoryBuffer(
+                self.0,
+                module.as_raw(),
+                file_type.into(),
+                &mut message,
+                &mut buffer,
+            ) != 0
+            {
+                return Err(emit_error(message));
+            }
+
+            let start = LLVMGetBufferStart(buffer).cast::<u8>();
+            let bytes = slice::from_raw_parts(start, LLVMGetBufferSize(buffer)).to_vec();
+            LLVMDisposeMemoryBuffer(buffer);
+
+            Ok(bytes)
+        }
+    }
+}
+
+unsafe fn emit_error(message: *mut std::ffi::c_char) -> TargetError {
+    let error =
+        TargetError::EmitFailed { message: CStr::from_ptr(message).to_string_lossy().into_owned() };
+    LLVMDisposeMessage(message);
+
+    error
 }
 
 impl_as_raw!(TargetMachine.0 -> LLVMTargetMachineRef);
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ByteOrder {
+    BigEndian,
+    LittleEndian,
+}
+
+impl From<LLVMByteOrdering> for ByteOrder {
+    fn from(value: LLVMByteOrdering) -> Self {
+        match value {
+            LLVMByteOrdering::LLVMBigEndian => Self::BigEndian,
+            LLVMByteOrdering::LLVMLittleEndian => Self::LittleEndian,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct TargetData(LLVMTargetDataRef);
+
+impl TargetData {
+    pub fn create(layout: &str) -> Self {
+        let layout = to_c_str(layout);
+
+        Self(unsafe { LLVMCreateTargetData(layout.as_ptr()) })
+    }
+
+    pub fn byte_order(&self) -> ByteOrder {
+        unsafe { LLVMByteOrder(self.0).into() }
+    }
+
+    pub fn pointer_size(&self, address_space: u32) -> u32 {
+        unsafe { LLVMPointerSizeForAS(self.0, address_space) }
+    }
+
+    pub fn size_in_bits(&self, ty: Type) -> u64 {
+        unsafe { LLVMSizeOfTypeInBits(self.0, ty.as_raw()) }
+    }
+
+    pub fn store_size(&self, ty: Type) -> u64 {
+        unsafe { LLVMStoreSizeOfType(self.0, ty.as_raw()) }
+    }
+
+    pub fn abi_size(&self, ty: Type) -> u64 {
+        unsafe { LLVMABISizeOfType(self.0, ty.as_raw()) }
+    }
+
+    pub fn abi_alignment(&self, ty: Type) -> u32 {
+        unsafe { LLVMABIAlignmentOfType(self.0, ty.as_raw()) }
+    }
+
+    pub fn preferred_alignment(&self, ty: Type) -> u32 {
+        unsafe { LLVMPreferredAlignmentOfType(self.0, ty.as_raw()) }
+    }
+
+    pub fn offset_of_field(&self, ty: StructType, idx: u32) -> u64 {
+        assert!(idx < ty.field_count(), "idx >= field.count");
+
+        unsafe { LLVMOffsetOfElement(self.0, ty.as_raw(), idx) }
+    }
+
+    pub fn to_string_rep(&self) -> CString {
+        unsafe {
+            let ptr = LLVMCopyStringRepOfTargetData(self.0);
+            let layout = CStr::from_ptr(ptr).to_owned();
+            LLVMDisposeMessage(ptr);
+
+            layout
+        }
+    }
+}
+
+impl Drop for TargetData {
+    fn drop(&mut self) {
+        unsafe { LLVMDisposeTargetData(self.0) }
+    }
+}
+
+impl_as_raw!(TargetData.0 -> LLVMTargetDataRef);
+
 pub mod host {
     use std::ffi::CStr;
 
@@ -191,3 +467,53 @@ pub mod host {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{context::Context, error::TargetError};
+
+    use super::{
+        AArch64, CodeModel, FileType, OptimizationLevel, RelocMode, Target, TargetMachine, RISCV,
+    };
+
+    #[test]
+    fn test_emit_cross_triple_object() {
+        let triple = "aarch64-unknown-linux-gnu";
+        let target = Target::<AArch64>::from_triple(triple).unwrap();
+        let machine = TargetMachine::new(
+            target,
+            triple,
+            "generic",
+            "",
+            OptimizationLevel::None,
+            RelocMode::Default,
+            CodeModel::Default,
+        );
+
+        let context = Context::create();
+        let module = context.module("test");
+        module.set_triple(triple);
+        module.set_data_layout(&machine.data_layout());
+
+        let int_32 = context.int::<32>();
+        let function = module.add_function("zero", context.function(&[], int_32.into()));
+        let builder = context.builder();
+        builder.position_at_end(&function.append_basic_block("entry"));
+        builder.ret(int_32.constant(0));
+
+        let object = machine.emit_to_memory(&module, FileType::Object).unwrap();
+
+        assert_eq!(&object[..4], b"\x7fELF");
+        assert_eq!(u16::from_le_bytes([object[18], object[19]]), 183);
+    }
+
+    #[test]
+    fn test_from_triple_mismatch() {
+        Target::<AArch64>::initialize().unwrap();
+
+        assert!(matches!(
+            Target::<RISCV>::from_triple("aarch64-unknown-linux-gnu"),
+            Err(TargetError::Mismatch { expected: "riscv64", .. })
+        ));
+    }
+}
@@ -0,0 +1,35 @@
+use llvm_sys::{
+    core::{LLVMGetArrayLength, LLVMGetElementType},
+    prelude::LLVMTypeRef,
+};
+
+use crate::{
+    impl_as_raw, impl_const_values, impl_get_context, impl_type_downcast,
+    values::array::ArrayValue, AsRaw,
+};
+
+use super::{BaseType, Type};
+
+#[derive(Debug, Clone, Copy)]
+pub struct ArrayType<'ctx>(pub(crate) BaseType<'ctx>);
+
+impl<'ctx> ArrayType<'ctx> {
+    pub fn len(&self) -> u32 {
+        unsafe { LLVMGetArrayLength(self.as_raw()) }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn element_type(&self) -> Type<'ctx> {
+        let ty_ref = unsafe { LLVMGetElementType(self.as_raw()) };
+
+        Type::from_base_type(BaseType::new(self.0.get_context(), ty_ref))
+    }
+}
+
+impl_as_raw!(@downcast ArrayType<'ctx>.0 -> LLVMTypeRef);
+impl_get_context!(ArrayType<'ctx>.0);
+impl_type_downcast!(ArrayType<'ctx> -> Type::Array);
+impl_const_values!(ArrayType<'ctx>.0 -> ArrayValue);
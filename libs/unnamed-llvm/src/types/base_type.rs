@@ -15,6 +15,10 @@ impl<'ctx> BaseType<'ctx> {
         Self { context, inner }
     }
 
+    pub(crate) fn get_context(&self) -> &'ctx Context {
+        self.context
+    }
+
     pub(crate) fn kind(&self) -> TypeKind {
         unsafe { LLVMGetTypeKind(self.as_raw()).into() }
     }
@@ -1,33 +1,36 @@
 use llvm_sys::{core::LLVMConstReal, prelude::LLVMTypeRef};
 
 use crate::{
-    impl_as_raw, impl_get_context, impl_type_downcast,
+    impl_as_raw, impl_const_values, impl_get_context, impl_type_downcast,
     values::{base_value::BaseValue, float::FloatValue},
-    AsRaw, GetContext,
+    AsRaw,
 };
 
 use super::BaseType;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FloatKind {
     Half,
+    BFloat,
     Float,
     Double,
+    Fp128,
 }
 
 impl FloatKind {
     pub fn size(&self) -> u32 {
         match self {
-            FloatKind::Half => 16,
+            FloatKind::Half | FloatKind::BFloat => 16,
             FloatKind::Float => 32,
             FloatKind::Double => 64,
+            FloatKind::Fp128 => 128,
         }
     }
 }
 
 #[derive(Debug, Clone, Copy)]
 pub struct FloatType<'ctx> {
-    base_type: BaseType<'ctx>,
+    pub(crate) base_type: BaseType<'ctx>,
     pub(crate) kind: FloatKind,
 }
 
@@ -40,13 +43,14 @@ impl<'ctx> FloatType<'ctx> {
         self.kind.size()
     }
 
-    pub fn constant(&self, value: f64) -> FloatValue {
+    pub fn constant(&self, value: f64) -> FloatValue<'ctx> {
         let float_ref = unsafe { LLVMConstReal(self.as_raw(), value) };
 
-        FloatValue::new(BaseValue::new(self.get_context(), float_ref), self.kind)
+        FloatValue::new(BaseValue::new(self.base_type.get_context(), float_ref), self.kind)
     }
 }
 
 impl_as_raw!(@downcast FloatType<'ctx>.base_type -> LLVMTypeRef);
 impl_get_context!(FloatType<'ctx>.base_type);
 impl_type_downcast!(FloatType<'ctx> -> Type::Float);
+impl_const_values!(FloatType<'ctx>.base_type -> FloatValue);
@@ -1,12 +1,15 @@
 use llvm_sys::{
-    core::{LLVMConstInt, LLVMGetIntTypeWidth},
+    core::{
+        LLVMConstInt, LLVMConstIntOfArbitraryPrecision, LLVMConstIntOfStringAndSize,
+        LLVMGetIntTypeWidth,
+    },
     prelude::LLVMTypeRef,
 };
 
 use crate::{
-    impl_as_raw, impl_get_context, impl_type_downcast,
+    impl_as_raw, impl_const_values, impl_get_context, impl_type_downcast,
     values::{base_value::BaseValue, integer::IntegerValue},
-    AsRaw, GetContext,
+    AsRaw,
 };
 
 use super::BaseType;
@@ -19,13 +22,48 @@ impl<'ctx> IntegerType<'ctx> {
         unsafe { LLVMGetIntTypeWidth(self.as_raw()) }
     }
 
-    pub fn constant(&self, value: u64) -> IntegerValue {
+    pub fn constant(&self, value: u64) -> IntegerValue<'ctx> {
         let int_ref = unsafe { LLVMConstInt(self.as_raw(), value, 0) };
 
-        IntegerValue(BaseValue::new(self.get_context(), int_ref))
+        IntegerValue(BaseValue::new(self.0.get_context(), int_ref))
+    }
+
+    pub fn const_signed(&self, value: i64) -> IntegerValue<'ctx> {
+        let int_ref = unsafe { LLVMConstInt(self.as_raw(), value as u64, 1) };
+
+        IntegerValue(BaseValue::new(self.0.get_context(), int_ref))
+    }
+
+    pub fn const_arbitrary_precision(&self, words: &[u64]) -> IntegerValue<'ctx> {
+        let int_ref = unsafe {
+            LLVMConstIntOfArbitraryPrecision(self.as_raw(), words.len() as u32, words.as_ptr())
+        };
+
+        IntegerValue(BaseValue::new(self.0.get_context(), int_ref))
+    }
+
+    pub fn const_from_str(&self, text: &str, radix: u8) -> Option<IntegerValue<'ctx>> {
+        assert!(matches!(radix, 2 | 8 | 10 | 16 | 36), "unsupported radix {radix}");
+
+        let digits = text.strip_prefix(['-', '+']).unwrap_or(text);
+        if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix as u32)) {
+            return None;
+        }
+
+        let int_ref = unsafe {
+            LLVMConstIntOfStringAndSize(
+                self.as_raw(),
+                text.as_ptr().cast(),
+                text.len() as u32,
+                radix,
+            )
+        };
+
+        Some(IntegerValue(BaseValue::new(self.0.get_context(), int_ref)))
     }
 }
 
 impl_as_raw!(@downcast IntegerType<'ctx>.0 -> LLVMTypeRef);
 impl_get_context!(IntegerType<'ctx>.0);
 impl_type_downcast!(IntegerType<'ctx> -> Type::Integer);
+impl_const_values!(IntegerType<'ctx>.0 -> IntegerValue);
@@ -1,21 +1,37 @@
+pub mod array;
 pub(crate) mod base_type;
 pub mod float;
 pub mod function;
 pub mod integer;
 pub mod pointer;
+pub mod structure;
+pub mod vector;
+pub mod void;
 
 use std::ffi::CStr;
 
-use llvm_sys::{core::LLVMPrintTypeToString, prelude::LLVMTypeRef, LLVMTypeKind};
+use llvm_sys::{
+    core::{LLVMConstNull, LLVMGetPoison, LLVMGetTypeKind, LLVMGetUndef, LLVMPrintTypeToString},
+    prelude::LLVMTypeRef,
+    LLVMTypeKind,
+};
 
-use crate::{context::Context, AsRaw, GetContext};
+use crate::{
+    context::Context,
+    values::{base_value::BaseValue, Value},
+    AsRaw, GetContext,
+};
 
 use self::{
+    array::ArrayType,
     base_type::BaseType,
     float::{FloatKind, FloatType},
     function::FunctionType,
     integer::IntegerType,
     pointer::PointerType,
+    structure::StructType,
+    vector::VectorType,
+    void::VoidType,
 };
 
 #[macro_export]
@@ -42,7 +58,44 @@ macro_rules! unwrap_type {
     };
 }
 
-#[derive(Debug, Clone, Copy)]
+#[macro_export]
+macro_rules! impl_const_values {
+    ($ty: ident<$lt: lifetime>.$field: tt -> $value: ident) => {
+        impl<$lt> $ty<$lt> {
+            pub fn const_null(&self) -> $value<$lt> {
+                let value_ref = unsafe { llvm_sys::core::LLVMConstNull(self.as_raw()) };
+
+                $crate::values::Value::from_unknown($crate::values::base_value::BaseValue::new(
+                    self.$field.get_context(),
+                    value_ref,
+                ))
+                .into()
+            }
+
+            pub fn undef(&self) -> $value<$lt> {
+                let value_ref = unsafe { llvm_sys::core::LLVMGetUndef(self.as_raw()) };
+
+                $crate::values::Value::from_unknown($crate::values::base_value::BaseValue::new(
+                    self.$field.get_context(),
+                    value_ref,
+                ))
+                .into()
+            }
+
+            pub fn poison(&self) -> $value<$lt> {
+                let value_ref = unsafe { llvm_sys::core::LLVMGetPoison(self.as_raw()) };
+
+                $crate::values::Value::from_unknown($crate::values::base_value::BaseValue::new(
+                    self.$field.get_context(),
+                    value_ref,
+                ))
+                .into()
+            }
+        }
+    };
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TypeKind {
     Void,
     Half,
@@ -101,26 +154,75 @@ pub enum Type<'ctx> {
     Function(FunctionType<'ctx>),
     Float(FloatType<'ctx>),
     Pointer(PointerType<'ctx>),
+    Struct(StructType<'ctx>),
+    Void(VoidType<'ctx>),
+    Array(ArrayType<'ctx>),
+    Vector(VectorType<'ctx>),
 }
 
 unwrap_type!(Integer(IntegerType));
 unwrap_type!(Function(FunctionType));
 unwrap_type!(Float(FloatType));
 unwrap_type!(Pointer(PointerType));
+unwrap_type!(Struct(StructType));
+unwrap_type!(Void(VoidType));
+unwrap_type!(Array(ArrayType));
+unwrap_type!(Vector(VectorType));
 
 impl<'ctx> Type<'ctx> {
     pub fn print_to_string(&self) -> &CStr {
         unsafe { CStr::from_ptr(LLVMPrintTypeToString(self.as_raw())) }
     }
 
+    pub fn kind(&self) -> TypeKind {
+        unsafe { LLVMGetTypeKind(self.as_raw()).into() }
+    }
+
+    pub fn const_null(&self) -> Value<'ctx> {
+        let value_ref = unsafe { LLVMConstNull(self.as_raw()) };
+
+        Value::from_unknown(BaseValue::new(self.base().get_context(), value_ref))
+    }
+
+    pub fn undef(&self) -> Value<'ctx> {
+        let value_ref = unsafe { LLVMGetUndef(self.as_raw()) };
+
+        Value::from_unknown(BaseValue::new(self.base().get_context(), value_ref))
+    }
+
+    pub fn poison(&self) -> Value<'ctx> {
+        let value_ref = unsafe { LLVMGetPoison(self.as_raw()) };
+
+        Value::from_unknown(BaseValue::new(self.base().get_context(), value_ref))
+    }
+
+    pub(crate) fn base(&self) -> BaseType<'ctx> {
+        match self {
+            Self::Integer(int) => int.0,
+            Self::Function(func) => func.0,
+            Self::Float(float) => float.base_type,
+            Self::Pointer(ptr) => ptr.0,
+            Self::Struct(structure) => structure.0,
+            Self::Void(void) => void.0,
+            Self::Array(array) => array.0,
+            Self::Vector(vector) => vector.0,
+        }
+    }
+
     pub(crate) fn from_base_type(base_type: BaseType<'ctx>) -> Self {
         match base_type.kind() {
             TypeKind::Integer => Self::Integer(IntegerType(base_type)),
             TypeKind::Double => Self::Float(FloatType::new(base_type, FloatKind::Double)),
             TypeKind::Float => Self::Float(FloatType::new(base_type, FloatKind::Float)),
             TypeKind::Half => Self::Float(FloatType::new(base_type, FloatKind::Half)),
+            TypeKind::BFloat => Self::Float(FloatType::new(base_type, FloatKind::BFloat)),
+            TypeKind::Fp128 => Self::Float(FloatType::new(base_type, FloatKind::Fp128)),
             TypeKind::Function => Self::Function(FunctionType(base_type)),
             TypeKind::Pointer => Self::Pointer(PointerType(base_type)),
+            TypeKind::Struct => Self::Struct(StructType(base_type)),
+            TypeKind::Void => Self::Void(VoidType(base_type)),
+            TypeKind::Array => Self::Array(ArrayType(base_type)),
+            TypeKind::Vector => Self::Vector(VectorType(base_type)),
             ty => panic!("unexpected {:?}", ty),
         }
     }
@@ -135,6 +237,10 @@ impl<'ctx> AsRaw for Type<'ctx> {
             Self::Function(func) => func.as_raw(),
             Self::Float(float) => float.as_raw(),
             Self::Pointer(ptr) => ptr.as_raw(),
+            Self::Struct(structure) => structure.as_raw(),
+            Self::Void(void) => void.as_raw(),
+            Self::Array(array) => array.as_raw(),
+            Self::Vector(vector) => vector.as_raw(),
         }
     }
 }
@@ -146,6 +252,10 @@ impl<'ctx> GetContext for Type<'ctx> {
             Type::Function(ty) => ty.get_context(),
             Type::Float(ty) => ty.get_context(),
             Type::Pointer(ty) => ty.get_context(),
+            Type::Struct(ty) => ty.get_context(),
+            Type::Void(ty) => ty.get_context(),
+            Type::Array(ty) => ty.get_context(),
+            Type::Vector(ty) => ty.get_context(),
         }
     }
 }
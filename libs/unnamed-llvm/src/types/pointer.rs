@@ -1,12 +1,22 @@
-use llvm_sys::prelude::LLVMTypeRef;
+use llvm_sys::{core::LLVMGetPointerAddressSpace, prelude::LLVMTypeRef};
 
-use crate::{impl_as_raw, impl_get_context, impl_type_downcast};
+use crate::{
+    impl_as_raw, impl_const_values, impl_get_context, impl_type_downcast,
+    values::pointer::PointerValue, AsRaw,
+};
 
 use super::BaseType;
 
 #[derive(Debug, Clone, Copy)]
 pub struct PointerType<'ctx>(pub(crate) BaseType<'ctx>);
 
+impl<'ctx> PointerType<'ctx> {
+    pub fn address_space(&self) -> u32 {
+        unsafe { LLVMGetPointerAddressSpace(self.as_raw()) }
+    }
+}
+
 impl_as_raw!(@downcast PointerType<'ctx>.0 -> LLVMTypeRef);
 impl_get_context!(PointerType<'ctx>.0);
 impl_type_downcast!(PointerType<'ctx> -> Type::Pointer);
+impl_const_values!(PointerType<'ctx>.0 -> PointerValue);
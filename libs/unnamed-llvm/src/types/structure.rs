@@ -0,0 +1,37 @@
+use llvm_sys::{
+    core::{LLVMCountStructElementTypes, LLVMIsPackedStruct, LLVMStructGetTypeAtIndex},
+    prelude::LLVMTypeRef,
+};
+
+use crate::{
+    impl_as_raw, impl_const_values, impl_get_context, impl_type_downcast,
+    values::structure::StructValue, AsRaw,
+};
+
+use super::{BaseType, Type};
+
+#[derive(Debug, Clone, Copy)]
+pub struct StructType<'ctx>(pub(crate) BaseType<'ctx>);
+
+impl<'ctx> StructType<'ctx> {
+    pub fn is_packed(&self) -> bool {
+        unsafe { LLVMIsPackedStruct(self.as_raw()) != 0 }
+    }
+
+    pub fn field_count(&self) -> u32 {
+        unsafe { LLVMCountStructElementTypes(self.as_raw()) }
+    }
+
+    pub fn field(&self, idx: u32) -> Type<'ctx> {
+        assert!(idx < self.field_count(), "idx >= field.count");
+
+        let ty_ref = unsafe { LLVMStructGetTypeAtIndex(self.as_raw(), idx) };
+
+        Type::from_base_type(BaseType::new(self.0.get_context(), ty_ref))
+    }
+}
+
+impl_as_raw!(@downcast StructType<'ctx>.0 -> LLVMTypeRef);
+impl_get_context!(StructType<'ctx>.0);
+impl_type_downcast!(StructType<'ctx> -> Type::Struct);
+impl_const_values!(StructType<'ctx>.0 -> StructValue);
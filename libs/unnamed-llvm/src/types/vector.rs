@@ -0,0 +1,35 @@
+use llvm_sys::{
+    core::{LLVMGetElementType, LLVMGetVectorSize},
+    prelude::LLVMTypeRef,
+};
+
+use crate::{
+    impl_as_raw, impl_const_values, impl_get_context, impl_type_downcast,
+    values::vector::VectorValue, AsRaw,
+};
+
+use super::{BaseType, Type};
+
+#[derive(Debug, Clone, Copy)]
+pub struct VectorType<'ctx>(pub(crate) BaseType<'ctx>);
+
+impl<'ctx> VectorType<'ctx> {
+    pub fn len(&self) -> u32 {
+        unsafe { LLVMGetVectorSize(self.as_raw()) }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn element_type(&self) -> Type<'ctx> {
+        let ty_ref = unsafe { LLVMGetElementType(self.as_raw()) };
+
+        Type::from_base_type(BaseType::new(self.0.get_context(), ty_ref))
+    }
+}
+
+impl_as_raw!(@downcast VectorType<'ctx>.0 -> LLVMTypeRef);
+impl_get_context!(VectorType<'ctx>.0);
+impl_type_downcast!(VectorType<'ctx> -> Type::Vector);
+impl_const_values!(VectorType<'ctx>.0 -> VectorValue);
@@ -0,0 +1,12 @@
+use llvm_sys::prelude::LLVMTypeRef;
+
+use crate::{impl_as_raw, impl_get_context, impl_type_downcast};
+
+use super::BaseType;
+
+#[derive(Debug, Clone, Copy)]
+pub struct VoidType<'ctx>(pub(crate) BaseType<'ctx>);
+
+impl_as_raw!(@downcast VoidType<'ctx>.0 -> LLVMTypeRef);
+impl_get_context!(VoidType<'ctx>.0);
+impl_type_downcast!(VoidType<'ctx> -> Type::Void);
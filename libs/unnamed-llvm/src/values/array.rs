@@ -0,0 +1,16 @@
+use llvm_sys::prelude::LLVMValueRef;
+
+use crate::{
+    impl_as_raw, impl_get_context, impl_type_of, impl_value_downcast, types::array::ArrayType,
+    AsRaw,
+};
+
+use super::base_value::BaseValue;
+
+#[derive(Debug, Clone)]
+pub struct ArrayValue<'ctx>(pub(crate) BaseValue<'ctx>);
+
+impl_as_raw!(@downcast ArrayValue<'ctx>.0 -> LLVMValueRef);
+impl_get_context!(ArrayValue<'ctx>.0);
+impl_type_of!(ArrayValue<'ctx> -> ArrayType);
+impl_value_downcast!(ArrayValue<'ctx> -> Value::Array);
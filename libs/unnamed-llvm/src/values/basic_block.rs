@@ -1,6 +1,19 @@
-use llvm_sys::prelude::LLVMBasicBlockRef;
+use llvm_sys::{
+    core::{
+        LLVMBlockAddress, LLVMDeleteBasicBlock, LLVMGetBasicBlockParent,
+        LLVMGetBasicBlockTerminator, LLVMGetFirstInstruction, LLVMGetLastInstruction,
+        LLVMGetNextBasicBlock, LLVMGetNextInstruction, LLVMMoveBasicBlockAfter,
+        LLVMMoveBasicBlockBefore, LLVMRemoveBasicBlockFromParent,
+    },
+    prelude::{LLVMBasicBlockRef, LLVMValueRef},
+};
 
-use crate::{context::Context, impl_as_raw, GetContext};
+use crate::{context::Context, impl_as_raw, AsRaw, GetContext};
+
+use super::{
+    base_value::BaseValue, function::FunctionValue, instruction::InstructionValue,
+    pointer::PointerValue,
+};
 
 #[derive(Debug, Clone)]
 pub struct BasicBlock<'ctx> {
@@ -12,6 +25,72 @@ impl<'ctx> BasicBlock<'ctx> {
     pub(crate) fn new(_context: &'ctx Context, inner: LLVMBasicBlockRef) -> Self {
         Self { context: _context, inner }
     }
+
+    pub fn address(&self) -> PointerValue<'ctx> {
+        let address_ref = unsafe {
+            let function_ref = LLVMGetBasicBlockParent(self.as_raw());
+            LLVMBlockAddress(function_ref, self.as_raw())
+        };
+
+        PointerValue(BaseValue::new(self.context, address_ref))
+    }
+
+    pub fn parent(&self) -> FunctionValue<'ctx> {
+        let function_ref = unsafe { LLVMGetBasicBlockParent(self.as_raw()) };
+
+        FunctionValue(BaseValue::new(self.context, function_ref))
+    }
+
+    pub fn first_instruction(&self) -> Option<InstructionValue<'ctx>> {
+        let instruction_ref = unsafe { LLVMGetFirstInstruction(self.as_raw()) };
+
+        InstructionValue::from_raw(self.context, instruction_ref)
+    }
+
+    pub fn last_instruction(&self) -> Option<InstructionValue<'ctx>> {
+        let instruction_ref = unsafe { LLVMGetLastInstruction(self.as_raw()) };
+
+        InstructionValue::from_raw(self.context, instruction_ref)
+    }
+
+    pub fn terminator(&self) -> Option<InstructionValue<'ctx>> {
+        let instruction_ref = unsafe { LLVMGetBasicBlockTerminator(self.as_raw()) };
+
+        InstructionValue::from_raw(self.context, instruction_ref)
+    }
+
+    pub fn move_before(&self, basic_block: &BasicBlock<'ctx>) {
+        unsafe { LLVMMoveBasicBlockBefore(self.as_raw(), basic_block.as_raw()) }
+    }
+
+    pub fn move_after(&self, basic_block: &BasicBlock<'ctx>) {
+        unsafe { LLVMMoveBasicBlockAfter(self.as_raw(), basic_block.as_raw()) }
+    }
+
+    pub fn remove_from_parent(&self) {
+        unsafe { LLVMRemoveBasicBlockFromParent(self.as_raw()) }
+    }
+
+    pub fn delete(self) {
+        unsafe { LLVMDeleteBasicBlock(self.as_raw()) }
+    }
+
+    pub fn instructions(&self) -> InstructionIter<'ctx> {
+        let instruction_ref = unsafe { LLVMGetFirstInstruction(self.as_raw()) };
+
+        InstructionIter { context: self.context, next: instruction_ref }
+    }
+
+    pub(crate) fn from_raw(
+        context: &'ctx Context,
+        basic_block_ref: LLVMBasicBlockRef,
+    ) -> Option<Self> {
+        if basic_block_ref.is_null() {
+            return None;
+        }
+
+        Some(Self::new(context, basic_block_ref))
+    }
 }
 
 impl<'ctx> GetContext for BasicBlock<'ctx> {
@@ -21,3 +100,35 @@ impl<'ctx> GetContext for BasicBlock<'ctx> {
 }
 
 impl_as_raw!(BasicBlock<'ctx>.inner -> LLVMBasicBlockRef);
+
+pub struct InstructionIter<'ctx> {
+    context: &'ctx Context,
+    next: LLVMValueRef,
+}
+
+impl<'ctx> Iterator for InstructionIter<'ctx> {
+    type Item = InstructionValue<'ctx>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = InstructionValue::from_raw(self.context, self.next)?;
+        self.next = unsafe { LLVMGetNextInstruction(self.next) };
+
+        Some(current)
+    }
+}
+
+pub struct BasicBlockIter<'ctx> {
+    pub(crate) context: &'ctx Context,
+    pub(crate) next: LLVMBasicBlockRef,
+}
+
+impl<'ctx> Iterator for BasicBlockIter<'ctx> {
+    type Item = BasicBlock<'ctx>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = BasicBlock::from_raw(self.context, self.next)?;
+        self.next = unsafe { LLVMGetNextBasicBlock(self.next) };
+
+        Some(current)
+    }
+}
@@ -0,0 +1,61 @@
+use llvm_sys::{
+    core::{LLVMIsTailCall, LLVMSetTailCall},
+    prelude::LLVMValueRef,
+};
+
+use crate::{attribute::Attribute, impl_as_raw, impl_get_context, types::Type, AsRaw};
+
+use super::{base_value::BaseValue, instruction::InstructionValue, TypeOf, Value};
+
+#[derive(Debug, Clone)]
+pub struct CallSiteValue<'ctx>(pub(crate) BaseValue<'ctx>);
+
+impl<'ctx> CallSiteValue<'ctx> {
+    pub fn value(&self) -> Value<'ctx> {
+        Value::from_unknown(self.0.clone())
+    }
+
+    pub fn as_instruction(&self) -> InstructionValue<'ctx> {
+        InstructionValue(self.0.clone())
+    }
+
+    pub fn add_attributes(&self, attributes: &[Attribute<'ctx>]) {
+        self.as_instruction().add_attributes(attributes)
+    }
+
+    pub fn add_argument_attributes(&self, idx: u32, attributes: &[Attribute<'ctx>]) {
+        self.as_instruction().add_argument_attributes(idx, attributes)
+    }
+
+    pub fn add_return_attributes(&self, attributes: &[Attribute<'ctx>]) {
+        self.as_instruction().add_return_attributes(attributes)
+    }
+
+    pub fn is_tail_call(&self) -> bool {
+        unsafe { LLVMIsTailCall(self.as_raw()) != 0 }
+    }
+
+    pub fn set_tail_call(&self, tail_call: bool) {
+        unsafe { LLVMSetTailCall(self.as_raw(), tail_call as i32) }
+    }
+}
+
+impl<'ctx> TypeOf for CallSiteValue<'ctx> {
+    type Output<'ty>
+        = Type<'ty>
+    where
+        Self: 'ty;
+
+    fn type_of(&self) -> Self::Output<'_> {
+        Type::from_base_type(self.0.type_of())
+    }
+}
+
+impl<'ctx> From<CallSiteValue<'ctx>> for Value<'ctx> {
+    fn from(call_site: CallSiteValue<'ctx>) -> Self {
+        call_site.value()
+    }
+}
+
+impl_as_raw!(@downcast CallSiteValue<'ctx>.0 -> LLVMValueRef);
+impl_get_context!(CallSiteValue<'ctx>.0);
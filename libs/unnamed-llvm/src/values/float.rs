@@ -1,19 +1,26 @@
-use llvm_sys::{core::LLVMTypeOf, prelude::LLVMValueRef};
+use llvm_sys::{
+    core::{
+        LLVMConstFPCast, LLVMConstFPToSI, LLVMConstFPToUI, LLVMConstRealGetDouble,
+        LLVMIsAConstantFP, LLVMTypeOf,
+    },
+    prelude::LLVMValueRef,
+};
 
 use crate::{
     impl_as_raw, impl_get_context, impl_value_downcast,
     types::{
         base_type::BaseType,
         float::{FloatKind, FloatType},
+        integer::IntegerType,
     },
     AsRaw,
 };
 
-use super::{base_value::BaseValue, TypeOf};
+use super::{base_value::BaseValue, integer::IntegerValue, TypeOf};
 
 #[derive(Debug, Clone)]
 pub struct FloatValue<'ctx> {
-    base_value: BaseValue<'ctx>,
+    pub(crate) base_value: BaseValue<'ctx>,
     pub(crate) kind: FloatKind,
 }
 
@@ -21,10 +28,57 @@ impl<'ctx> FloatValue<'ctx> {
     pub(crate) fn new(base_value: BaseValue<'ctx>, kind: FloatKind) -> Self {
         Self { base_value, kind }
     }
+
+    pub fn is_constant_float(&self) -> bool {
+        unsafe { !LLVMIsAConstantFP(self.as_raw()).is_null() }
+    }
+
+    pub fn as_constant_f64(&self) -> Option<f64> {
+        if !self.is_constant_float() {
+            return None;
+        }
+
+        let mut loses_info = 0;
+
+        Some(unsafe { LLVMConstRealGetDouble(self.as_raw(), &mut loses_info) })
+    }
+
+    pub fn const_float_cast(&self, ty: FloatType<'ctx>) -> Option<FloatValue<'ctx>> {
+        if !self.is_constant_float() || (self.kind != ty.kind && self.kind.size() == ty.size()) {
+            return None;
+        }
+
+        let float_ref = unsafe { LLVMConstFPCast(self.as_raw(), ty.as_raw()) };
+
+        Some(FloatValue::new(BaseValue::new(self.base_value.get_context(), float_ref), ty.kind))
+    }
+
+    pub fn const_to_signed_int(&self, ty: IntegerType<'ctx>) -> Option<IntegerValue<'ctx>> {
+        if !self.is_constant_float() {
+            return None;
+        }
+
+        let int_ref = unsafe { LLVMConstFPToSI(self.as_raw(), ty.as_raw()) };
+
+        Some(IntegerValue(BaseValue::new(self.base_value.get_context(), int_ref)))
+    }
+
+    pub fn const_to_unsigned_int(&self, ty: IntegerType<'ctx>) -> Option<IntegerValue<'ctx>> {
+        if !self.is_constant_float() {
+            return None;
+        }
+
+        let int_ref = unsafe { LLVMConstFPToUI(self.as_raw(), ty.as_raw()) };
+
+        Some(IntegerValue(BaseValue::new(self.base_value.get_context(), int_ref)))
+    }
 }
 
 impl<'ctx> TypeOf for FloatValue<'ctx> {
-    type Output<'ty> = FloatType<'ty> where Self: 'ty;
+    type Output<'ty>
+        = FloatType<'ty>
+    where
+        Self: 'ty;
 
     fn type_of(&self) -> Self::Output<'_> {
         let float_ref = unsafe { LLVMTypeOf(self.as_raw()) };
@@ -36,3 +90,18 @@ impl<'ctx> TypeOf for FloatValue<'ctx> {
 impl_as_raw!(@downcast FloatValue<'ctx>.base_value -> LLVMValueRef);
 impl_get_context!(FloatValue<'ctx>.base_value);
 impl_value_downcast!(FloatValue<'ctx> -> Value::Float);
+
+#[cfg(test)]
+mod tests {
+    use crate::context::Context;
+
+    #[test]
+    fn test_const_float_cast() {
+        let context = Context::create();
+        let half = context.f16().constant(1.5);
+
+        assert_eq!(half.const_float_cast(context.f64()).unwrap().as_constant_f64(), Some(1.5));
+        assert_eq!(half.const_float_cast(context.f16()).unwrap().as_constant_f64(), Some(1.5));
+        assert!(half.const_float_cast(context.bf16()).is_none());
+    }
+}
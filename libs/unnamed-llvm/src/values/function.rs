@@ -1,20 +1,42 @@
+use std::ffi::CStr;
+
 use llvm_sys::{
-    core::{LLVMAppendBasicBlockInContext, LLVMCountParams, LLVMGetParam, LLVMSetLinkage},
+    comdat::{LLVMGetComdat, LLVMSetComdat},
+    core::{
+        LLVMAppendBasicBlockInContext, LLVMCountBasicBlocks, LLVMCountParams, LLVMGetAlignment,
+        LLVMGetDLLStorageClass, LLVMGetEntryBasicBlock, LLVMGetFirstBasicBlock,
+        LLVMGetFunctionCallConv, LLVMGetGC, LLVMGetLinkage, LLVMGetNextFunction, LLVMGetParam,
+        LLVMGetPersonalityFn, LLVMGetSection, LLVMGetVisibility, LLVMGlobalGetValueType,
+        LLVMHasPersonalityFn, LLVMSetAlignment, LLVMSetDLLStorageClass, LLVMSetFunctionCallConv,
+        LLVMSetGC, LLVMSetLinkage, LLVMSetPersonalityFn, LLVMSetSection, LLVMSetVisibility,
+    },
+    debuginfo::LLVMSetSubprogram,
     prelude::LLVMValueRef,
-    LLVMLinkage,
+    LLVMDLLStorageClass, LLVMLinkage, LLVMVisibility,
 };
 
 use crate::{
     attribute::{Attribute, AttributeLocation},
-    extra::LLVMAddFunctionAttributes,
-    impl_as_raw, impl_get_context, impl_type_of, impl_value_downcast, to_c_str,
-    types::function::FunctionType,
+    comdat::Comdat,
+    context::Context,
+    debug_info::DISubprogram,
+    error::Error,
+    extra::{LLVMAddFunctionAttributes, LLVMRustRunFunctionPasses},
+    impl_as_raw, impl_get_context, impl_type_of, impl_value_downcast,
+    pass_manager::FunctionPipeline,
+    target::TargetMachine,
+    to_c_str,
+    types::{base_type::BaseType, function::FunctionType},
     AsRaw, GetContext,
 };
 
-use super::{base_value::BaseValue, basic_block::BasicBlock, Value};
+use super::{
+    base_value::BaseValue,
+    basic_block::{BasicBlock, BasicBlockIter},
+    Value,
+};
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Linkage {
     External,
     AvailableExternally,
@@ -59,13 +81,160 @@ impl From<Linkage> for LLVMLinkage {
     }
 }
 
+impl From<LLVMLinkage> for Linkage {
+    fn from(value: LLVMLinkage) -> Self {
+        match value {
+            LLVMLinkage::LLVMExternalLinkage => Self::External,
+            LLVMLinkage::LLVMAvailableExternallyLinkage => Self::AvailableExternally,
+            LLVMLinkage::LLVMLinkOnceAnyLinkage => Self::LinkOnceAny,
+            LLVMLinkage::LLVMLinkOnceODRLinkage => Self::LinkOnceODR,
+            LLVMLinkage::LLVMLinkOnceODRAutoHideLinkage => Self::LinkOnceODRAutoHide,
+            LLVMLinkage::LLVMWeakAnyLinkage => Self::WeakAny,
+            LLVMLinkage::LLVMWeakODRLinkage => Self::WeakODR,
+            LLVMLinkage::LLVMAppendingLinkage => Self::Appending,
+            LLVMLinkage::LLVMInternalLinkage => Self::Internal,
+            LLVMLinkage::LLVMPrivateLinkage => Self::Private,
+            LLVMLinkage::LLVMDLLImportLinkage => Self::DLLImport,
+            LLVMLinkage::LLVMDLLExportLinkage => Self::DLLExport,
+            LLVMLinkage::LLVMExternalWeakLinkage => Self::ExternalWeak,
+            LLVMLinkage::LLVMGhostLinkage => Self::Ghost,
+            LLVMLinkage::LLVMCommonLinkage => Self::Common,
+            LLVMLinkage::LLVMLinkerPrivateLinkage => Self::LinkerPrivate,
+            LLVMLinkage::LLVMLinkerPrivateWeakLinkage => Self::LinkerPrivateWeak,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CallingConvention {
+    C,
+    Fast,
+    Cold,
+    Ghc,
+    HiPE,
+    AnyReg,
+    PreserveMost,
+    PreserveAll,
+    Swift,
+    X86Stdcall,
+    X86Fastcall,
+    X86VectorCall,
+    X8664SysV,
+    Win64,
+    ArmAapcs,
+    ArmAapcsVfp,
+    Other(u32),
+}
+
+impl From<CallingConvention> for u32 {
+    fn from(value: CallingConvention) -> Self {
+        match value {
+            CallingConvention::C => 0,
+            CallingConvention::Fast => 8,
+            CallingConvention::Cold => 9,
+            CallingConvention::Ghc => 10,
+            CallingConvention::HiPE => 11,
+            CallingConvention::AnyReg => 13,
+            CallingConvention::PreserveMost => 14,
+            CallingConvention::PreserveAll => 15,
+            CallingConvention::Swift => 16,
+            CallingConvention::X86Stdcall => 64,
+            CallingConvention::X86Fastcall => 65,
+            CallingConvention::ArmAapcs => 67,
+            CallingConvention::ArmAapcsVfp => 68,
+            CallingConvention::X8664SysV => 78,
+            CallingConvention::Win64 => 79,
+            CallingConvention::X86VectorCall => 80,
+            CallingConvention::Other(cc) => cc,
+        }
+    }
+}
+
+impl From<u32> for CallingConvention {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Self::C,
+            8 => Self::Fast,
+            9 => Self::Cold,
+            10 => Self::Ghc,
+            11 => Self::HiPE,
+            13 => Self::AnyReg,
+            14 => Self::PreserveMost,
+            15 => Self::PreserveAll,
+            16 => Self::Swift,
+            64 => Self::X86Stdcall,
+            65 => Self::X86Fastcall,
+            67 => Self::ArmAapcs,
+            68 => Self::ArmAapcsVfp,
+            78 => Self::X8664SysV,
+            79 => Self::Win64,
+            80 => Self::X86VectorCall,
+            cc => Self::Other(cc),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Visibility {
+    Default,
+    Hidden,
+    Protected,
+}
+
+impl From<Visibility> for LLVMVisibility {
+    fn from(value: Visibility) -> Self {
+        match value {
+            Visibility::Default => Self::LLVMDefaultVisibility,
+            Visibility::Hidden => Self::LLVMHiddenVisibility,
+            Visibility::Protected => Self::LLVMProtectedVisibility,
+        }
+    }
+}
+
+impl From<LLVMVisibility> for Visibility {
+    fn from(value: LLVMVisibility) -> Self {
+        match value {
+            LLVMVisibility::LLVMDefaultVisibility => Self::Default,
+            LLVMVisibility::LLVMHiddenVisibility => Self::Hidden,
+            LLVMVisibility::LLVMProtectedVisibility => Self::Protected,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DllStorageClass {
+    Default,
+    Import,
+    Export,
+}
+
+impl From<DllStorageClass> for LLVMDLLStorageClass {
+    fn from(value: DllStorageClass) -> Self {
+        match value {
+            DllStorageClass::Default => Self::LLVMDefaultStorageClass,
+            DllStorageClass::Import => Self::LLVMDLLImportStorageClass,
+            DllStorageClass::Export => Self::LLVMDLLExportStorageClass,
+        }
+    }
+}
+
+impl From<LLVMDLLStorageClass> for DllStorageClass {
+    fn from(value: LLVMDLLStorageClass) -> Self {
+        match value {
+            LLVMDLLStorageClass::LLVMDefaultStorageClass => Self::Default,
+            LLVMDLLStorageClass::LLVMDLLImportStorageClass => Self::Import,
+            LLVMDLLStorageClass::LLVMDLLExportStorageClass => Self::Export,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct FunctionValue<'ctx>(pub(crate) BaseValue<'ctx>);
 
 impl<'ctx> FunctionValue<'ctx> {
     fn add_any_attributes(&self, attributes: &[Attribute<'ctx>], location: AttributeLocation) {
         if let AttributeLocation::Argument(idx) = location {
-            assert!(idx > self.param_count() - 1, "idx > param.count")
+            assert!(idx < self.param_count(), "idx >= param.count")
         }
 
         let attributes = attributes.iter().map(|attr| attr.as_raw()).collect::<Vec<_>>();
@@ -84,10 +253,115 @@ impl<'ctx> FunctionValue<'ctx> {
         unsafe { LLVMSetLinkage(self.as_raw(), linkage.into()) }
     }
 
-    pub fn param(&self, idx: u32) -> Value {
+    pub fn linkage(&self) -> Linkage {
+        unsafe { LLVMGetLinkage(self.as_raw()).into() }
+    }
+
+    pub fn set_calling_convention(&self, calling_convention: CallingConvention) {
+        unsafe { LLVMSetFunctionCallConv(self.as_raw(), calling_convention.into()) }
+    }
+
+    pub fn calling_convention(&self) -> CallingConvention {
+        unsafe { LLVMGetFunctionCallConv(self.as_raw()).into() }
+    }
+
+    pub fn set_visibility(&self, visibility: Visibility) {
+        unsafe { LLVMSetVisibility(self.as_raw(), visibility.into()) }
+    }
+
+    pub fn visibility(&self) -> Visibility {
+        unsafe { LLVMGetVisibility(self.as_raw()).into() }
+    }
+
+    pub fn set_dll_storage_class(&self, storage_class: DllStorageClass) {
+        unsafe { LLVMSetDLLStorageClass(self.as_raw(), storage_class.into()) }
+    }
+
+    pub fn dll_storage_class(&self) -> DllStorageClass {
+        unsafe { LLVMGetDLLStorageClass(self.as_raw()).into() }
+    }
+
+    pub fn set_section(&self, section: &str) {
+        let section = to_c_str(section);
+
+        unsafe { LLVMSetSection(self.as_raw(), section.as_ptr()) }
+    }
+
+    pub fn section(&self) -> Option<&CStr> {
+        unsafe {
+            let ptr = LLVMGetSection(self.as_raw());
+
+            if ptr.is_null() {
+                return None;
+            }
+
+            Some(CStr::from_ptr(ptr)).filter(|section| !section.is_empty())
+        }
+    }
+
+    pub fn set_alignment(&self, alignment: u32) {
+        unsafe { LLVMSetAlignment(self.as_raw(), alignment) }
+    }
+
+    pub fn alignment(&self) -> u32 {
+        unsafe { LLVMGetAlignment(self.as_raw()) }
+    }
+
+    pub fn set_personality(&self, personality: &FunctionValue<'ctx>) {
+        unsafe { LLVMSetPersonalityFn(self.as_raw(), personality.as_raw()) }
+    }
+
+    pub fn personality(&self) -> Option<FunctionValue<'ctx>> {
+        unsafe {
+            if LLVMHasPersonalityFn(self.as_raw()) == 0 {
+                return None;
+            }
+
+            let personality_ref = LLVMGetPersonalityFn(self.as_raw());
+            Some(FunctionValue(BaseValue::new(self.0.get_context(), personality_ref)))
+        }
+    }
+
+    pub fn set_gc(&self, strategy: &str) {
+        let strategy = to_c_str(strategy);
+
+        unsafe { LLVMSetGC(self.as_raw(), strategy.as_ptr()) }
+    }
+
+    pub fn gc(&self) -> Option<&CStr> {
+        unsafe {
+            let ptr = LLVMGetGC(self.as_raw());
+
+            if ptr.is_null() {
+                return None;
+            }
+
+            Some(CStr::from_ptr(ptr)).filter(|section| !section.is_empty())
+        }
+    }
+
+    pub fn set_comdat(&self, comdat: &Comdat<'ctx>) {
+        unsafe { LLVMSetComdat(self.as_raw(), comdat.as_raw()) }
+    }
+
+    pub fn comdat(&self) -> Option<Comdat<'ctx>> {
+        let comdat_ref = unsafe { LLVMGetComdat(self.as_raw()) };
+
+        if comdat_ref.is_null() {
+            return None;
+        }
+
+        Some(Comdat::new(self.0.get_context(), comdat_ref))
+    }
+
+    pub fn set_subprogram(&self, subprogram: &DISubprogram<'ctx>) {
+        unsafe { LLVMSetSubprogram(self.as_raw(), subprogram.as_raw()) }
+    }
+
+    pub fn param(&self, idx: u32) -> Value<'ctx> {
         let param_ref = unsafe { LLVMGetParam(self.as_raw(), idx) };
 
-        Value::from_unknown(BaseValue::new(self.get_context(), param_ref))
+        Value::from_unknown(BaseValue::new(self.0.get_context(), param_ref))
     }
 
     pub fn append_basic_block(&self, name: &str) -> BasicBlock {
@@ -104,6 +378,51 @@ impl<'ctx> FunctionValue<'ctx> {
         BasicBlock::new(self.get_context(), basic_block_ref)
     }
 
+    pub fn basic_block_count(&self) -> u32 {
+        unsafe { LLVMCountBasicBlocks(self.as_raw()) }
+    }
+
+    pub fn entry_basic_block(&self) -> Option<BasicBlock<'ctx>> {
+        if self.basic_block_count() == 0 {
+            return None;
+        }
+
+        let basic_block_ref = unsafe { LLVMGetEntryBasicBlock(self.as_raw()) };
+
+        BasicBlock::from_raw(self.0.get_context(), basic_block_ref)
+    }
+
+    pub fn basic_blocks(&self) -> BasicBlockIter<'ctx> {
+        let basic_block_ref = unsafe { LLVMGetFirstBasicBlock(self.as_raw()) };
+
+        BasicBlockIter { context: self.0.get_context(), next: basic_block_ref }
+    }
+
+    pub fn run_pipeline(
+        &self,
+        pipeline: &FunctionPipeline,
+        target_machine: &TargetMachine,
+    ) -> Result<(), Error> {
+        let passes = to_c_str(&pipeline.to_string());
+
+        let result_ref = unsafe {
+            LLVMRustRunFunctionPasses(self.as_raw(), passes.as_ptr(), target_machine.as_raw())
+        };
+
+        let error = Error(result_ref);
+        if error.is_failure() {
+            return Err(error);
+        }
+
+        Ok(())
+    }
+
+    pub fn function_type(&self) -> FunctionType<'ctx> {
+        let ty_ref = unsafe { LLVMGlobalGetValueType(self.as_raw()) };
+
+        FunctionType(BaseType::new(self.0.get_context(), ty_ref))
+    }
+
     pub fn param_count(&self) -> u32 {
         unsafe { LLVMCountParams(self.as_raw()) }
     }
@@ -115,6 +434,30 @@ impl<'ctx> FunctionValue<'ctx> {
     pub fn add_argument_attributes(&self, idx: u32, attributes: &[Attribute<'ctx>]) {
         self.add_any_attributes(attributes, AttributeLocation::Argument(idx))
     }
+
+    pub fn add_return_attributes(&self, attributes: &[Attribute<'ctx>]) {
+        self.add_any_attributes(attributes, AttributeLocation::Return)
+    }
+}
+
+pub struct FunctionIter<'ctx> {
+    pub(crate) context: &'ctx Context,
+    pub(crate) next: LLVMValueRef,
+}
+
+impl<'ctx> Iterator for FunctionIter<'ctx> {
+    type Item = FunctionValue<'ctx>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next.is_null() {
+            return None;
+        }
+
+        let current = FunctionValue(BaseValue::new(self.context, self.next));
+        self.next = unsafe { LLVMGetNextFunction(self.next) };
+
+        Some(current)
+    }
 }
 
 impl_type_of!(FunctionValue<'ctx> -> FunctionType);
@@ -0,0 +1,156 @@
+use std::ffi::CStr;
+
+use llvm_sys::{
+    core::{
+        LLVMGetAlignment, LLVMGetInitializer, LLVMGetLinkage, LLVMGetSection,
+        LLVMGlobalGetValueType, LLVMIsGlobalConstant, LLVMSetAlignment, LLVMSetGlobalConstant,
+        LLVMSetInitializer, LLVMSetLinkage, LLVMSetSection, LLVMSetThreadLocalMode,
+    },
+    prelude::LLVMValueRef,
+    LLVMThreadLocalMode,
+};
+
+use crate::{
+    impl_as_raw, impl_get_context, impl_type_of, to_c_str,
+    types::{base_type::BaseType, pointer::PointerType, Type},
+    AsRaw,
+};
+
+use super::{base_value::BaseValue, function::Linkage, pointer::PointerValue, Value};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThreadLocalMode {
+    NotThreadLocal,
+    GeneralDynamic,
+    LocalDynamic,
+    InitialExec,
+    LocalExec,
+}
+
+impl From<ThreadLocalMode> for LLVMThreadLocalMode {
+    fn from(value: ThreadLocalMode) -> Self {
+        match value {
+            ThreadLocalMode::NotThreadLocal => Self::LLVMNotThreadLocal,
+            ThreadLocalMode::GeneralDynamic => Self::LLVMGeneralDynamicTLSModel,
+            ThreadLocalMode::LocalDynamic => Self::LLVMLocalDynamicTLSModel,
+            ThreadLocalMode::InitialExec => Self::LLVMInitialExecTLSModel,
+            ThreadLocalMode::LocalExec => Self::LLVMLocalExecTLSModel,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GlobalValue<'ctx>(pub(crate) BaseValue<'ctx>);
+
+impl<'ctx> GlobalValue<'ctx> {
+    pub fn value_type(&self) -> Type<'ctx> {
+        let ty_ref = unsafe { LLVMGlobalGetValueType(self.as_raw()) };
+
+        Type::from_base_type(BaseType::new(self.0.get_context(), ty_ref))
+    }
+
+    pub fn set_initializer<V>(&self, value: V)
+    where
+        Value<'ctx>: From<V>,
+    {
+        let value = Value::from(value);
+
+        unsafe { LLVMSetInitializer(self.as_raw(), value.as_raw()) }
+    }
+
+    pub fn initializer(&self) -> Option<Value<'ctx>> {
+        let value_ref = unsafe { LLVMGetInitializer(self.as_raw()) };
+
+        if value_ref.is_null() {
+            return None;
+        }
+
+        Some(Value::from_unknown(BaseValue::new(self.0.get_context(), value_ref)))
+    }
+
+    pub fn set_constant(&self, constant: bool) {
+        unsafe { LLVMSetGlobalConstant(self.as_raw(), constant as i32) }
+    }
+
+    pub fn is_constant(&self) -> bool {
+        unsafe { LLVMIsGlobalConstant(self.as_raw()) != 0 }
+    }
+
+    pub fn set_linkage(&self, linkage: Linkage) {
+        unsafe { LLVMSetLinkage(self.as_raw(), linkage.into()) }
+    }
+
+    pub fn linkage(&self) -> Linkage {
+        unsafe { LLVMGetLinkage(self.as_raw()).into() }
+    }
+
+    pub fn set_alignment(&self, alignment: u32) {
+        unsafe { LLVMSetAlignment(self.as_raw(), alignment) }
+    }
+
+    pub fn alignment(&self) -> u32 {
+        unsafe { LLVMGetAlignment(self.as_raw()) }
+    }
+
+    pub fn set_thread_local_mode(&self, mode: ThreadLocalMode) {
+        unsafe { LLVMSetThreadLocalMode(self.as_raw(), mode.into()) }
+    }
+
+    pub fn set_section(&self, section: &str) {
+        let section = to_c_str(section);
+
+        unsafe { LLVMSetSection(self.as_raw(), section.as_ptr()) }
+    }
+
+    pub fn section(&self) -> Option<&CStr> {
+        unsafe {
+            let ptr = LLVMGetSection(self.as_raw());
+
+            if ptr.is_null() {
+                return None;
+            }
+
+            Some(CStr::from_ptr(ptr)).filter(|section| !section.is_empty())
+        }
+    }
+
+    pub fn as_pointer(&self) -> PointerValue<'ctx> {
+        PointerValue(self.0.clone())
+    }
+}
+
+impl<'ctx> From<GlobalValue<'ctx>> for PointerValue<'ctx> {
+    fn from(global: GlobalValue<'ctx>) -> Self {
+        PointerValue(global.0)
+    }
+}
+
+impl<'ctx> From<GlobalValue<'ctx>> for Value<'ctx> {
+    fn from(global: GlobalValue<'ctx>) -> Self {
+        Value::Pointer(global.into())
+    }
+}
+
+impl_as_raw!(@downcast GlobalValue<'ctx>.0 -> LLVMValueRef);
+impl_get_context!(GlobalValue<'ctx>.0);
+impl_type_of!(GlobalValue<'ctx> -> PointerType);
+
+#[cfg(test)]
+mod tests {
+    use crate::{context::Context, values::function::Linkage};
+
+    #[test]
+    fn test_section_and_linkage() {
+        let context = Context::create();
+        let module = context.module("test");
+
+        let global = module.add_global("counter", context.int::<32>().into());
+        assert_eq!(global.section(), None);
+        assert_eq!(global.linkage(), Linkage::External);
+
+        global.set_section(".data.counter");
+        global.set_linkage(Linkage::Internal);
+        assert_eq!(global.section().unwrap().to_str(), Ok(".data.counter"));
+        assert_eq!(global.linkage(), Linkage::Internal);
+    }
+}
@@ -0,0 +1,60 @@
+use llvm_sys::{core::LLVMGetInlineAsm, prelude::LLVMValueRef, LLVMInlineAsmDialect};
+
+use crate::{impl_as_raw, impl_get_context, types::function::FunctionType, AsRaw};
+
+use super::base_value::BaseValue;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InlineAsmDialect {
+    Att,
+    Intel,
+}
+
+impl From<InlineAsmDialect> for LLVMInlineAsmDialect {
+    fn from(value: InlineAsmDialect) -> Self {
+        match value {
+            InlineAsmDialect::Att => Self::LLVMInlineAsmDialectATT,
+            InlineAsmDialect::Intel => Self::LLVMInlineAsmDialectIntel,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct InlineAsm<'ctx> {
+    value: BaseValue<'ctx>,
+    function_type: FunctionType<'ctx>,
+}
+
+impl<'ctx> InlineAsm<'ctx> {
+    pub fn new(
+        function_type: FunctionType<'ctx>,
+        template: &str,
+        constraints: &str,
+        dialect: InlineAsmDialect,
+        has_side_effects: bool,
+        is_align_stack: bool,
+    ) -> Self {
+        let asm_ref = unsafe {
+            LLVMGetInlineAsm(
+                function_type.as_raw(),
+                template.as_ptr().cast_mut().cast(),
+                template.len(),
+                constraints.as_ptr().cast_mut().cast(),
+                constraints.len(),
+                has_side_effects as i32,
+                is_align_stack as i32,
+                dialect.into(),
+                0,
+            )
+        };
+
+        Self { value: BaseValue::new(function_type.0.get_context(), asm_ref), function_type }
+    }
+
+    pub fn function_type(&self) -> FunctionType<'ctx> {
+        self.function_type
+    }
+}
+
+impl_as_raw!(@downcast InlineAsm<'ctx>.value -> LLVMValueRef);
+impl_get_context!(InlineAsm<'ctx>.value);
@@ -0,0 +1,487 @@
+use std::ptr;
+
+use llvm_sys::{
+    core::{
+        LLVMGetInstructionOpcode, LLVMGetInstructionParent, LLVMGetMetadata,
+        LLVMGetNextInstruction, LLVMGetNumOperands, LLVMGetOperand, LLVMGetPreviousInstruction,
+        LLVMInstructionEraseFromParent, LLVMIsATerminatorInst, LLVMReplaceAllUsesWith,
+        LLVMSetMetadata, LLVMValueAsBasicBlock, LLVMValueAsMetadata, LLVMValueIsBasicBlock,
+    },
+    prelude::LLVMValueRef,
+    LLVMOpcode,
+};
+
+use crate::{
+    attribute::{Attribute, AttributeLocation},
+    context::Context,
+    extra::LLVMAddCallSiteAttributes,
+    impl_as_raw, impl_get_context, impl_value_downcast,
+    metadata::{self, Metadata, MetadataKind},
+    types::{Type, TypeKind},
+    AsRaw,
+};
+
+use super::{
+    base_value::BaseValue,
+    basic_block::BasicBlock,
+    integer::IntegerValue,
+    uses::{Use, UseIter},
+    TypeOf, Value,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Opcode {
+    Ret,
+    Br,
+    Switch,
+    IndirectBr,
+    Invoke,
+    Unreachable,
+    CallBr,
+    FNeg,
+    Add,
+    FAdd,
+    Sub,
+    FSub,
+    Mul,
+    FMul,
+    UDiv,
+    SDiv,
+    FDiv,
+    URem,
+    SRem,
+    FRem,
+    Shl,
+    LShr,
+    AShr,
+    And,
+    Or,
+    Xor,
+    Alloca,
+    Load,
+    Store,
+    GetElementPtr,
+    Trunc,
+    ZExt,
+    SExt,
+    FPToUI,
+    FPToSI,
+    UIToFP,
+    SIToFP,
+    FPTrunc,
+    FPExt,
+    PtrToInt,
+    IntToPtr,
+    BitCast,
+    AddrSpaceCast,
+    ICmp,
+    FCmp,
+    Phi,
+    Call,
+    Select,
+    UserOp1,
+    UserOp2,
+    VAArg,
+    ExtractElement,
+    InsertElement,
+    ShuffleVector,
+    ExtractValue,
+    InsertValue,
+    Freeze,
+    Fence,
+    AtomicCmpXchg,
+    AtomicRMW,
+    Resume,
+    LandingPad,
+    CleanupRet,
+    CatchRet,
+    CatchPad,
+    CleanupPad,
+    CatchSwitch,
+}
+
+impl From<LLVMOpcode> for Opcode {
+    fn from(opcode: LLVMOpcode) -> Self {
+        match opcode {
+            LLVMOpcode::LLVMRet => Self::Ret,
+            LLVMOpcode::LLVMBr => Self::Br,
+            LLVMOpcode::LLVMSwitch => Self::Switch,
+            LLVMOpcode::LLVMIndirectBr => Self::IndirectBr,
+            LLVMOpcode::LLVMInvoke => Self::Invoke,
+            LLVMOpcode::LLVMUnreachable => Self::Unreachable,
+            LLVMOpcode::LLVMCallBr => Self::CallBr,
+            LLVMOpcode::LLVMFNeg => Self::FNeg,
+            LLVMOpcode::LLVMAdd => Self::Add,
+            LLVMOpcode::LLVMFAdd => Self::FAdd,
+            LLVMOpcode::LLVMSub => Self::Sub,
+            LLVMOpcode::LLVMFSub => Self::FSub,
+            LLVMOpcode::LLVMMul => Self::Mul,
+            LLVMOpcode::LLVMFMul => Self::FMul,
+            LLVMOpcode::LLVMUDiv => Self::UDiv,
+            LLVMOpcode::LLVMSDiv => Self::SDiv,
+            LLVMOpcode::LLVMFDiv => Self::FDiv,
+            LLVMOpcode::LLVMURem => Self::URem,
+            LLVMOpcode::LLVMSRem => Self::SRem,
+            LLVMOpcode::LLVMFRem => Self::FRem,
+            LLVMOpcode::LLVMShl => Self::Shl,
+            LLVMOpcode::LLVMLShr => Self::LShr,
+            LLVMOpcode::LLVMAShr => Self::AShr,
+            LLVMOpcode::LLVMAnd => Self::And,
+            LLVMOpcode::LLVMOr => Self::Or,
+            LLVMOpcode::LLVMXor => Self::Xor,
+            LLVMOpcode::LLVMAlloca => Self::Alloca,
+            LLVMOpcode::LLVMLoad => Self::Load,
+            LLVMOpcode::LLVMStore => Self::Store,
+            LLVMOpcode::LLVMGetElementPtr => Self::GetElementPtr,
+            LLVMOpcode::LLVMTrunc => Self::Trunc,
+            LLVMOpcode::LLVMZExt => Self::ZExt,
+            LLVMOpcode::LLVMSExt => Self::SExt,
+            LLVMOpcode::LLVMFPToUI => Self::FPToUI,
+            LLVMOpcode::LLVMFPToSI => Self::FPToSI,
+            LLVMOpcode::LLVMUIToFP => Self::UIToFP,
+            LLVMOpcode::LLVMSIToFP => Self::SIToFP,
+            LLVMOpcode::LLVMFPTrunc => Self::FPTrunc,
+            LLVMOpcode::LLVMFPExt => Self::FPExt,
+            LLVMOpcode::LLVMPtrToInt => Self::PtrToInt,
+            LLVMOpcode::LLVMIntToPtr => Self::IntToPtr,
+            LLVMOpcode::LLVMBitCast => Self::BitCast,
+            LLVMOpcode::LLVMAddrSpaceCast => Self::AddrSpaceCast,
+            LLVMOpcode::LLVMICmp => Self::ICmp,
+            LLVMOpcode::LLVMFCmp => Self::FCmp,
+            LLVMOpcode::LLVMPHI => Self::Phi,
+            LLVMOpcode::LLVMCall => Self::Call,
+            LLVMOpcode::LLVMSelect => Self::Select,
+            LLVMOpcode::LLVMUserOp1 => Self::UserOp1,
+            LLVMOpcode::LLVMUserOp2 => Self::UserOp2,
+            LLVMOpcode::LLVMVAArg => Self::VAArg,
+            LLVMOpcode::LLVMExtractElement => Self::ExtractElement,
+            LLVMOpcode::LLVMInsertElement => Self::InsertElement,
+            LLVMOpcode::LLVMShuffleVector => Self::ShuffleVector,
+            LLVMOpcode::LLVMExtractValue => Self::ExtractValue,
+            LLVMOpcode::LLVMInsertValue => Self::InsertValue,
+            LLVMOpcode::LLVMFreeze => Self::Freeze,
+            LLVMOpcode::LLVMFence => Self::Fence,
+            LLVMOpcode::LLVMAtomicCmpXchg => Self::AtomicCmpXchg,
+            LLVMOpcode::LLVMAtomicRMW => Self::AtomicRMW,
+            LLVMOpcode::LLVMResume => Self::Resume,
+            LLVMOpcode::LLVMLandingPad => Self::LandingPad,
+            LLVMOpcode::LLVMCleanupRet => Self::CleanupRet,
+            LLVMOpcode::LLVMCatchRet => Self::CatchRet,
+            LLVMOpcode::LLVMCatchPad => Self::CatchPad,
+            LLVMOpcode::LLVMCleanupPad => Self::CleanupPad,
+            LLVMOpcode::LLVMCatchSwitch => Self::CatchSwitch,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Operand<'ctx> {
+    Value(Value<'ctx>),
+    BasicBlock(BasicBlock<'ctx>),
+    Metadata(Metadata<'ctx>),
+    Other(TypeKind),
+}
+
+impl<'ctx> Operand<'ctx> {
+    pub(crate) fn new(context: &'ctx Context, value_ref: LLVMValueRef) -> Self {
+        if unsafe { LLVMValueIsBasicBlock(value_ref) } != 0 {
+            let basic_block_ref = unsafe { LLVMValueAsBasicBlock(value_ref) };
+            return Operand::BasicBlock(BasicBlock::new(context, basic_block_ref));
+        }
+
+        let base_value = BaseValue::new(context, value_ref);
+
+        match base_value.type_of().kind() {
+            TypeKind::Metadata => {
+                let metadata_ref = unsafe { LLVMValueAsMetadata(value_ref) };
+                Operand::Metadata(Metadata::new(context, metadata_ref))
+            }
+            kind => match Value::try_from_unknown(base_value) {
+                Some(value) => Operand::Value(value),
+                None => Operand::Other(kind),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct InstructionValue<'ctx>(pub(crate) BaseValue<'ctx>);
+
+impl<'ctx> InstructionValue<'ctx> {
+    pub fn opcode(&self) -> Opcode {
+        unsafe { LLVMGetInstructionOpcode(self.as_raw()).into() }
+    }
+
+    pub fn is_terminator(&self) -> bool {
+        unsafe { !LLVMIsATerminatorInst(self.as_raw()).is_null() }
+    }
+
+    pub fn parent(&self) -> BasicBlock<'ctx> {
+        let basic_block_ref = unsafe { LLVMGetInstructionParent(self.as_raw()) };
+
+        BasicBlock::new(self.0.get_context(), basic_block_ref)
+    }
+
+    pub fn next(&self) -> Option<InstructionValue<'ctx>> {
+        let instruction_ref = unsafe { LLVMGetNextInstruction(self.as_raw()) };
+
+        InstructionValue::from_raw(self.0.get_context(), instruction_ref)
+    }
+
+    pub fn previous(&self) -> Option<InstructionValue<'ctx>> {
+        let instruction_ref = unsafe { LLVMGetPreviousInstruction(self.as_raw()) };
+
+        InstructionValue::from_raw(self.0.get_context(), instruction_ref)
+    }
+
+    pub fn operand_count(&self) -> u32 {
+        unsafe { LLVMGetNumOperands(self.as_raw()) as u32 }
+    }
+
+    pub fn operand(&self, idx: u32) -> Option<Operand<'ctx>> {
+        if idx >= self.operand_count() {
+            return None;
+        }
+
+        let operand_ref = unsafe { LLVMGetOperand(self.as_raw(), idx) };
+        if operand_ref.is_null() {
+            return None;
+        }
+
+        Some(Operand::new(self.0.get_context(), operand_ref))
+    }
+
+    pub fn operands(&self) -> OperandIter<'_, 'ctx> {
+        OperandIter { instruction: self, idx: 0, count: self.operand_count() }
+    }
+
+    pub fn uses(&self) -> UseIter<'ctx> {
+        Use::first(&self.0)
+    }
+
+    pub fn as_value(&self) -> Value<'ctx> {
+        Value::from_unknown(self.0.clone())
+    }
+
+    pub fn replace_all_uses_with<V>(&self, value: V)
+    where
+        Value<'ctx>: From<V>,
+    {
+        let value = Value::from(value);
+
+        unsafe { LLVMReplaceAllUsesWith(self.as_raw(), value.as_raw()) }
+    }
+
+    fn add_any_attributes(&self, attributes: &[Attribute<'ctx>], location: AttributeLocation) {
+        assert!(
+            matches!(self.opcode(), Opcode::Call | Opcode::Invoke | Opcode::CallBr),
+            "{:?} is not a call site",
+            self.opcode()
+        );
+
+        let attributes = attributes.iter().map(|attr| attr.as_raw()).collect::<Vec<_>>();
+
+        unsafe {
+            LLVMAddCallSiteAttributes(
+                self.as_raw(),
+                location,
+                attributes.as_ptr(),
+                attributes.len(),
+            )
+        }
+    }
+
+    pub fn add_attributes(&self, attributes: &[Attribute<'ctx>]) {
+        self.add_any_attributes(attributes, AttributeLocation::Function)
+    }
+
+    pub fn add_argument_attributes(&self, idx: u32, attributes: &[Attribute<'ctx>]) {
+        self.add_any_attributes(attributes, AttributeLocation::Argument(idx))
+    }
+
+    pub fn add_return_attributes(&self, attributes: &[Attribute<'ctx>]) {
+        self.add_any_attributes(attributes, AttributeLocation::Return)
+    }
+
+    pub fn set_metadata(&self, kind: MetadataKind, node: &Metadata<'ctx>) {
+        self.set_custom_metadata(kind.name(), node)
+    }
+
+    pub fn set_custom_metadata(&self, kind: &str, node: &Metadata<'ctx>) {
+        let kind_id = metadata::kind_id(self.0.get_context(), kind);
+
+        unsafe { LLVMSetMetadata(self.as_raw(), kind_id, node.as_value_ref()) }
+    }
+
+    pub fn metadata(&self, kind: MetadataKind) -> Option<Metadata<'ctx>> {
+        self.custom_metadata(kind.name())
+    }
+
+    pub fn custom_metadata(&self, kind: &str) -> Option<Metadata<'ctx>> {
+        let kind_id = metadata::kind_id(self.0.get_context(), kind);
+
+        let value_ref = unsafe { LLVMGetMetadata(self.as_raw(), kind_id) };
+        if value_ref.is_null() {
+            return None;
+        }
+
+        let metadata_ref = unsafe { LLVMValueAsMetadata(value_ref) };
+
+        Some(Metadata::new(self.0.get_context(), metadata_ref))
+    }
+
+    pub fn remove_metadata(&self, kind: MetadataKind) {
+        let kind_id = metadata::kind_id(self.0.get_context(), kind.name());
+
+        unsafe { LLVMSetMetadata(self.as_raw(), kind_id, ptr::null_mut()) }
+    }
+
+    pub fn set_range_metadata(&self, ranges: &[(IntegerValue<'ctx>, IntegerValue<'ctx>)]) {
+        let context = self.0.get_context();
+
+        let operands = ranges
+            .iter()
+            .flat_map(|(low, high)| [low, high])
+            .map(|bound| context.value_metadata(bound.clone()))
+            .collect::<Vec<_>>();
+
+        self.set_metadata(MetadataKind::Range, &context.metadata_node(&operands))
+    }
+
+    pub fn set_nonnull_metadata(&self) {
+        let context = self.0.get_context();
+
+        self.set_metadata(MetadataKind::NonNull, &context.metadata_node(&[]))
+    }
+
+    /// # Safety
+    ///
+    /// The instruction is freed, no other handle to it may be used afterwards.
+    pub unsafe fn erase_from_parent(self) {
+        unsafe { LLVMInstructionEraseFromParent(self.as_raw()) }
+    }
+
+    pub(crate) fn from_raw(context: &'ctx Context, instruction_ref: LLVMValueRef) -> Option<Self> {
+        if instruction_ref.is_null() {
+            return None;
+        }
+
+        Some(Self(BaseValue::new(context, instruction_ref)))
+    }
+}
+
+impl<'ctx> TypeOf for InstructionValue<'ctx> {
+    type Output<'ty>
+        = Type<'ty>
+    where
+        Self: 'ty;
+
+    fn type_of(&self) -> Self::Output<'_> {
+        Type::from_base_type(self.0.type_of())
+    }
+}
+
+pub struct OperandIter<'a, 'ctx> {
+    instruction: &'a InstructionValue<'ctx>,
+    idx: u32,
+    count: u32,
+}
+
+impl<'a, 'ctx> Iterator for OperandIter<'a, 'ctx> {
+    type Item = Operand<'ctx>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.idx < self.count {
+            let operand = self.instruction.operand(self.idx);
+            self.idx += 1;
+
+            if operand.is_some() {
+                return operand;
+            }
+        }
+
+        None
+    }
+}
+
+impl_as_raw!(@downcast InstructionValue<'ctx>.0 -> LLVMValueRef);
+impl_get_context!(InstructionValue<'ctx>.0);
+impl_value_downcast!(InstructionValue<'ctx> -> Value::Instruction);
+
+#[cfg(test)]
+mod tests {
+    use crate::{builder::IntPredicate, context::Context, values::integer::IntegerValue, AsRaw};
+
+    use super::{Opcode, Operand};
+
+    #[test]
+    fn test_walk_function() {
+        let context = Context::create();
+        let module = context.module("test");
+        let builder = context.builder();
+
+        let int_32 = context.int::<32>();
+        let ty = context.function(&[int_32.into(), int_32.into()], int_32.into());
+        let function = module.add_function("test", ty);
+        let entry = function.append_basic_block("entry");
+        let zero = function.append_basic_block("zero");
+        let other = function.append_basic_block("other");
+
+        builder.position_at_end(&entry);
+        let sum = builder.add(
+            IntegerValue::from(function.param(0)),
+            IntegerValue::from(function.param(1)),
+            "sum",
+        );
+        let is_zero = builder.icmp(IntPredicate::EQ, sum.clone(), int_32.constant(0), "is_zero");
+        builder.cond_br(is_zero, &zero, &other);
+
+        builder.position_at_end(&zero);
+        builder.ret(int_32.constant(0));
+
+        builder.position_at_end(&other);
+        builder.ret(sum.clone());
+
+        module.verify().unwrap();
+
+        let functions = module.functions().map(|function| function.as_raw()).collect::<Vec<_>>();
+        assert_eq!(functions, vec![function.as_raw()]);
+
+        let blocks = function.basic_blocks().map(|block| block.as_raw()).collect::<Vec<_>>();
+        assert_eq!(blocks, vec![entry.as_raw(), zero.as_raw(), other.as_raw()]);
+
+        let opcodes =
+            entry.instructions().map(|instruction| instruction.opcode()).collect::<Vec<_>>();
+        assert_eq!(opcodes, vec![Opcode::Add, Opcode::ICmp, Opcode::Br]);
+
+        let add = entry.first_instruction().unwrap();
+        assert_eq!(add.operand_count(), 2);
+        assert!(add.operands().all(|operand| matches!(operand, Operand::Value(..))));
+        assert!(add.operand(2).is_none());
+
+        let branch = entry.terminator().unwrap();
+        assert!(branch.is_terminator());
+        assert!(matches!(branch.operand(0), Some(Operand::Value(..))));
+        let targets = branch
+            .operands()
+            .filter_map(|operand| match operand {
+                Operand::BasicBlock(block) => Some(block.as_raw()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(targets.len(), 2);
+        assert!(targets.contains(&zero.as_raw()) && targets.contains(&other.as_raw()));
+
+        let users = add
+            .uses()
+            .map(|used| match used.user() {
+                Operand::Value(value) => value.as_instruction().unwrap().opcode(),
+                operand => panic!("unexpected user {operand:?}"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(users.len(), 2);
+        assert!(users.contains(&Opcode::ICmp) && users.contains(&Opcode::Ret));
+        assert_eq!(function.param(0).uses().count(), 1);
+        assert_eq!(zero.first_instruction().unwrap().opcode(), Opcode::Ret);
+    }
+}
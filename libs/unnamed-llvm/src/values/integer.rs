@@ -1,12 +1,55 @@
-use llvm_sys::{core::LLVMTypeOf, prelude::LLVMValueRef};
+use std::cmp::Ordering;
+
+use llvm_sys::{
+    core::{
+        LLVMConstAShr, LLVMConstAdd, LLVMConstAnd, LLVMConstICmp, LLVMConstIntCast,
+        LLVMConstIntGetSExtValue, LLVMConstIntGetZExtValue, LLVMConstIntToPtr, LLVMConstLShr,
+        LLVMConstMul, LLVMConstNSWAdd, LLVMConstNSWMul, LLVMConstNSWSub, LLVMConstNUWAdd,
+        LLVMConstNUWMul, LLVMConstNUWSub, LLVMConstNeg, LLVMConstNot, LLVMConstOr, LLVMConstSExt,
+        LLVMConstSIToFP, LLVMConstShl, LLVMConstSub, LLVMConstTrunc, LLVMConstUIToFP, LLVMConstXor,
+        LLVMConstZExt, LLVMIsAConstantInt, LLVMTypeOf,
+    },
+    prelude::LLVMValueRef,
+};
 
 use crate::{
+    builder::IntPredicate,
     impl_as_raw, impl_get_context, impl_type_of, impl_value_downcast,
-    types::{base_type::BaseType, integer::IntegerType},
+    types::{base_type::BaseType, float::FloatType, integer::IntegerType, pointer::PointerType},
     AsRaw,
 };
 
-use super::base_value::BaseValue;
+use super::{base_value::BaseValue, float::FloatValue, pointer::PointerValue, Value};
+
+macro_rules! const_int_op {
+    ($name: ident($op: ident)) => {
+        pub fn $name(&self, rhs: &IntegerValue<'ctx>) -> Option<IntegerValue<'ctx>> {
+            if !self.is_constant_int() || !rhs.is_constant_int() || !self.same_width(rhs) {
+                return None;
+            }
+
+            let int_ref = unsafe { $op(self.as_raw(), rhs.as_raw()) };
+
+            Some(IntegerValue(BaseValue::new(self.0.get_context(), int_ref)))
+        }
+    };
+}
+
+macro_rules! const_int_cast {
+    ($name: ident($op: ident, $ordering: ident)) => {
+        pub fn $name(&self, ty: IntegerType<'ctx>) -> Option<IntegerValue<'ctx>> {
+            if !self.is_constant_int()
+                || self.get_type().width().cmp(&ty.width()) != Ordering::$ordering
+            {
+                return None;
+            }
+
+            let int_ref = unsafe { $op(self.as_raw(), ty.as_raw()) };
+
+            Some(IntegerValue(BaseValue::new(self.0.get_context(), int_ref)))
+        }
+    };
+}
 
 #[derive(Debug, Clone)]
 pub struct IntegerValue<'ctx>(pub(crate) BaseValue<'ctx>);
@@ -19,9 +62,250 @@ impl<'ctx> IntegerValue<'ctx> {
             IntegerType(base_type)
         }
     }
+
+    pub fn is_constant_int(&self) -> bool {
+        unsafe { !LLVMIsAConstantInt(self.as_raw()).is_null() }
+    }
+
+    pub fn as_constant_u64(&self) -> Option<u64> {
+        if !self.is_constant_int() || self.get_type().width() > 64 {
+            return None;
+        }
+
+        Some(unsafe { LLVMConstIntGetZExtValue(self.as_raw()) })
+    }
+
+    pub fn as_constant_i64(&self) -> Option<i64> {
+        if !self.is_constant_int() || self.get_type().width() > 64 {
+            return None;
+        }
+
+        Some(unsafe { LLVMConstIntGetSExtValue(self.as_raw()) })
+    }
+
+    const_int_op!(const_add(LLVMConstAdd));
+    const_int_op!(const_nsw_add(LLVMConstNSWAdd));
+    const_int_op!(const_nuw_add(LLVMConstNUWAdd));
+    const_int_op!(const_sub(LLVMConstSub));
+    const_int_op!(const_nsw_sub(LLVMConstNSWSub));
+    const_int_op!(const_nuw_sub(LLVMConstNUWSub));
+    const_int_op!(const_mul(LLVMConstMul));
+    const_int_op!(const_nsw_mul(LLVMConstNSWMul));
+    const_int_op!(const_nuw_mul(LLVMConstNUWMul));
+    const_int_op!(const_and(LLVMConstAnd));
+    const_int_op!(const_or(LLVMConstOr));
+    const_int_op!(const_xor(LLVMConstXor));
+    const_int_op!(const_shl(LLVMConstShl));
+    const_int_op!(const_lshr(LLVMConstLShr));
+    const_int_op!(const_ashr(LLVMConstAShr));
+
+    pub fn const_neg(&self) -> Option<IntegerValue<'ctx>> {
+        if !self.is_constant_int() {
+            return None;
+        }
+
+        let int_ref = unsafe { LLVMConstNeg(self.as_raw()) };
+
+        Some(IntegerValue(BaseValue::new(self.0.get_context(), int_ref)))
+    }
+
+    pub fn const_not(&self) -> Option<IntegerValue<'ctx>> {
+        if !self.is_constant_int() {
+            return None;
+        }
+
+        let int_ref = unsafe { LLVMConstNot(self.as_raw()) };
+
+        Some(IntegerValue(BaseValue::new(self.0.get_context(), int_ref)))
+    }
+
+    pub fn const_icmp(
+        &self,
+        op: IntPredicate,
+        rhs: &IntegerValue<'ctx>,
+    ) -> Option<IntegerValue<'ctx>> {
+        if !self.is_constant_int() || !rhs.is_constant_int() || !self.same_width(rhs) {
+            return None;
+        }
+
+        let int_ref = unsafe { LLVMConstICmp(op.into(), self.as_raw(), rhs.as_raw()) };
+
+        Some(IntegerValue(BaseValue::new(self.0.get_context(), int_ref)))
+    }
+
+    const_int_cast!(const_trunc(LLVMConstTrunc, Greater));
+    const_int_cast!(const_sign_extend(LLVMConstSExt, Less));
+    const_int_cast!(const_zero_extend(LLVMConstZExt, Less));
+
+    pub fn const_int_cast(
+        &self,
+        ty: IntegerType<'ctx>,
+        signed: bool,
+    ) -> Option<IntegerValue<'ctx>> {
+        if !self.is_constant_int() {
+            return None;
+        }
+
+        let int_ref = unsafe { LLVMConstIntCast(self.as_raw(), ty.as_raw(), signed as i32) };
+
+        Some(IntegerValue(BaseValue::new(self.0.get_context(), int_ref)))
+    }
+
+    pub fn const_signed_to_float(&self, ty: FloatType<'ctx>) -> Option<FloatValue<'ctx>> {
+        if !self.is_constant_int() {
+            return None;
+        }
+
+        let float_ref = unsafe { LLVMConstSIToFP(self.as_raw(), ty.as_raw()) };
+
+        Some(FloatValue::new(BaseValue::new(self.0.get_context(), float_ref), ty.kind))
+    }
+
+    pub fn const_unsigned_to_float(&self, ty: FloatType<'ctx>) -> Option<FloatValue<'ctx>> {
+        if !self.is_constant_int() {
+            return None;
+        }
+
+        let float_ref = unsafe { LLVMConstUIToFP(self.as_raw(), ty.as_raw()) };
+
+        Some(FloatValue::new(BaseValue::new(self.0.get_context(), float_ref), ty.kind))
+    }
+
+    pub fn const_to_pointer(&self, ty: PointerType<'ctx>) -> Option<PointerValue<'ctx>> {
+        if !self.is_constant_int() {
+            return None;
+        }
+
+        let pointer_ref = unsafe { LLVMConstIntToPtr(self.as_raw(), ty.as_raw()) };
+
+        Some(PointerValue(BaseValue::new(self.0.get_context(), pointer_ref)))
+    }
+
+    fn same_width(&self, other: &IntegerValue<'ctx>) -> bool {
+        self.get_type().width() == other.get_type().width()
+    }
+
+    pub fn with_width<const WIDTH: u32>(self) -> Option<IntValue<'ctx, WIDTH>> {
+        if self.get_type().width() != WIDTH {
+            return None;
+        }
+
+        Some(IntValue(self))
+    }
 }
 
 impl_as_raw!(@downcast IntegerValue<'ctx>.0 -> LLVMValueRef);
 impl_get_context!(IntegerValue<'ctx>.0);
 impl_type_of!(IntegerValue<'ctx> -> IntegerType);
 impl_value_downcast!(IntegerValue<'ctx> -> Value::Integer);
+
+/// An integer value whose bit width is part of its type.
+///
+/// ```compile_fail
+/// # use llvm::{context::Context, signature::StaticType};
+/// let context = Context::create();
+/// let module = context.module("test");
+/// let builder = context.builder();
+/// let callee = module.add_typed_function("callee", context.fn_type::<(i32,), ()>());
+/// let caller = module.add_typed_function("caller", context.fn_type::<(i64,), ()>());
+/// builder.typed_call(&callee, (caller.param::<0>(),), "");
+/// ```
+#[derive(Debug, Clone)]
+pub struct IntValue<'ctx, const WIDTH: u32>(IntegerValue<'ctx>);
+
+impl<'ctx, const WIDTH: u32> IntValue<'ctx, WIDTH> {
+    pub fn as_integer(&self) -> &IntegerValue<'ctx> {
+        &self.0
+    }
+}
+
+impl<'ctx, const WIDTH: u32> From<IntValue<'ctx, WIDTH>> for IntegerValue<'ctx> {
+    fn from(value: IntValue<'ctx, WIDTH>) -> Self {
+        value.0
+    }
+}
+
+impl<'ctx, const WIDTH: u32> From<IntValue<'ctx, WIDTH>> for Value<'ctx> {
+    fn from(value: IntValue<'ctx, WIDTH>) -> Self {
+        Value::Integer(value.0)
+    }
+}
+
+impl<'ctx, const WIDTH: u32> From<Value<'ctx>> for IntValue<'ctx, WIDTH> {
+    fn from(value: Value<'ctx>) -> Self {
+        IntegerValue::from(value).with_width().expect("bad integer width")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{builder::IntPredicate, context::Context, values::integer::IntegerValue};
+
+    #[test]
+    fn test_const_folding() {
+        let context = Context::create();
+        let int_32 = context.int::<32>();
+
+        let sum = int_32.constant(40).const_add(&int_32.constant(2)).unwrap();
+        assert_eq!(sum.as_constant_i64(), Some(42));
+
+        let less = sum.const_icmp(IntPredicate::SLT, &int_32.const_signed(-1)).unwrap();
+        assert_eq!(less.as_constant_u64(), Some(0));
+
+        let truncated = int_32.const_signed(-1).const_trunc(context.int::<8>()).unwrap();
+        assert_eq!(truncated.as_constant_u64(), Some(0xff));
+    }
+
+    #[test]
+    fn test_const_ops_on_non_constant() {
+        let context = Context::create();
+        let module = context.module("test");
+        let int_32 = context.int::<32>();
+        let function =
+            module.add_function("test", context.function(&[int_32.into()], int_32.into()));
+        let param = IntegerValue::from(function.param(0));
+
+        assert!(param.const_add(&int_32.constant(1)).is_none());
+        assert!(int_32.constant(1).const_add(&param).is_none());
+        assert!(param.const_neg().is_none());
+        assert!(param.const_zero_extend(context.int::<64>()).is_none());
+        assert!(param.const_signed_to_float(context.f64()).is_none());
+    }
+
+    #[test]
+    fn test_const_ops_width_mismatch() {
+        let context = Context::create();
+        let (int_8, int_32, int_64) =
+            (context.int::<8>(), context.int::<32>(), context.int::<64>());
+        let value = int_32.constant(7);
+
+        assert!(value.const_add(&int_64.constant(1)).is_none());
+        assert!(value.const_shl(&int_8.constant(1)).is_none());
+        assert!(value.const_icmp(IntPredicate::EQ, &int_64.constant(7)).is_none());
+
+        assert!(value.const_trunc(int_64).is_none());
+        assert!(value.const_trunc(int_32).is_none());
+        assert!(value.const_zero_extend(int_8).is_none());
+        assert!(value.const_sign_extend(int_32).is_none());
+
+        assert_eq!(value.const_trunc(int_8).unwrap().as_constant_u64(), Some(7));
+        assert_eq!(value.const_sign_extend(int_64).unwrap().as_constant_i64(), Some(7));
+        assert_eq!(
+            int_8.const_signed(-1).const_zero_extend(int_32).unwrap().as_constant_u64(),
+            Some(255)
+        );
+    }
+
+    #[test]
+    fn test_const_from_str() {
+        let context = Context::create();
+        let int_32 = context.int::<32>();
+
+        assert_eq!(int_32.const_from_str("-ff", 16).unwrap().as_constant_i64(), Some(-255));
+        assert_eq!(int_32.const_from_str("101", 2).unwrap().as_constant_u64(), Some(5));
+        assert!(int_32.const_from_str("12a", 10).is_none());
+        assert!(int_32.const_from_str("2", 2).is_none());
+        assert!(int_32.const_from_str("-", 10).is_none());
+        assert!(int_32.const_from_str("", 10).is_none());
+    }
+}
@@ -0,0 +1,37 @@
+use llvm_sys::{
+    core::{LLVMAddClause, LLVMSetCleanup},
+    prelude::LLVMValueRef,
+};
+
+use crate::{impl_as_raw, impl_get_context, impl_value_downcast, types::Type, AsRaw};
+
+use super::{base_value::BaseValue, TypeOf, Value};
+
+#[derive(Debug, Clone)]
+pub struct LandingPadValue<'ctx>(pub(crate) BaseValue<'ctx>);
+
+impl<'ctx> LandingPadValue<'ctx> {
+    pub fn add_clauses(&self, clauses: &[Value<'ctx>]) {
+        for clause in clauses {
+            unsafe { LLVMAddClause(self.as_raw(), clause.as_raw()) }
+        }
+    }
+
+    pub fn set_cleanup(&self, cleanup: bool) {
+        unsafe { LLVMSetCleanup(self.as_raw(), cleanup as i32) }
+    }
+}
+
+impl<'ctx> TypeOf for LandingPadValue<'ctx> {
+    type Output<'ty> = Type<'ty>
+    where
+        Self: 'ty;
+
+    fn type_of(&self) -> Self::Output<'_> {
+        Type::from_base_type(self.0.type_of())
+    }
+}
+
+impl_as_raw!(@downcast LandingPadValue<'ctx>.0 -> LLVMValueRef);
+impl_get_context!(LandingPadValue<'ctx>.0);
+impl_value_downcast!(LandingPadValue<'ctx> -> Value::LandingPad);
@@ -1,11 +1,24 @@
+pub mod array;
 pub(crate) mod base_value;
 pub mod basic_block;
+pub mod call_site;
 pub mod float;
 pub mod function;
+pub mod global;
+pub mod inline_asm;
+pub mod instruction;
 pub mod integer;
+pub mod landing_pad;
 pub mod phi_node;
-
-use llvm_sys::prelude::LLVMValueRef;
+pub mod pointer;
+pub mod structure;
+pub mod uses;
+pub mod vector;
+
+use llvm_sys::{
+    core::{LLVMIsAInstruction, LLVMIsConstant, LLVMIsNull, LLVMIsPoison, LLVMIsUndef},
+    prelude::LLVMValueRef,
+};
 
 use crate::{
     context::Context,
@@ -14,8 +27,18 @@ use crate::{
 };
 
 use self::{
-    base_value::BaseValue, float::FloatValue, function::FunctionValue, integer::IntegerValue,
+    array::ArrayValue,
+    base_value::BaseValue,
+    float::FloatValue,
+    function::FunctionValue,
+    instruction::InstructionValue,
+    integer::IntegerValue,
+    landing_pad::LandingPadValue,
     phi_node::PhiNodeValue,
+    pointer::PointerValue,
+    structure::StructValue,
+    uses::{Use, UseIter},
+    vector::VectorValue,
 };
 
 #[macro_export]
@@ -74,25 +97,93 @@ pub enum Value<'ctx> {
     Integer(IntegerValue<'ctx>),
     Float(FloatValue<'ctx>),
     PhiNode(PhiNodeValue<'ctx>),
+    Pointer(PointerValue<'ctx>),
+    Struct(StructValue<'ctx>),
+    LandingPad(LandingPadValue<'ctx>),
+    Array(ArrayValue<'ctx>),
+    Instruction(InstructionValue<'ctx>),
+    Vector(VectorValue<'ctx>),
 }
 
 unwrap_value!(Function(FunctionValue));
 unwrap_value!(Integer(IntegerValue));
 unwrap_value!(Float(FloatValue));
+unwrap_value!(Pointer(PointerValue));
+unwrap_value!(Struct(StructValue));
+unwrap_value!(Array(ArrayValue));
+unwrap_value!(Vector(VectorValue));
 
 impl<'ctx> Value<'ctx> {
     pub(crate) fn from_unknown(base_value: BaseValue<'ctx>) -> Self {
+        let kind = base_value.type_of().kind();
+
+        Self::try_from_unknown(base_value).unwrap_or_else(|| panic!("unexpected {:?}", kind))
+    }
+
+    pub(crate) fn try_from_unknown(base_value: BaseValue<'ctx>) -> Option<Self> {
         let base_type = base_value.type_of();
 
-        match base_type.kind() {
+        Some(match base_type.kind() {
             TypeKind::Integer => Self::Integer(IntegerValue(base_value)),
             TypeKind::Double => Self::Float(FloatValue::new(base_value, FloatKind::Double)),
             TypeKind::Float => Self::Float(FloatValue::new(base_value, FloatKind::Float)),
             TypeKind::Half => Self::Float(FloatValue::new(base_value, FloatKind::Half)),
+            TypeKind::BFloat => Self::Float(FloatValue::new(base_value, FloatKind::BFloat)),
+            TypeKind::Fp128 => Self::Float(FloatValue::new(base_value, FloatKind::Fp128)),
             TypeKind::Function => Self::Function(FunctionValue(base_value)),
-            kind => panic!("unexpected {:?}", kind),
+            TypeKind::Pointer => Self::Pointer(PointerValue(base_value)),
+            TypeKind::Struct => Self::Struct(StructValue(base_value)),
+            TypeKind::Array => Self::Array(ArrayValue(base_value)),
+            TypeKind::Vector => Self::Vector(VectorValue(base_value)),
+            TypeKind::Void => Self::Instruction(InstructionValue(base_value)),
+            _ => return None,
+        })
+    }
+
+    pub(crate) fn base(&self) -> BaseValue<'ctx> {
+        match self {
+            Value::Function(value) => value.0.clone(),
+            Value::Integer(value) => value.0.clone(),
+            Value::Float(value) => value.base_value.clone(),
+            Value::PhiNode(value) => value.0.clone(),
+            Value::Pointer(value) => value.0.clone(),
+            Value::Struct(value) => value.0.clone(),
+            Value::Array(value) => value.0.clone(),
+            Value::LandingPad(value) => value.0.clone(),
+            Value::Instruction(value) => value.0.clone(),
+            Value::Vector(value) => value.0.clone(),
         }
     }
+
+    pub fn is_constant(&self) -> bool {
+        unsafe { LLVMIsConstant(self.as_raw()) != 0 }
+    }
+
+    pub fn is_null(&self) -> bool {
+        unsafe { LLVMIsNull(self.as_raw()) != 0 }
+    }
+
+    pub fn is_undef(&self) -> bool {
+        unsafe { LLVMIsUndef(self.as_raw()) != 0 }
+    }
+
+    pub fn is_poison(&self) -> bool {
+        unsafe { LLVMIsPoison(self.as_raw()) != 0 }
+    }
+
+    pub fn as_instruction(&self) -> Option<InstructionValue<'ctx>> {
+        let base_value = self.base();
+
+        if unsafe { LLVMIsAInstruction(base_value.as_raw()) }.is_null() {
+            return None;
+        }
+
+        Some(InstructionValue(base_value))
+    }
+
+    pub fn uses(&self) -> UseIter<'ctx> {
+        Use::first(&self.base())
+    }
 }
 
 impl<'ctx> TypeOf for Value<'ctx> {
@@ -104,6 +195,12 @@ impl<'ctx> TypeOf for Value<'ctx> {
             Value::Integer(int) => Type::Integer(int.type_of()),
             Value::Float(float) => Type::Float(float.type_of()),
             Value::PhiNode(node) => node.type_of(),
+            Value::Pointer(ptr) => Type::Pointer(ptr.type_of()),
+            Value::Struct(structure) => Type::Struct(structure.type_of()),
+            Value::Array(array) => Type::Array(array.type_of()),
+            Value::LandingPad(landing_pad) => landing_pad.type_of(),
+            Value::Instruction(instruction) => instruction.type_of(),
+            Value::Vector(vector) => Type::Vector(vector.type_of()),
         }
     }
 }
@@ -117,6 +214,12 @@ impl<'ctx> AsRaw for Value<'ctx> {
             Value::Integer(value) => value.as_raw(),
             Value::Float(value) => value.as_raw(),
             Value::PhiNode(value) => value.as_raw(),
+            Value::Pointer(value) => value.as_raw(),
+            Value::Struct(value) => value.as_raw(),
+            Value::Array(value) => value.as_raw(),
+            Value::LandingPad(value) => value.as_raw(),
+            Value::Instruction(value) => value.as_raw(),
+            Value::Vector(value) => value.as_raw(),
         }
     }
 }
@@ -128,6 +231,12 @@ impl<'ctx> GetContext for Value<'ctx> {
             Value::Integer(value) => value.get_context(),
             Value::Float(value) => value.get_context(),
             Value::PhiNode(value) => value.get_context(),
+            Value::Pointer(value) => value.get_context(),
+            Value::Struct(value) => value.get_context(),
+            Value::Array(value) => value.get_context(),
+            Value::LandingPad(value) => value.get_context(),
+            Value::Instruction(value) => value.get_context(),
+            Value::Vector(value) => value.get_context(),
         }
     }
 }
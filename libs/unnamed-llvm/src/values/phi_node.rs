@@ -13,6 +13,10 @@ pub type Incomming<'ctx> = (Value<'ctx>, BasicBlock<'ctx>);
 pub struct PhiNodeValue<'ctx>(pub(crate) BaseValue<'ctx>);
 
 impl<'ctx> PhiNodeValue<'ctx> {
+    pub fn value(&self) -> Value<'ctx> {
+        Value::from_unknown(self.0.clone())
+    }
+
     pub fn add_incomming(&self, incommings: &[Incomming<'ctx>]) {
         let (mut values, mut basic_blocks): (Vec<LLVMValueRef>, Vec<LLVMBasicBlockRef>) =
             incommings
@@ -32,7 +36,8 @@ impl<'ctx> PhiNodeValue<'ctx> {
 }
 
 impl<'ctx> TypeOf for PhiNodeValue<'ctx> {
-    type Output<'ty> = Type<'ty>
+    type Output<'ty>
+        = Type<'ty>
     where
         Self: 'ty;
 
@@ -0,0 +1,25 @@
+use llvm_sys::{core::LLVMConstPtrToInt, prelude::LLVMValueRef};
+
+use crate::{
+    impl_as_raw, impl_get_context, impl_type_of, impl_value_downcast,
+    types::{integer::IntegerType, pointer::PointerType},
+    AsRaw,
+};
+
+use super::{base_value::BaseValue, integer::IntegerValue};
+
+#[derive(Debug, Clone)]
+pub struct PointerValue<'ctx>(pub(crate) BaseValue<'ctx>);
+
+impl<'ctx> PointerValue<'ctx> {
+    pub fn const_to_int(&self, ty: IntegerType<'ctx>) -> IntegerValue<'ctx> {
+        let int_ref = unsafe { LLVMConstPtrToInt(self.as_raw(), ty.as_raw()) };
+
+        IntegerValue(BaseValue::new(self.0.get_context(), int_ref))
+    }
+}
+
+impl_as_raw!(@downcast PointerValue<'ctx>.0 -> LLVMValueRef);
+impl_get_context!(PointerValue<'ctx>.0);
+impl_type_of!(PointerValue<'ctx> -> PointerType);
+impl_value_downcast!(PointerValue<'ctx> -> Value::Pointer);
@@ -0,0 +1,16 @@
+use llvm_sys::prelude::LLVMValueRef;
+
+use crate::{
+    impl_as_raw, impl_get_context, impl_type_of, impl_value_downcast, types::structure::StructType,
+    AsRaw,
+};
+
+use super::base_value::BaseValue;
+
+#[derive(Debug, Clone)]
+pub struct StructValue<'ctx>(pub(crate) BaseValue<'ctx>);
+
+impl_as_raw!(@downcast StructValue<'ctx>.0 -> LLVMValueRef);
+impl_get_context!(StructValue<'ctx>.0);
+impl_type_of!(StructValue<'ctx> -> StructType);
+impl_value_downcast!(StructValue<'ctx> -> Value::Struct);
@@ -0,0 +1,56 @@
+use llvm_sys::{
+    core::{LLVMGetFirstUse, LLVMGetNextUse, LLVMGetUsedValue, LLVMGetUser},
+    prelude::LLVMUseRef,
+};
+
+use crate::{context::Context, impl_as_raw, AsRaw};
+
+use super::{base_value::BaseValue, instruction::Operand};
+
+#[derive(Debug, Clone)]
+pub struct Use<'ctx> {
+    context: &'ctx Context,
+    inner: LLVMUseRef,
+}
+
+impl<'ctx> Use<'ctx> {
+    pub(crate) fn first(value: &BaseValue<'ctx>) -> UseIter<'ctx> {
+        let use_ref = unsafe { LLVMGetFirstUse(value.as_raw()) };
+
+        UseIter { context: value.get_context(), next: use_ref }
+    }
+
+    pub fn user(&self) -> Operand<'ctx> {
+        let user_ref = unsafe { LLVMGetUser(self.as_raw()) };
+
+        Operand::new(self.context, user_ref)
+    }
+
+    pub fn used_value(&self) -> Operand<'ctx> {
+        let value_ref = unsafe { LLVMGetUsedValue(self.as_raw()) };
+
+        Operand::new(self.context, value_ref)
+    }
+}
+
+impl_as_raw!(Use<'ctx>.inner -> LLVMUseRef);
+
+pub struct UseIter<'ctx> {
+    context: &'ctx Context,
+    next: LLVMUseRef,
+}
+
+impl<'ctx> Iterator for UseIter<'ctx> {
+    type Item = Use<'ctx>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next.is_null() {
+            return None;
+        }
+
+        let current = Use { context: self.context, inner: self.next };
+        self.next = unsafe { LLVMGetNextUse(self.next) };
+
+        Some(current)
+    }
+}
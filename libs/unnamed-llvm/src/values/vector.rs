@@ -0,0 +1,49 @@
+use llvm_sys::{
+    core::{LLVMConstExtractElement, LLVMConstInsertElement, LLVMConstShuffleVector},
+    prelude::LLVMValueRef,
+};
+
+use crate::{
+    impl_as_raw, impl_get_context, impl_type_of, impl_value_downcast, types::vector::VectorType,
+    AsRaw,
+};
+
+use super::{base_value::BaseValue, integer::IntegerValue, Value};
+
+#[derive(Debug, Clone)]
+pub struct VectorValue<'ctx>(pub(crate) BaseValue<'ctx>);
+
+impl<'ctx> VectorValue<'ctx> {
+    pub fn const_extract_element(&self, idx: &IntegerValue<'ctx>) -> Value<'ctx> {
+        let value_ref = unsafe { LLVMConstExtractElement(self.as_raw(), idx.as_raw()) };
+
+        Value::from_unknown(BaseValue::new(self.0.get_context(), value_ref))
+    }
+
+    pub fn const_insert_element(
+        &self,
+        value: &Value<'ctx>,
+        idx: &IntegerValue<'ctx>,
+    ) -> VectorValue<'ctx> {
+        let vector_ref =
+            unsafe { LLVMConstInsertElement(self.as_raw(), value.as_raw(), idx.as_raw()) };
+
+        VectorValue(BaseValue::new(self.0.get_context(), vector_ref))
+    }
+
+    pub fn const_shuffle(
+        &self,
+        other: &VectorValue<'ctx>,
+        mask: &VectorValue<'ctx>,
+    ) -> VectorValue<'ctx> {
+        let vector_ref =
+            unsafe { LLVMConstShuffleVector(self.as_raw(), other.as_raw(), mask.as_raw()) };
+
+        VectorValue(BaseValue::new(self.0.get_context(), vector_ref))
+    }
+}
+
+impl_as_raw!(@downcast VectorValue<'ctx>.0 -> LLVMValueRef);
+impl_get_context!(VectorValue<'ctx>.0);
+impl_type_of!(VectorValue<'ctx> -> VectorType);
+impl_value_downcast!(VectorValue<'ctx> -> Value::Vector);